@@ -4,20 +4,86 @@ use cranelift_module::{DataDescription, DataId, Module};
 use cranelift_object::ObjectModule;
 use miette::Result;
 
-use crate::compiler::parser::{Ast, DataDiv, Literal, Pic, StrLitId, StrLitStore};
+use crate::compiler::parser::{
+    Ast, DataDiv, DataItem, DataItemKind, DataRef, Figurative, Literal, Pic, PicUsage,
+    SignPosition, StrLitId, StrLitStore,
+};
 
 /**
  * Structures and utilities for configuring the static data layout
  * within output Cranelift objects.
  */
 
+/// The name of the special register holding the exit status of the program.
+pub(super) const RETURN_CODE_REGISTER: &str = "RETURN-CODE";
+
+/// Unique identifier for a single data symbol registered within the [`DataManager`].
+pub(super) type DataSymId = usize;
+
+/// A single data item registered within the [`DataManager`].
+pub(super) struct DataSym {
+    /// The name this item was declared with.
+    pub name: String,
+
+    /// The Cranelift data object this item is stored within.
+    /// Subordinate items share the data object of their top level (01) item.
+    pub data_id: DataId,
+
+    /// The offset of this item from the start of its data object, in bytes.
+    /// For items within tables, this is the offset of the first occurrence.
+    pub offset: usize,
+
+    /// The PIC layout of this item. Group items are treated as a single alphanumeric
+    /// item spanning the length of the whole group.
+    /// For tables, this describes only a single occurrence of the item.
+    pub pic: Pic,
+
+    /// The group item this item is subordinate to, if any.
+    pub parent: Option<DataSymId>,
+
+    /// The item whose storage this item redefines, if any.
+    pub redefines: Option<DataSymId>,
+
+    /// The table layout of this item, if it is a table.
+    pub table: Option<TableInfo>,
+
+    /// The subordinate items of this data item, in declaration order.
+    /// Empty for elementary items.
+    pub children: Vec<DataSymId>,
+}
+
+/// Table information for a single data item declared with an OCCURS clause.
+pub(super) struct TableInfo {
+    /// The maximum number of occurrences of the item.
+    pub occurs: usize,
+
+    /// The item holding the current number of occurrences, for variable length tables.
+    pub depending_on: Option<String>,
+
+    /// The keys the table is ordered by, as (name, ascending) pairs in order of significance.
+    pub keys: Vec<(String, bool)>,
+
+    /// The index names declared for the table.
+    pub indexes: Vec<String>,
+}
+
+impl DataSym {
+    /// Returns whether this data item is a group item.
+    pub fn is_group(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
 /// Manages static, runtime variable and file data within the code
 /// generator, mapping definitions from [`crate::compiler::parser::Ast`] to
 /// appropriate Cranelift definitions.
 pub(super) struct DataManager {
-    /// A map of all symbols for variables within the object to their Cranelift
-    /// data IDs, layouts.
-    sym_map: HashMap<String, (DataId, Pic)>,
+    /// All data items registered within the object, indexed by [`DataSymId`].
+    syms: Vec<DataSym>,
+
+    /// A map of all symbol names for variables within the object to their data items.
+    /// Multiple items may share the same name when declared within different groups.
+    sym_map: HashMap<String, Vec<DataSymId>>,
 
     /// A map of all string literals within the program to a Cranelift data symbol.
     str_lit_map: HashMap<StrLitId, DataId>,
@@ -27,6 +93,7 @@ impl DataManager {
     /// Creates a new data manager.
     pub(super) fn new() -> Self {
         DataManager {
+            syms: Vec::new(),
             sym_map: HashMap::new(),
             str_lit_map: HashMap::new(),
         }
@@ -38,24 +105,112 @@ impl DataManager {
         if let Some(data_div) = ast.data_div.as_ref() {
             self.upload_vars(module, &ast.str_lits, data_div)?;
         }
+        self.upload_special_registers(module)?;
         self.upload_str_lits(module, &ast.str_lits)?;
         Ok(())
     }
 
-    /// Returns the Cranelift [`DataId`] associated with the given COBOL symbol.
-    pub(super) fn sym_data_id(&self, sym: &str) -> Result<DataId> {
-        self.sym_map
-            .get(sym)
-            .map(|o| o.0)
-            .ok_or(miette::diagnostic!("No declared variable named '{}'.", sym).into())
+    /// Resolves the given COBOL symbol to a single registered data item.
+    /// If no item with that name exists, or the name is ambiguous, returns an error.
+    pub(super) fn resolve(&self, sym: &str) -> Result<DataSymId> {
+        match self.sym_map.get(sym).map(|ids| ids.as_slice()) {
+            Some([id]) => Ok(*id),
+            Some([_, _, ..]) => Err(miette::diagnostic!(
+                "Reference to variable '{}' is ambiguous, as multiple items share that name.",
+                sym
+            )
+            .into()),
+            _ => Err(miette::diagnostic!("No declared variable named '{}'.", sym).into()),
+        }
+    }
+
+    /// Resolves the given data reference to a single registered data item, using any qualifiers
+    /// on the reference to select between items which share the same name.
+    /// A reference qualified by every group containing an item always selects that item.
+    pub(super) fn resolve_ref(&self, sym: &DataRef<'_>) -> Result<DataSymId> {
+        let candidates: Vec<DataSymId> = self
+            .sym_map
+            .get(sym.name)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|id| self.is_qualified_by(*id, &sym.qualifiers))
+            .collect();
+        if let [id] = candidates.as_slice() {
+            return Ok(*id);
+        }
+        let fully_qualified: Vec<DataSymId> = candidates
+            .iter()
+            .copied()
+            .filter(|id| self.ancestor_names(*id).eq(sym.qualifiers.iter().copied()))
+            .collect();
+        match (candidates.as_slice(), fully_qualified.as_slice()) {
+            (_, [id]) => Ok(*id),
+            ([], _) => Err(miette::diagnostic!("No declared variable named '{}'.", sym).into()),
+            _ => Err(miette::diagnostic!(
+                "Reference to variable '{}' is ambiguous, as multiple items share that name.",
+                sym
+            )
+            .into()),
+        }
+    }
+
+    /// Returns whether the given qualifiers each name a group containing the given data item,
+    /// with each qualifier naming a group further out than the last.
+    fn is_qualified_by(&self, id: DataSymId, qualifiers: &[&str]) -> bool {
+        let mut ancestors = self.ancestor_names(id);
+        qualifiers
+            .iter()
+            .all(|qualifier| ancestors.any(|name| name == *qualifier))
     }
 
-    /// Returns the [`Pic`] layout associated with the given COBOL symbol.
-    pub(super) fn sym_pic(&self, sym: &str) -> Result<&Pic> {
-        self.sym_map
-            .get(sym)
-            .map(|o| &o.1)
-            .ok_or(miette::diagnostic!("Failed to fetch data slot for variable '{}'.", sym).into())
+    /// Returns the names of the groups containing the given data item, from the innermost outwards.
+    pub(super) fn ancestor_names(&self, id: DataSymId) -> impl Iterator<Item = &str> {
+        std::iter::successors(self.sym(id).parent, |id| self.sym(*id).parent)
+            .map(|id| self.sym(id).name.as_str())
+    }
+
+    /// Returns the registered data item for the given [`DataSymId`].
+    pub(super) fn sym(&self, id: DataSymId) -> &DataSym {
+        &self.syms[id]
+    }
+
+    /// Returns the [`Pic`] layout associated with the given data reference.
+    pub(super) fn sym_pic(&self, sym: &DataRef<'_>) -> Result<&Pic> {
+        Ok(&self.sym(self.resolve_ref(sym)?).pic)
+    }
+
+    /// Returns the table dimensions which the given data item is contained within, from the
+    /// outermost table inwards. Each dimension is given as an (occurrences, stride) pair,
+    /// where the stride is the size of a single occurrence in bytes.
+    pub(super) fn dimensions(&self, id: DataSymId) -> Vec<(usize, usize)> {
+        let mut dims = Vec::new();
+        let mut cur = Some(id);
+        while let Some(cur_id) = cur {
+            let sym = self.sym(cur_id);
+            if let Some(table) = &sym.table {
+                dims.push((table.occurs, sym.pic.storage_size()));
+            }
+            cur = sym.parent;
+        }
+        dims.reverse();
+        dims
+    }
+
+    /// Returns the variable length table which the given group item ends with, if any.
+    /// Variable length tables may only appear at the end of the group containing them.
+    pub(super) fn variable_table(&self, id: DataSymId) -> Option<DataSymId> {
+        let last_id = self
+            .sym(id)
+            .children
+            .iter()
+            .rev()
+            .find(|child_id| self.sym(**child_id).redefines.is_none())?;
+        match &self.sym(*last_id).table {
+            Some(table) if table.depending_on.is_some() => Some(*last_id),
+            Some(_) => None,
+            None => self.variable_table(*last_id),
+        }
     }
 
     /// Returns the Cranelift [`DataId`] associated with the given [`LiteralId`].
@@ -71,6 +226,8 @@ impl DataManager {
 
     /// Uploads variables present in the data division to the object file, registering them
     /// in the data manager's symbol table.
+    /// Each top level item is declared as a single data object, with all subordinate items
+    /// laid out contiguously within it.
     fn upload_vars(
         &mut self,
         module: &mut ObjectModule,
@@ -78,62 +235,258 @@ impl DataManager {
         data_div: &DataDiv<'_>,
     ) -> Result<()> {
         let mut desc = DataDescription::new();
-        for elem_var in data_div.ws_section.elementary_data.iter() {
+        let mut base_id: Option<DataSymId> = None;
+        for item in data_div.ws_section.items.iter() {
+            // Redefining items share the data object of the item they redefine.
+            if item.redefines.is_some() {
+                let base_id = base_id.expect("Redefining item has no preceding item to redefine.");
+                self.register_redefinition(item, None, base_id, str_lits)?;
+                continue;
+            }
+
             // Declare symbol data within module.
             let data_id = module
-                .declare_data(elem_var.name, cranelift_module::Linkage::Local, true, false)
+                .declare_data(item.name, cranelift_module::Linkage::Local, true, false)
                 .map_err(|err| {
                     miette::diagnostic!(
                         "Failed to declare data for symbol '{}': {}",
-                        elem_var.name,
+                        item.name,
                         err
                     )
                 })?;
             desc.clear();
 
+            // Register the item (and all subordinate items), generating the initial data.
+            let mut init_data: Vec<u8> = Vec::new();
+            base_id = Some(self.register_item(item, None, data_id, &mut init_data, str_lits)?);
+
             // Declare the data description of the variable.
-            match &elem_var.initial_val {
-                Some(init_val) => {
-                    let init_data = self.create_init_val(&elem_var.pic, init_val, str_lits);
-                    desc.define(init_data.into_boxed_slice());
-                }
-                None => {
-                    // No initial value, so declare as zeroed out.
-                    // We require this behaviour as strings can be arbitrarily partly copied as substrings.
-                    desc.define_zeroinit(elem_var.pic.comp_size());
-                }
+            // If there are no initial values, we can simply declare it as zeroed out.
+            // We require zeroed data as strings can be arbitrarily partly copied as substrings.
+            if init_data.iter().all(|b| *b == 0x0) {
+                desc.define_zeroinit(init_data.len());
+            } else {
+                desc.define(init_data.into_boxed_slice());
             }
 
             // Define the data within the object.
             module.define_data(data_id, &desc).map_err(|err| {
-                miette::diagnostic!(
-                    "Failed to define data for symbol '{}': {}",
-                    elem_var.name,
-                    err
-                )
+                miette::diagnostic!("Failed to define data for symbol '{}': {}", item.name, err)
+            })?;
+        }
+
+        self.upload_indexes(module)
+    }
+
+    /// Uploads storage for all index names declared by tables to the object file, registering
+    /// them in the data manager's symbol table. Each index holds a single occurrence number.
+    fn upload_indexes(&mut self, module: &mut ObjectModule) -> Result<()> {
+        let index_names: Vec<String> = self
+            .syms
+            .iter()
+            .filter_map(|sym| sym.table.as_ref())
+            .flat_map(|table| table.indexes.iter().cloned())
+            .collect();
+
+        let mut desc = DataDescription::new();
+        for name in index_names {
+            if self.sym_map.contains_key(&name) {
+                miette::bail!(
+                    "Index name '{}' conflicts with an existing data item or index.",
+                    name
+                );
+            }
+            let data_id = module
+                .declare_data(&name, cranelift_module::Linkage::Local, true, false)
+                .map_err(|err| {
+                    miette::diagnostic!("Failed to declare data for index '{}': {}", name, err)
+                })?;
+            let pic = Pic::index();
+            desc.clear();
+            desc.define_zeroinit(pic.storage_size());
+            module.define_data(data_id, &desc).map_err(|err| {
+                miette::diagnostic!("Failed to define data for index '{}': {}", name, err)
             })?;
 
-            // Register this symbol in the symbol map.
-            self.sym_map
-                .insert(elem_var.name.into(), (data_id, elem_var.pic.clone()));
+            self.sym_map.insert(name.clone(), vec![self.syms.len()]);
+            self.syms.push(DataSym {
+                name,
+                data_id,
+                offset: 0,
+                pic,
+                parent: None,
+                redefines: None,
+                table: None,
+                children: Vec::new(),
+            });
         }
+        Ok(())
+    }
 
+    /// Uploads storage for all special registers to the object file, registering them in the
+    /// data manager's symbol table.
+    fn upload_special_registers(&mut self, module: &mut ObjectModule) -> Result<()> {
+        let name = RETURN_CODE_REGISTER.to_string();
+        if self.sym_map.contains_key(&name) {
+            miette::bail!(
+                "Data item '{}' conflicts with the special register of the same name.",
+                name
+            );
+        }
+        let data_id = module
+            .declare_data(&name, cranelift_module::Linkage::Local, true, false)
+            .map_err(|err| {
+                miette::diagnostic!("Failed to declare data for register '{}': {}", name, err)
+            })?;
+        let pic = Pic::return_code();
+        let mut desc = DataDescription::new();
+        desc.define_zeroinit(pic.storage_size());
+        module.define_data(data_id, &desc).map_err(|err| {
+            miette::diagnostic!("Failed to define data for register '{}': {}", name, err)
+        })?;
+
+        self.sym_map.insert(name.clone(), vec![self.syms.len()]);
+        self.syms.push(DataSym {
+            name,
+            data_id,
+            offset: 0,
+            pic,
+            parent: None,
+            redefines: None,
+            table: None,
+            children: Vec::new(),
+        });
         Ok(())
     }
 
+    /// Registers a single data item (and any subordinate items) within the symbol table,
+    /// appending the item's initial data to the given data buffer.
+    /// Tables have their initial data repeated for each occurrence.
+    fn register_item(
+        &mut self,
+        item: &DataItem<'_>,
+        parent: Option<DataSymId>,
+        data_id: DataId,
+        init_data: &mut Vec<u8>,
+        str_lits: &StrLitStore,
+    ) -> Result<DataSymId> {
+        // Reserve this item's slot first, so subordinate items are registered after their parent.
+        let id = self.syms.len();
+        let offset = init_data.len();
+        self.syms.push(DataSym {
+            name: item.name.into(),
+            data_id,
+            offset,
+            pic: Pic::alphanumeric(0),
+            parent,
+            redefines: None,
+            table: item.occurs.as_ref().map(|occurs| TableInfo {
+                occurs: occurs.max,
+                depending_on: occurs.depending_on.map(|d| d.to_string()),
+                keys: occurs
+                    .keys
+                    .iter()
+                    .map(|key| (key.name.to_string(), key.ascending))
+                    .collect(),
+                indexes: occurs.indexed_by.iter().map(|i| i.to_string()).collect(),
+            }),
+            children: Vec::new(),
+        });
+
+        match &item.kind {
+            DataItemKind::Elementary(elem) => {
+                match &elem.initial_val {
+                    Some(init_val) => {
+                        init_data.extend(self.create_init_val(&elem.pic, init_val, str_lits))
+                    }
+                    None if elem.pic.is_decimal() => init_data.extend(encode_decimal(&elem.pic, 0)),
+                    None => init_data.resize(offset + elem.pic.storage_size(), 0x0),
+                }
+                self.syms[id].pic = elem.pic.clone();
+            }
+            DataItemKind::Group(items) => {
+                let mut children = Vec::new();
+                let mut base_id: Option<DataSymId> = None;
+                for child in items.iter() {
+                    let child_id = if child.redefines.is_some() {
+                        let base_id =
+                            base_id.expect("Redefining item has no preceding item to redefine.");
+                        self.register_redefinition(child, Some(id), base_id, str_lits)?
+                    } else {
+                        let child_id =
+                            self.register_item(child, Some(id), data_id, init_data, str_lits)?;
+                        base_id = Some(child_id);
+                        child_id
+                    };
+                    children.push(child_id);
+                }
+                self.syms[id].pic = Pic::alphanumeric(init_data.len() - offset);
+                self.syms[id].children = children;
+            }
+        }
+
+        // Repeat the data for the first occurrence for any remaining occurrences.
+        if let Some(occurs) = &item.occurs {
+            let occurrence = init_data[offset..].to_vec();
+            for _ in 1..occurs.max {
+                init_data.extend_from_slice(&occurrence);
+            }
+        }
+        self.sym_map.entry(item.name.into()).or_default().push(id);
+        Ok(id)
+    }
+
+    /// Registers a single data item (and any subordinate items) that redefines the storage of
+    /// the given base item, laying it out at the same offset within the base item's data object.
+    /// The redefining item cannot be larger than the item it redefines.
+    fn register_redefinition(
+        &mut self,
+        item: &DataItem<'_>,
+        parent: Option<DataSymId>,
+        base_id: DataSymId,
+        str_lits: &StrLitStore,
+    ) -> Result<DataSymId> {
+        let (data_id, base_offset, base_len) = {
+            let base = self.sym(base_id);
+            (base.data_id, base.offset, base.pic.storage_size())
+        };
+
+        // Redefining items cannot have initial values, so the data generated here is discarded.
+        let mut overlay_data: Vec<u8> = vec![0x0; base_offset];
+        let id = self.register_item(item, parent, data_id, &mut overlay_data, str_lits)?;
+        let len = overlay_data.len() - base_offset;
+        if len > base_len {
+            miette::bail!(
+                "Data item '{}' ({} bytes) cannot be larger than the item '{}' it redefines ({} bytes).",
+                item.name,
+                len,
+                item.redefines.unwrap(),
+                base_len
+            );
+        }
+        self.syms[id].redefines = Some(base_id);
+        Ok(id)
+    }
+
     /// Creates the initial byte value for a single COBOL variable.
     fn create_init_val(&self, pic: &Pic, lit: &Literal, str_lits: &StrLitStore) -> Vec<u8> {
         match lit {
-            Literal::Float(f) => f.to_ne_bytes().to_vec(),
-            Literal::Int(i) => i.to_ne_bytes().to_vec(),
+            Literal::Decimal(d) if pic.is_decimal() => encode_decimal(pic, d.rescale(pic.scale())),
+            Literal::Int(i) if pic.is_decimal() => encode_decimal(pic, i * 10i64.pow(pic.scale())),
+            Literal::Decimal(d) => d.rescale(pic.scale()).to_ne_bytes().to_vec(),
+            Literal::Int(i) => (i * 10i64.pow(pic.scale())).to_ne_bytes().to_vec(),
             Literal::String(id) => {
                 let str = str_lits.get(*id).unwrap();
                 let mut init_data = str.clone().into_bytes();
-                while init_data.len() < pic.comp_size() {
-                    init_data.push(0x0);
+                while init_data.len() < pic.storage_size() {
+                    init_data.push(b' ');
                 }
                 init_data
             }
+            Literal::Figurative(Figurative::Zeros) if !pic.is_str() => {
+                self.create_init_val(pic, &Literal::Int(0), str_lits)
+            }
+            Literal::Figurative(fig) => fig.fill(str_lits, pic.storage_size()),
         }
     }
 
@@ -164,3 +517,81 @@ impl DataManager {
         Ok(())
     }
 }
+
+/// Returns the sign mode passed to the zoned numeric intrinsics for the given DISPLAY numeric layout.
+/// Must match the modes defined within the [`cobalt_intrinsics`] crate.
+pub(super) fn zoned_sign_mode(pic: &Pic) -> i64 {
+    if !pic.is_signed() {
+        return 0;
+    }
+    match pic.sign_pos {
+        SignPosition::TrailingOverpunch => 1,
+        SignPosition::LeadingOverpunch => 2,
+        SignPosition::TrailingSeparate => 3,
+        SignPosition::LeadingSeparate => 4,
+    }
+}
+
+/// Encodes the given integer (scaled by the layout's decimal places) as the bytes of a
+/// decimal numeric value with the given layout.
+fn encode_decimal(pic: &Pic, val: i64) -> Vec<u8> {
+    if pic.usage == PicUsage::Packed {
+        encode_packed(pic, val)
+    } else {
+        encode_zoned(pic, val)
+    }
+}
+
+/// Encodes the given integer as the bytes of a COMP-3 numeric value with the given layout.
+/// Equivalent to the `cb_packed_store` intrinsic.
+fn encode_packed(pic: &Pic, val: i64) -> Vec<u8> {
+    let sign_nibble = match (pic.is_signed(), val < 0) {
+        (false, _) => 0xF,
+        (true, false) => 0xC,
+        (true, true) => 0xD,
+    };
+    let mut magnitude = val.unsigned_abs();
+    let mut bytes = vec![0u8; pic.storage_size()];
+    let last = bytes.len() - 1;
+    bytes[last] = ((magnitude % 10) as u8) << 4 | sign_nibble;
+    magnitude /= 10;
+    for idx in (0..last).rev() {
+        let low = (magnitude % 10) as u8;
+        let high = (magnitude / 10 % 10) as u8;
+        bytes[idx] = high << 4 | low;
+        magnitude /= 100;
+    }
+    bytes
+}
+
+/// Encodes the given integer as the bytes of a DISPLAY numeric value with the given layout.
+/// Equivalent to the `cb_zoned_store` intrinsic.
+fn encode_zoned(pic: &Pic, val: i64) -> Vec<u8> {
+    let negative = val < 0;
+    let digits = pic.digits();
+    let mut magnitude = val.unsigned_abs();
+    let mut bytes = vec![b'0'; digits];
+    for idx in (0..digits).rev() {
+        bytes[idx] = b'0' + (magnitude % 10) as u8;
+        magnitude /= 10;
+    }
+    if !pic.is_signed() {
+        return bytes;
+    }
+
+    // Apply the sign, either as a separate character or by overpunching a digit.
+    let overpunch = |digit: u8| match (negative, digit - b'0') {
+        (false, 0) => b'{',
+        (false, d) => b'A' + d - 1,
+        (true, 0) => b'}',
+        (true, d) => b'J' + d - 1,
+    };
+    let sign_char = if negative { b'-' } else { b'+' };
+    match pic.sign_pos {
+        SignPosition::TrailingOverpunch => bytes[digits - 1] = overpunch(bytes[digits - 1]),
+        SignPosition::LeadingOverpunch => bytes[0] = overpunch(bytes[0]),
+        SignPosition::TrailingSeparate => bytes.push(sign_char),
+        SignPosition::LeadingSeparate => bytes.insert(0, sign_char),
+    }
+    bytes
+}
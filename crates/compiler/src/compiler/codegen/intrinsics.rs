@@ -22,21 +22,30 @@ pub(super) struct IntrinsicManager {
 /// All others have sources within the [`cobalt_intrinsics`] crate.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) enum CobaltIntrinsic {
-    LibcPutchar, // int putc(char)
-    LibcExit,    // void exit(int)
-    PrintStr,    // void cb_print_str(char*)
-    PrintFloat,  // void cb_print_f64(f64)
-    PrintInt,    // void cb_print_i64(i64)
-    StrCmp,      // i8 cb_strcmp(char*, char*)
-    StrCpy,      // void cb_strcpy(char*, char*, i64, i64, i64, i64, i64, i64)
-    CharCpy,     // void cb_charcpy(char*, char*, i64, i64)
-    ReadStr,     // void cb_readstr(char*, usize)
-    ReadInt,     // i64 cb_readint()
-    ReadFloat,   // f64 cb_readfloat()
-    Mod,         // i64 cb_mod(i64, i64)
-    Length,      // i64 cb_length(char*)
-    Random,      // f64 cb_random()
-    Integer,     // i64 cb_integer(f64)
+    LibcExit,      // void exit(int)
+    PrintStr,      // void cb_print_str(char*, i64)
+    PrintDec,      // void cb_print_dec(i64, i64)
+    PrintInt,      // void cb_print_i64(i64)
+    PrintNl,       // void cb_print_nl()
+    PrintFixed,    // void cb_print_fixed(i64, i64, i64, i8)
+    ZonedLoad,     // i64 cb_zoned_load(char*, i64, i64)
+    ZonedStore,    // void cb_zoned_store(i64, char*, i64, i64)
+    PackedLoad,    // i64 cb_packed_load(char*, i64)
+    PackedStore,   // void cb_packed_store(i64, char*, i64, i8)
+    EditNum,       // void cb_edit_num(i64, i64, char*, char*)
+    PowDec,        // i64 cb_pow_dec(i64, i64, i64, i64, i64)
+    StrCmp,        // i8 cb_strcmp(char*, i64, char*, i64)
+    StrOrd,        // i8 cb_strord(char*, i64, char*, i64)
+    StrCpy,        // void cb_strcpy(char*, char*, i64, i64, i64, i64, i64, i64)
+    CharCpy,       // void cb_charcpy(char*, char*, i64, i64)
+    ReadStr,       // void cb_readstr(char*, usize)
+    ReadInt,       // i64 cb_readint()
+    ReadDec,       // i64 cb_readdec(i64)
+    Mod,           // i64 cb_mod(i64, i64)
+    Length,        // i64 cb_length(char*, i64)
+    Random,        // f64 cb_random()
+    Integer,       // i64 cb_integer(f64)
+    SubscriptFail, // void cb_subscript_fail(i64, i64)
 }
 
 impl IntrinsicManager {
@@ -101,21 +110,30 @@ impl IntrinsicManager {
     pub fn get_signature(&mut self, module: &mut ObjectModule, i: CobaltIntrinsic) -> Signature {
         let mut sig = module.make_signature();
         match i {
-            CobaltIntrinsic::LibcPutchar => libcputchar_sig(&mut sig),
             CobaltIntrinsic::LibcExit => libcexit_sig(&mut sig, module),
             CobaltIntrinsic::PrintStr => printstr_sig(&mut sig, module),
-            CobaltIntrinsic::PrintFloat => printfloat_sig(&mut sig),
+            CobaltIntrinsic::PrintDec => printdec_sig(&mut sig),
             CobaltIntrinsic::PrintInt => printint_sig(&mut sig),
+            CobaltIntrinsic::PrintNl => {}
+            CobaltIntrinsic::PrintFixed => printfixed_sig(&mut sig),
+            CobaltIntrinsic::ZonedLoad => zonedload_sig(&mut sig, module),
+            CobaltIntrinsic::ZonedStore => zonedstore_sig(&mut sig, module),
+            CobaltIntrinsic::PackedLoad => packedload_sig(&mut sig, module),
+            CobaltIntrinsic::PackedStore => packedstore_sig(&mut sig, module),
+            CobaltIntrinsic::EditNum => editnum_sig(&mut sig, module),
+            CobaltIntrinsic::PowDec => powdec_sig(&mut sig),
             CobaltIntrinsic::StrCmp => strcmp_sig(&mut sig, module),
+            CobaltIntrinsic::StrOrd => strcmp_sig(&mut sig, module),
             CobaltIntrinsic::StrCpy => strcpy_sig(&mut sig, module),
             CobaltIntrinsic::CharCpy => charcpy_sig(&mut sig, module),
             CobaltIntrinsic::ReadStr => readstr_sig(&mut sig, module),
             CobaltIntrinsic::ReadInt => readint_sig(&mut sig),
-            CobaltIntrinsic::ReadFloat => readfloat_sig(&mut sig),
+            CobaltIntrinsic::ReadDec => readdec_sig(&mut sig),
             CobaltIntrinsic::Mod => mod_sig(&mut sig),
             CobaltIntrinsic::Length => length_sig(&mut sig, module),
             CobaltIntrinsic::Random => random_sig(&mut sig),
             CobaltIntrinsic::Integer => integer_sig(&mut sig),
+            CobaltIntrinsic::SubscriptFail => subscriptfail_sig(&mut sig),
         };
         sig
     }
@@ -132,21 +150,30 @@ impl IntrinsicManager {
         // Get the function signature, name.
         let sig = self.get_signature(module, i);
         let name = match i {
-            CobaltIntrinsic::LibcPutchar => "putchar",
             CobaltIntrinsic::LibcExit => "exit",
             CobaltIntrinsic::PrintStr => "cb_print_str",
-            CobaltIntrinsic::PrintFloat => "cb_print_f64",
+            CobaltIntrinsic::PrintDec => "cb_print_dec",
             CobaltIntrinsic::PrintInt => "cb_print_i64",
+            CobaltIntrinsic::PrintNl => "cb_print_nl",
+            CobaltIntrinsic::PrintFixed => "cb_print_fixed",
+            CobaltIntrinsic::ZonedLoad => "cb_zoned_load",
+            CobaltIntrinsic::ZonedStore => "cb_zoned_store",
+            CobaltIntrinsic::PackedLoad => "cb_packed_load",
+            CobaltIntrinsic::PackedStore => "cb_packed_store",
+            CobaltIntrinsic::EditNum => "cb_edit_num",
+            CobaltIntrinsic::PowDec => "cb_pow_dec",
             CobaltIntrinsic::StrCmp => "cb_strcmp",
+            CobaltIntrinsic::StrOrd => "cb_strord",
             CobaltIntrinsic::StrCpy => "cb_strcpy",
             CobaltIntrinsic::CharCpy => "cb_charcpy",
             CobaltIntrinsic::ReadStr => "cb_readstr",
             CobaltIntrinsic::ReadInt => "cb_readint",
-            CobaltIntrinsic::ReadFloat => "cb_readfloat",
+            CobaltIntrinsic::ReadDec => "cb_readdec",
             CobaltIntrinsic::Mod => "cb_mod",
             CobaltIntrinsic::Length => "cb_length",
             CobaltIntrinsic::Random => "cb_random",
             CobaltIntrinsic::Integer => "cb_integer",
+            CobaltIntrinsic::SubscriptFail => "cb_subscript_fail",
         };
 
         // Import it.
@@ -160,12 +187,6 @@ impl IntrinsicManager {
     }
 }
 
-/// Generates a function signature for [`CobaltIntrinsic::LibcPutchar`].
-fn libcputchar_sig(sig: &mut Signature) {
-    sig.params.push(AbiParam::new(types::I8));
-    sig.returns.push(AbiParam::new(types::I32));
-}
-
 /// Generates a function signature for [`CobaltIntrinsic::LibcExit`].
 fn libcexit_sig(sig: &mut Signature, module: &mut ObjectModule) {
     let ptr_type = module.target_config().pointer_type();
@@ -175,12 +196,14 @@ fn libcexit_sig(sig: &mut Signature, module: &mut ObjectModule) {
 /// Generates a function signature for [`CobaltIntrinsic::PrintStr`].
 fn printstr_sig(sig: &mut Signature, module: &mut ObjectModule) {
     let ptr_type = module.target_config().pointer_type();
-    sig.params.push(AbiParam::new(ptr_type));
+    sig.params.push(AbiParam::new(ptr_type)); // str
+    sig.params.push(AbiParam::new(types::I64)); // max_len
 }
 
-/// Generates a function signature for [`CobaltIntrinsic::PrintFloat`].
-fn printfloat_sig(sig: &mut Signature) {
-    sig.params.push(AbiParam::new(types::F64));
+/// Generates a function signature for [`CobaltIntrinsic::PrintDec`].
+fn printdec_sig(sig: &mut Signature) {
+    sig.params.push(AbiParam::new(types::I64)); // val
+    sig.params.push(AbiParam::new(types::I64)); // scale
 }
 
 /// Generates a function signature for [`CobaltIntrinsic::PrintInt`].
@@ -188,11 +211,75 @@ fn printint_sig(sig: &mut Signature) {
     sig.params.push(AbiParam::new(types::I64));
 }
 
+/// Generates a function signature for [`CobaltIntrinsic::PrintFixed`].
+fn printfixed_sig(sig: &mut Signature) {
+    sig.params.push(AbiParam::new(types::I64)); // val
+    sig.params.push(AbiParam::new(types::I64)); // digits
+    sig.params.push(AbiParam::new(types::I64)); // scale
+    sig.params.push(AbiParam::new(types::I8)); // signed
+}
+
+/// Generates a function signature for [`CobaltIntrinsic::ZonedLoad`].
+fn zonedload_sig(sig: &mut Signature, module: &mut ObjectModule) {
+    let ptr_type = module.target_config().pointer_type();
+    sig.params.push(AbiParam::new(ptr_type)); // buf
+    sig.params.push(AbiParam::new(types::I64)); // digits
+    sig.params.push(AbiParam::new(types::I64)); // sign_mode
+    sig.returns.push(AbiParam::new(types::I64));
+}
+
+/// Generates a function signature for [`CobaltIntrinsic::ZonedStore`].
+fn zonedstore_sig(sig: &mut Signature, module: &mut ObjectModule) {
+    let ptr_type = module.target_config().pointer_type();
+    sig.params.push(AbiParam::new(types::I64)); // val
+    sig.params.push(AbiParam::new(ptr_type)); // buf
+    sig.params.push(AbiParam::new(types::I64)); // digits
+    sig.params.push(AbiParam::new(types::I64)); // sign_mode
+}
+
+/// Generates a function signature for [`CobaltIntrinsic::PackedLoad`].
+fn packedload_sig(sig: &mut Signature, module: &mut ObjectModule) {
+    let ptr_type = module.target_config().pointer_type();
+    sig.params.push(AbiParam::new(ptr_type)); // buf
+    sig.params.push(AbiParam::new(types::I64)); // digits
+    sig.returns.push(AbiParam::new(types::I64));
+}
+
+/// Generates a function signature for [`CobaltIntrinsic::PackedStore`].
+fn packedstore_sig(sig: &mut Signature, module: &mut ObjectModule) {
+    let ptr_type = module.target_config().pointer_type();
+    sig.params.push(AbiParam::new(types::I64)); // val
+    sig.params.push(AbiParam::new(ptr_type)); // buf
+    sig.params.push(AbiParam::new(types::I64)); // digits
+    sig.params.push(AbiParam::new(types::I8)); // signed
+}
+
+/// Generates a function signature for [`CobaltIntrinsic::EditNum`].
+fn editnum_sig(sig: &mut Signature, module: &mut ObjectModule) {
+    let ptr_type = module.target_config().pointer_type();
+    sig.params.push(AbiParam::new(types::I64)); // val
+    sig.params.push(AbiParam::new(types::I64)); // scale
+    sig.params.push(AbiParam::new(ptr_type)); // mask
+    sig.params.push(AbiParam::new(ptr_type)); // buf
+}
+
+/// Generates a function signature for [`CobaltIntrinsic::PowDec`].
+fn powdec_sig(sig: &mut Signature) {
+    sig.params.push(AbiParam::new(types::I64)); // base
+    sig.params.push(AbiParam::new(types::I64)); // base_scale
+    sig.params.push(AbiParam::new(types::I64)); // exp
+    sig.params.push(AbiParam::new(types::I64)); // exp_scale
+    sig.params.push(AbiParam::new(types::I64)); // out_scale
+    sig.returns.push(AbiParam::new(types::I64));
+}
+
 /// Generates a function signature for [`CobaltIntrinsic::StrCmp`].
 fn strcmp_sig(sig: &mut Signature, module: &mut ObjectModule) {
     let ptr_type = module.target_config().pointer_type();
-    sig.params.push(AbiParam::new(ptr_type));
-    sig.params.push(AbiParam::new(ptr_type));
+    sig.params.push(AbiParam::new(ptr_type)); // str_a
+    sig.params.push(AbiParam::new(types::I64)); // a_max_len
+    sig.params.push(AbiParam::new(ptr_type)); // str_b
+    sig.params.push(AbiParam::new(types::I64)); // b_max_len
     sig.returns.push(AbiParam::new(types::I8));
 }
 
@@ -229,9 +316,10 @@ fn readint_sig(sig: &mut Signature) {
     sig.returns.push(AbiParam::new(types::I64));
 }
 
-/// Generates a function signature for [`CobaltIntrinsic::ReadFloat`].
-fn readfloat_sig(sig: &mut Signature) {
-    sig.returns.push(AbiParam::new(types::F64));
+/// Generates a function signature for [`CobaltIntrinsic::ReadDec`].
+fn readdec_sig(sig: &mut Signature) {
+    sig.params.push(AbiParam::new(types::I64)); // scale
+    sig.returns.push(AbiParam::new(types::I64));
 }
 
 /// Generates a function signature for [`CobaltIntrinsic::Mod`].
@@ -244,7 +332,8 @@ fn mod_sig(sig: &mut Signature) {
 /// Generates a function signature for [`CobaltIntrinsic::Length`].
 fn length_sig(sig: &mut Signature, module: &mut ObjectModule) {
     let ptr_type = module.target_config().pointer_type();
-    sig.params.push(AbiParam::new(ptr_type));
+    sig.params.push(AbiParam::new(ptr_type)); // str
+    sig.params.push(AbiParam::new(types::I64)); // max_len
     sig.returns.push(AbiParam::new(types::I64));
 }
 
@@ -258,3 +347,9 @@ fn integer_sig(sig: &mut Signature) {
     sig.params.push(AbiParam::new(types::F64));
     sig.returns.push(AbiParam::new(types::I64));
 }
+
+/// Generates a function signature for [`CobaltIntrinsic::SubscriptFail`].
+fn subscriptfail_sig(sig: &mut Signature) {
+    sig.params.push(AbiParam::new(types::I64)); // idx
+    sig.params.push(AbiParam::new(types::I64)); // occurs
+}
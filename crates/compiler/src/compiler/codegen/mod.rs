@@ -6,10 +6,7 @@ use std::path::PathBuf;
 #[cfg(debug_assertions)]
 use colored::Colorize;
 use cranelift::{
-    codegen::{
-        ir::{AbiParam, InstBuilder},
-        verify_function,
-    },
+    codegen::{ir::AbiParam, verify_function},
     frontend::{FunctionBuilder, FunctionBuilderContext},
 };
 use cranelift_module::Module;
@@ -18,15 +15,11 @@ use miette::Result;
 
 use crate::config::BuildConfig;
 
-use self::{
-    data::DataManager, func::FuncManager, intrinsics::IntrinsicManager, isa::Isa,
-    translate::FuncTranslator,
-};
+use self::{data::DataManager, intrinsics::IntrinsicManager, isa::Isa, translate::FuncTranslator};
 
-use super::parser::{Ast, Paragraph};
+use super::parser::Ast;
 
 mod data;
-mod func;
 mod intrinsics;
 mod isa;
 mod translate;
@@ -56,9 +49,6 @@ pub struct CodeGenerator<'cfg, 'src> {
 
     /// Manages object data for this module.
     data_manager: DataManager,
-
-    /// Manages function registration for this module.
-    func_manager: FuncManager,
 }
 
 impl<'cfg, 'src> CodeGenerator<'cfg, 'src> {
@@ -81,7 +71,6 @@ impl<'cfg, 'src> CodeGenerator<'cfg, 'src> {
             module: obj_module,
             intrinsics: IntrinsicManager::new(),
             data_manager: DataManager::new(),
-            func_manager: FuncManager::new(),
         })
     }
 
@@ -91,19 +80,7 @@ impl<'cfg, 'src> CodeGenerator<'cfg, 'src> {
         let ast = self.ast.take().unwrap();
         self.data_manager.upload(&mut self.module, &ast)?;
 
-        // Define all functions.
-        for (idx, para) in ast.proc_div.paragraphs.iter().enumerate() {
-            let is_entrypoint = idx == 0;
-            self.func_manager
-                .create_fn(&mut self.module, para.name.map(|x| x.0), is_entrypoint)?;
-        }
-
-        // Translate all functions.
-        for para in ast.proc_div.paragraphs.iter() {
-            self.translate_fn(para, &ast)?;
-        }
-
-        // Create an entrypoint for the program.
+        // Create an entrypoint for the program, containing the procedure division.
         self.translate_entrypoint(&ast)?;
 
         Ok(())
@@ -111,6 +88,8 @@ impl<'cfg, 'src> CodeGenerator<'cfg, 'src> {
 
     /// Generates the program entrypoint, executing paragraphs in order until a terminating
     /// paragraph is encountered.
+    /// All paragraphs are translated into this single function, so that control can be
+    /// transferred between them without growing the stack.
     fn translate_entrypoint(&mut self, ast: &Ast<'src>) -> Result<()> {
         // Create "main" function for later linking.
         // Returns int, has no parameters.
@@ -136,42 +115,18 @@ impl<'cfg, 'src> CodeGenerator<'cfg, 'src> {
             builder.switch_to_block(entry_block);
             builder.seal_block(entry_block);
 
-            // Call each paragraph in turn, until we encounter one which terminates.
-            // A terminating paragraph may be called before this, but we can't be 100% sure of that through static analysis alone,
-            // at least without some quite annoying processing. Worst case scenario, we generate some useless function calls.
-            self.func_manager.clear_refs();
-            let mut terminator_found = false;
-            for para in ast.proc_div.paragraphs.iter() {
-                let para_func_ref = match para.name {
-                    Some((name, _)) => {
-                        self.func_manager
-                            .get_ref(&mut self.module, builder.func, name)?
-                    }
-                    None => self
-                        .func_manager
-                        .get_entrypoint_ref(&mut self.module, builder.func)?,
-                };
-                builder.ins().call(para_func_ref, &[]);
-
-                if para.terminates {
-                    terminator_found = true;
-                    break;
-                }
-            }
-
-            // If there was no terminator found, no "STOP RUN" statement is present anywhere in the code.
-            if !terminator_found {
-                miette::bail!(
-                    "No paragraph within the program terminates execution with 'STOP RUN'."
-                );
-            }
-
-            // Generate a return value (for now, just 0).
-            let ret_val = builder.ins().iconst(int, 0x0);
-            builder.ins().return_(&[ret_val]);
+            // Create function translator, translate all paragraphs.
+            let mut trans = FuncTranslator::new(
+                builder,
+                &mut self.module,
+                ast,
+                &mut self.intrinsics,
+                &mut self.data_manager,
+            );
+            trans.translate_proc_div(&ast.proc_div)?;
 
             // Finish the function.
-            builder.finalize();
+            trans.builder.finalize();
         }
 
         // Print the IR for this function if matched (debug mode only).
@@ -204,82 +159,6 @@ impl<'cfg, 'src> CodeGenerator<'cfg, 'src> {
         Ok(())
     }
 
-    /// Generates a single function from the AST, given a name & list of statements.
-    fn translate_fn(&mut self, paragraph: &Paragraph<'src>, ast: &Ast<'src>) -> Result<()> {
-        // Fetch the function to be defined.
-        let func_id = match paragraph.name {
-            Some((name, _)) => self.func_manager.get_id(name)?,
-            None => self.func_manager.get_entrypoint_id()?,
-        };
-
-        // Create builder, begin entry block for function.
-        {
-            let mut builder = FunctionBuilder::new(&mut self.ctx.func, &mut self.builder_ctx);
-            let entry_block = builder.create_block();
-            builder.append_block_params_for_function_params(entry_block);
-
-            // Switch to block for this function.
-            // By "sealing" here, we inform Cranelift there are no predecessors to this block, as it's the
-            // entry block.
-            builder.switch_to_block(entry_block);
-            builder.seal_block(entry_block);
-
-            // Create function translator, translate all statements.
-            let mut trans = FuncTranslator::new(
-                builder,
-                &mut self.module,
-                ast,
-                &mut self.intrinsics,
-                &mut self.data_manager,
-                &mut self.func_manager,
-            );
-            trans.translate(&paragraph.stats)?;
-
-            // If this function terminates, emit a call to `exit()`.
-            if paragraph.terminates {
-                trans.translate_terminate()?;
-            }
-            trans.builder.ins().return_(&[]);
-
-            // Finish the function.
-            trans.builder.finalize();
-        }
-
-        // Print the IR for this function if matched (debug mode only).
-        #[cfg(debug_assertions)]
-        {
-            let func_match_name = paragraph.name.map(|n| n.0).unwrap_or("cobalt::entrypoint");
-            if self
-                .cfg
-                .output_ir_regex
-                .as_ref()
-                .is_some_and(|r| r.is_match(func_match_name))
-            {
-                println!(
-                    "{}{}{}{}",
-                    "info(ir): Function IR for ".blue(),
-                    func_match_name.blue(),
-                    ".\n".blue(),
-                    self.ctx.func.display()
-                );
-            }
-        }
-
-        // Verify that the function is valid.
-        verify_function(&self.ctx.func, self.module.isa())
-            .map_err(|err| miette::diagnostic!("codegen: Function verification failed: {}", err))?;
-
-        // Define the function from the built function held in ctx.
-        self.module
-            .define_function(func_id, &mut self.ctx)
-            .map_err(|err| {
-                miette::diagnostic!("codegen: Failed to define function body: {}", err)
-            })?;
-
-        self.module.clear_context(&mut self.ctx);
-        Ok(())
-    }
-
     /// Converts the generated Cranelift IR to object code, emitting it.
     /// Returns a path to the generated object file, if successful.
     pub fn generate(self) -> Result<PathBuf> {
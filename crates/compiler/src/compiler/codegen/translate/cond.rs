@@ -1,15 +1,25 @@
-use cranelift::codegen::ir::{
-    condcodes::{FloatCC, IntCC},
-    types, InstBuilder, Value,
+use cranelift::{
+    codegen::ir::{
+        condcodes::{CondCode, IntCC},
+        types, Block, InstBuilder, Value,
+    },
+    frontend::Switch,
 };
 use miette::Result;
 
 use crate::compiler::{
     codegen::intrinsics::CobaltIntrinsic,
-    parser::{self, Cond, IfData},
+    parser::{
+        self, Cond, CondName, DataItem, DataItemKind, DataRef, EvalObject, EvalSubject,
+        EvaluateData, Expr, Figurative, IfData, Literal,
+    },
 };
 
-use super::FuncTranslator;
+use super::{math::EXPR_GUARD_DIGITS, FuncTranslator};
+
+/// The largest number of values a single "THRU" range may cover for an "EVALUATE" statement
+/// to be lowered to a switch on its subject, rather than a chain of comparisons.
+const EVAL_SWITCH_MAX_RANGE: i64 = 256;
 
 impl<'a, 'src> FuncTranslator<'a, 'src> {
     /// Translates a single "IF" statement to Cranelift IR.
@@ -46,14 +56,7 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         // Switch to the if block, translate contents.
         // Once the if block is done, we jump to the trailing block.
         self.switch_to_block(if_block);
-        let mut block_self_terminates = false;
-        for stat in if_data.if_stats.as_ref().unwrap() {
-            // If this block has already terminated, we don't want to generate anything else.
-            if block_self_terminates {
-                miette::bail!("Unreachable statements detected in block: No statements should be placed after unconditional jumps.");
-            }
-            block_self_terminates |= self.translate_stat(stat)?;
-        }
+        let block_self_terminates = self.translate_stats(if_data.if_stats.as_ref().unwrap())?;
         if !block_self_terminates {
             self.builder.ins().jump(trailing_block, &[]);
         }
@@ -66,14 +69,7 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         // If there's an else block, translate contents.
         if let Some(else_stats) = if_data.else_stats.as_ref() {
             self.switch_to_block(else_block.unwrap());
-            let mut block_self_terminates = false;
-            for stat in else_stats {
-                // If this block has already terminated, we don't want to generate anything else.
-                if block_self_terminates {
-                    miette::bail!("Unreachable statements detected in block: No statements should be placed after unconditional jumps.");
-                }
-                block_self_terminates |= self.translate_stat(stat)?;
-            }
+            let block_self_terminates = self.translate_stats(else_stats)?;
             if !block_self_terminates {
                 self.builder.ins().jump(trailing_block, &[]);
             }
@@ -85,6 +81,195 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         Ok(())
     }
 
+    /// Translates a single "EVALUATE" statement to Cranelift IR.
+    /// Statements with a single integer subject and only integer literal objects are lowered to a
+    /// switch on the subject, which uses jump tables for dense sets of cases. All other statements
+    /// are lowered to a chain of comparisons, checking each branch in order.
+    pub(super) fn translate_evaluate(&mut self, eval: &EvaluateData<'src>) -> Result<()> {
+        // Create blocks for each branch, "WHEN OTHER" and the post-statement.
+        let when_blocks: Vec<Block> = eval
+            .whens
+            .iter()
+            .map(|_| self.builder.create_block())
+            .collect();
+        let other_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+
+        // Branch to the first selected branch.
+        if let Some(cases) = self.evaluate_switch_cases(eval)? {
+            let EvalSubject::Expr(Expr::Value(subject)) = &eval.subjects[0] else {
+                unreachable!("Switched EVALUATE statement has no value subject.");
+            };
+            let subject_val = self.load_num(subject)?.val;
+            let mut switch = Switch::new();
+            for (case, idx) in cases {
+                // Earlier branches take precedence over later branches with the same value.
+                let case = case as u64 as u128;
+                if !switch.entries().contains_key(&case) {
+                    switch.set_entry(case, when_blocks[idx]);
+                }
+            }
+            switch.emit(&mut self.builder, subject_val, other_block);
+        } else {
+            for (when, when_block) in eval.whens.iter().zip(when_blocks.iter()) {
+                let mut selected: Option<Value> = None;
+                for objects in when.object_sets.iter() {
+                    let matched = self.translate_eval_objects(&eval.subjects, objects)?;
+                    selected = Some(match selected {
+                        Some(prev) => self.builder.ins().bor(prev, matched),
+                        None => matched,
+                    });
+                }
+                let selected = selected.expect("EVALUATE branch declared with no objects.");
+                let next_block = self.builder.create_block();
+                self.builder
+                    .ins()
+                    .brif(selected, *when_block, &[], next_block, &[]);
+                self.builder.seal_block(next_block);
+                self.switch_to_block(next_block);
+            }
+            self.builder.ins().jump(other_block, &[]);
+        }
+        for when_block in when_blocks.iter() {
+            self.builder.seal_block(*when_block);
+        }
+        self.builder.seal_block(other_block);
+
+        // Translate the statements of each branch, each jumping to the trailing block once done.
+        let branches = eval
+            .whens
+            .iter()
+            .map(|when| Some(&when.stats))
+            .chain(std::iter::once(eval.other.as_ref()));
+        for (block, stats) in when_blocks.into_iter().chain([other_block]).zip(branches) {
+            self.switch_to_block(block);
+            let block_self_terminates = match stats {
+                Some(stats) => self.translate_stats(stats)?,
+                None => false,
+            };
+            if !block_self_terminates {
+                self.builder.ins().jump(trailing_block, &[]);
+            }
+        }
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
+        Ok(())
+    }
+
+    /// Returns the cases of the given "EVALUATE" statement as (value, branch index) pairs, if the
+    /// statement can be lowered to a switch on its subject. This requires a single integer subject,
+    /// with every object being an integer literal or a short range of integer literals.
+    fn evaluate_switch_cases(
+        &self,
+        eval: &EvaluateData<'src>,
+    ) -> Result<Option<Vec<(i64, usize)>>> {
+        let [EvalSubject::Expr(Expr::Value(parser::Value::Variable(subject)))] =
+            eval.subjects.as_slice()
+        else {
+            return Ok(None);
+        };
+        let pic = self.data.sym_pic(subject)?;
+        if pic.is_str() || pic.is_float() || pic.scale() != 0 {
+            return Ok(None);
+        }
+
+        let int_literal = |expr: &Expr<'src>| match expr {
+            Expr::Value(parser::Value::Literal(Literal::Int(i))) => Some(*i),
+            _ => None,
+        };
+        let mut cases: Vec<(i64, usize)> = Vec::new();
+        for (idx, when) in eval.whens.iter().enumerate() {
+            for objects in when.object_sets.iter() {
+                let [EvalObject::Value {
+                    negated: false,
+                    from,
+                    thru,
+                }] = objects.as_slice()
+                else {
+                    return Ok(None);
+                };
+                let Some(from) = int_literal(from) else {
+                    return Ok(None);
+                };
+                let thru = match thru {
+                    Some(thru) => match int_literal(thru) {
+                        Some(thru) if thru.saturating_sub(from) < EVAL_SWITCH_MAX_RANGE => thru,
+                        _ => return Ok(None),
+                    },
+                    None => from,
+                };
+                cases.extend((from..=thru).map(|case| (case, idx)));
+            }
+        }
+        Ok(Some(cases))
+    }
+
+    /// Translates a check of whether each of the given objects of a single "WHEN" phrase matches
+    /// the subject in the same position, returning the outcome of the check.
+    fn translate_eval_objects(
+        &mut self,
+        subjects: &[EvalSubject<'src>],
+        objects: &[EvalObject<'src>],
+    ) -> Result<Value> {
+        let mut result: Option<Value> = None;
+        for (subject, object) in subjects.iter().zip(objects.iter()) {
+            let matched = match (subject, object) {
+                (_, EvalObject::Any) => continue,
+                (EvalSubject::Bool(truth), EvalObject::Cond(cond)) => {
+                    let cond_val = self.translate_cond_eval(cond)?;
+                    if *truth {
+                        cond_val
+                    } else {
+                        self.builder.ins().bxor_imm(cond_val, 0x1)
+                    }
+                }
+                (
+                    EvalSubject::Expr(subject),
+                    EvalObject::Value {
+                        negated,
+                        from,
+                        thru,
+                    },
+                ) => {
+                    let matched = match thru {
+                        None => {
+                            self.verify_binary_eq_cmp(subject, from)?;
+                            self.translate_cond_comp(subject, from, IntCC::Equal)?
+                        }
+                        Some(thru) => {
+                            self.verify_binary_ord_cmp(subject, from)?;
+                            self.verify_binary_ord_cmp(subject, thru)?;
+                            let above_from = self.translate_cond_comp(
+                                subject,
+                                from,
+                                IntCC::SignedGreaterThanOrEqual,
+                            )?;
+                            let below_thru = self.translate_cond_comp(
+                                subject,
+                                thru,
+                                IntCC::SignedLessThanOrEqual,
+                            )?;
+                            self.builder.ins().band(above_from, below_thru)
+                        }
+                    };
+                    if *negated {
+                        self.builder.ins().bxor_imm(matched, 0x1)
+                    } else {
+                        matched
+                    }
+                }
+                _ => unreachable!("EVALUATE object does not match the kind of its subject."),
+            };
+            result = Some(match result {
+                Some(prev) => self.builder.ins().band(prev, matched),
+                None => matched,
+            });
+        }
+
+        // Phrases made up only of "ANY" objects always match.
+        Ok(result.unwrap_or_else(|| self.builder.ins().iconst(types::I8, 1)))
+    }
+
     /// Translates an evaluation of the given conditional, returning the outcome of the condition.
     /// On the condition being true, the return value is an i64 with a value of 1.
     /// On the condition being false, the return value is an i64 with a value of 0.
@@ -94,76 +279,131 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
 
         match cond {
             // Comparisons.
-            Cond::Eq(l, r) => self.translate_cond_comp(l, r, IntCC::Equal, FloatCC::Equal),
-            Cond::Ge(l, r) => self.translate_cond_comp(
-                l,
-                r,
-                IntCC::SignedGreaterThanOrEqual,
-                FloatCC::GreaterThanOrEqual,
-            ),
-            Cond::Le(l, r) => self.translate_cond_comp(
-                l,
-                r,
-                IntCC::SignedLessThanOrEqual,
-                FloatCC::LessThanOrEqual,
-            ),
-            Cond::Gt(l, r) => {
-                self.translate_cond_comp(l, r, IntCC::SignedGreaterThan, FloatCC::GreaterThan)
-            }
-            Cond::Lt(l, r) => {
-                self.translate_cond_comp(l, r, IntCC::SignedLessThan, FloatCC::LessThan)
-            }
+            Cond::Eq(l, r) => self.translate_cond_comp(l, r, IntCC::Equal),
+            Cond::Ge(l, r) => self.translate_cond_comp(l, r, IntCC::SignedGreaterThanOrEqual),
+            Cond::Le(l, r) => self.translate_cond_comp(l, r, IntCC::SignedLessThanOrEqual),
+            Cond::Gt(l, r) => self.translate_cond_comp(l, r, IntCC::SignedGreaterThan),
+            Cond::Lt(l, r) => self.translate_cond_comp(l, r, IntCC::SignedLessThan),
 
             // Recursive conditions.
             Cond::Not(inner) => self.translate_cond_not(inner),
             Cond::And(l, r) => self.translate_cond_and(l, r),
             Cond::Or(l, r) => self.translate_cond_or(l, r),
+
+            // Condition names.
+            Cond::Name(cond_ref) => self.translate_cond_name(cond_ref),
         }
     }
 
     /// Translates a single comparison condition into a given value.
-    fn translate_cond_comp(
+    /// Ordinal comparisons of strings are performed as a COBOL alphanumeric comparison, with the
+    /// shorter string treated as if padded with trailing spaces.
+    /// Numeric comparisons are performed after aligning both sides to the same decimal places.
+    /// Arithmetic expressions keep additional decimal places for any inexact intermediate results.
+    pub(super) fn translate_cond_comp(
         &mut self,
-        l: &parser::Value<'src>,
-        r: &parser::Value<'src>,
+        l: &Expr<'src>,
+        r: &Expr<'src>,
         int_cc: IntCC,
-        float_cc: FloatCC,
     ) -> Result<Value> {
-        let (mut l_val, mut r_val) = (self.load_value(l)?, self.load_value(r)?);
-        let use_float_cmp = l.is_float(self.data)? || r.is_float(self.data)?;
-
-        // If the comparison requires a floating point comparison, convert both sides to float.
-        if use_float_cmp {
-            if !l.is_float(self.data)? {
-                l_val = self.builder.ins().fcvt_from_sint(types::F64, l_val);
-            }
-            if !r.is_float(self.data)? {
-                r_val = self.builder.ins().fcvt_from_sint(types::F64, r_val);
-            }
+        // Figurative constants are compared as if they were the same length as the other operand.
+        if let Some(fig) = r.as_figurative() {
+            return self.translate_cond_comp_figurative(l, fig, int_cc);
+        }
+        if let Some(fig) = l.as_figurative() {
+            return self.translate_cond_comp_figurative(r, fig, int_cc.swap_args());
         }
 
         // Perform the comparison based on type.
         let result = if l.is_str(self.data)? || r.is_str(self.data)? {
-            // String comparison, this must be an equality. We must use our `strcmp` intrinsic.
-            assert!(int_cc == IntCC::Equal && float_cc == FloatCC::Equal);
-            let strcmp =
-                self.intrinsics
-                    .get_ref(self.module, self.builder.func, CobaltIntrinsic::StrCmp)?;
-            let inst = self.builder.ins().call(strcmp, &[l_val, r_val]);
-            *self
-                .builder
-                .inst_results(inst)
-                .first()
-                .expect("Strcmp intrinsic does not return a result.")
-        } else if use_float_cmp {
-            self.builder.ins().fcmp(float_cc, l_val, r_val)
+            let (Some(l), Some(r)) = (l.as_value(), r.as_value()) else {
+                miette::bail!("Cannot compare a string to an arithmetic expression.");
+            };
+            let (l_val, r_val) = (self.load_value(l)?, self.load_value(r)?);
+            let (l_len, r_len) = (self.str_value_len(l)?, self.str_value_len(r)?);
+            self.translate_cond_comp_str(l_val, l_len, r_val, r_len, int_cc)?
         } else {
+            let scale = l.max_scale(self.data)?.max(r.max_scale(self.data)?) + EXPR_GUARD_DIGITS;
+            let ((l_num, _), (r_num, _)) = (
+                self.translate_expr(l, scale)?,
+                self.translate_expr(r, scale)?,
+            );
+            let scale = l_num.scale.max(r_num.scale);
+            let l_val = self.rescale_num(l_num, scale);
+            let r_val = self.rescale_num(r_num, scale);
             self.builder.ins().icmp(int_cc, l_val, r_val)
         };
 
         Ok(result)
     }
 
+    /// Translates a single comparison between the given operand and a figurative constant on the
+    /// right hand side into a given value.
+    /// Against a numeric operand, only "ZERO" may be used, which is compared as the number zero.
+    /// Otherwise, the constant is filled out to the length of the operand before comparison.
+    fn translate_cond_comp_figurative(
+        &mut self,
+        operand: &Expr<'src>,
+        fig: Figurative,
+        int_cc: IntCC,
+    ) -> Result<Value> {
+        if !operand.is_str(self.data)? {
+            if fig != Figurative::Zeros {
+                miette::bail!(
+                    "Cannot compare a numeric value to the figurative constant '{}'.",
+                    Literal::Figurative(fig).text(&self.ast.str_lits)
+                );
+            }
+            let zero = Expr::Value(parser::Value::Literal(Literal::Int(0)));
+            return self.translate_cond_comp(operand, &zero, int_cc);
+        }
+        let Some(operand) = operand.as_value() else {
+            unreachable!("String expressions must consist of a single value.");
+        };
+        let len = self.str_value_len(operand)?;
+        let operand_val = self.load_value(operand)?;
+        let fig_val = self.load_figurative(&fig, len)?;
+        self.translate_cond_comp_str(operand_val, len, fig_val, len, int_cc)
+    }
+
+    /// Translates a single comparison between two strings of the given maximum lengths into a
+    /// given value, using the `strcmp`/`strord` intrinsics.
+    fn translate_cond_comp_str(
+        &mut self,
+        l_val: Value,
+        l_len: usize,
+        r_val: Value,
+        r_len: usize,
+        int_cc: IntCC,
+    ) -> Result<Value> {
+        let intrinsic = if int_cc == IntCC::Equal {
+            CobaltIntrinsic::StrCmp
+        } else {
+            CobaltIntrinsic::StrOrd
+        };
+        let str_func = self
+            .intrinsics
+            .get_ref(self.module, self.builder.func, intrinsic)?;
+        let l_len = self.builder.ins().iconst(types::I64, l_len as i64);
+        let r_len = self.builder.ins().iconst(types::I64, r_len as i64);
+        let inst = self
+            .builder
+            .ins()
+            .call(str_func, &[l_val, l_len, r_val, r_len]);
+        let str_result = *self
+            .builder
+            .inst_results(inst)
+            .first()
+            .expect("String comparison intrinsic does not return a result.");
+
+        // Ordinal comparisons return a value less than, equal to or greater than zero.
+        if intrinsic == CobaltIntrinsic::StrOrd {
+            Ok(self.builder.ins().icmp_imm(int_cc, str_result, 0))
+        } else {
+            Ok(str_result)
+        }
+    }
+
     /// Translates a single inverted "NOT" condition into Cranelift IR, returning the generated value.
     fn translate_cond_not(&mut self, inner: &Cond<'src>) -> Result<Value> {
         let inner_val = self.translate_cond_eval(inner)?;
@@ -182,6 +422,75 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         Ok(self.builder.ins().bor(l_val, r_val))
     }
 
+    /// Translates a single condition name check into Cranelift IR, returning the generated value.
+    /// The condition holds when its item matches any one of the condition's values or ranges.
+    fn translate_cond_name(&mut self, cond_ref: &DataRef<'src>) -> Result<Value> {
+        let (item_ref, cond_name) = self.resolve_cond_name(cond_ref)?;
+        let item = Expr::Value(parser::Value::Variable(item_ref));
+
+        let mut result: Option<Value> = None;
+        for value in cond_name.values.iter() {
+            let from = Expr::Value(parser::Value::Literal(value.from));
+            let matches = match value.thru {
+                None => self.translate_cond_comp(&item, &from, IntCC::Equal)?,
+                Some(thru) => {
+                    let thru = Expr::Value(parser::Value::Literal(thru));
+                    let above_from =
+                        self.translate_cond_comp(&item, &from, IntCC::SignedGreaterThanOrEqual)?;
+                    let below_thru =
+                        self.translate_cond_comp(&item, &thru, IntCC::SignedLessThanOrEqual)?;
+                    self.builder.ins().band(above_from, below_thru)
+                }
+            };
+            result = Some(match result {
+                Some(prev) => self.builder.ins().bor(prev, matches),
+                None => matches,
+            });
+        }
+        Ok(result.expect("Condition name declared with no values."))
+    }
+
+    /// Resolves the given condition name reference to its declaration, along with a reference to
+    /// the item it is declared for. Qualifiers on the condition name select between conditions
+    /// sharing the same name, and subscripts on the condition name apply to the item.
+    pub(super) fn resolve_cond_name(
+        &self,
+        cond_ref: &DataRef<'src>,
+    ) -> Result<(DataRef<'src>, &'a CondName<'src>)> {
+        let ast = self.ast;
+        let mut found: Vec<(&'a DataItem<'src>, Vec<&'src str>, &'a CondName<'src>)> = Vec::new();
+        if let Some(data_div) = ast.data_div.as_ref() {
+            find_cond_names(
+                &data_div.ws_section.items,
+                cond_ref.name,
+                &mut Vec::new(),
+                &mut found,
+            );
+        }
+        found.retain(|(item, ancestors, _)| {
+            let mut names = std::iter::once(&item.name).chain(ancestors.iter());
+            cond_ref
+                .qualifiers
+                .iter()
+                .all(|qualifier| names.any(|name| name == qualifier))
+        });
+        match found.as_slice() {
+            [(item, ancestors, cond_name)] => Ok((
+                DataRef {
+                    name: item.name,
+                    qualifiers: ancestors.clone(),
+                    subscripts: cond_ref.subscripts.clone(),
+                },
+                *cond_name,
+            )),
+            [] => miette::bail!("No declared condition name '{}'.", cond_ref.name),
+            _ => miette::bail!(
+                "Reference to condition name '{}' is ambiguous, as multiple conditions share that name.",
+                cond_ref.name
+            ),
+        }
+    }
+
     /// Verifies that the condition provided is sane, and can be computed.
     fn verify_cond(&self, cond: &Cond<'src>) -> Result<()> {
         match cond {
@@ -206,15 +515,18 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                 self.verify_cond(r)?;
                 Ok(())
             }
+
+            // Condition names are verified upon resolution.
+            Cond::Name(_) => Ok(()),
         }
     }
 
-    /// Verifies that the two values can be checked for equality.
-    fn verify_binary_eq_cmp(
-        &self,
-        left: &parser::Value<'src>,
-        right: &parser::Value<'src>,
-    ) -> Result<()> {
+    /// Verifies that the two operands can be checked for equality.
+    pub(super) fn verify_binary_eq_cmp(&self, left: &Expr<'src>, right: &Expr<'src>) -> Result<()> {
+        // Figurative constants take on the class of the operand they are compared with.
+        if left.as_figurative().is_some() || right.as_figurative().is_some() {
+            return Ok(());
+        }
         if (left.is_str(self.data)? && !right.is_str(self.data)?)
             || (!left.is_str(self.data)? && right.is_str(self.data)?)
         {
@@ -223,12 +535,8 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         Ok(())
     }
 
-    /// Verifies that the two values can be compared ordinally.
-    fn verify_binary_ord_cmp(
-        &self,
-        left: &parser::Value<'src>,
-        right: &parser::Value<'src>,
-    ) -> Result<()> {
+    /// Verifies that the two operands can be compared ordinally.
+    fn verify_binary_ord_cmp(&self, left: &Expr<'src>, right: &Expr<'src>) -> Result<()> {
         // String types cannot be ordinally compared.
         if left.is_str(self.data)? || right.is_str(self.data)? {
             miette::bail!("Cannot ordinally compare string variables.");
@@ -236,3 +544,27 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         Ok(())
     }
 }
+
+/// Finds all condition names with the given name declared within the given items (and their
+/// subordinate items), along with the item each is declared for and the names of the groups
+/// containing that item, from the innermost outwards.
+fn find_cond_names<'a, 'src>(
+    items: &'a [DataItem<'src>],
+    name: &str,
+    ancestors: &mut Vec<&'src str>,
+    found: &mut Vec<(&'a DataItem<'src>, Vec<&'src str>, &'a CondName<'src>)>,
+) {
+    for item in items {
+        found.extend(
+            item.conditions
+                .iter()
+                .filter(|c| c.name == name)
+                .map(|c| (item, ancestors.iter().rev().copied().collect(), c)),
+        );
+        if let DataItemKind::Group(children) = &item.kind {
+            ancestors.push(item.name);
+            find_cond_names(children, name, ancestors, found);
+            ancestors.pop();
+        }
+    }
+}
@@ -1,21 +1,24 @@
 use cranelift::{
-    codegen::{
-        entity::EntityRef,
-        ir::{types, InstBuilder},
-    },
-    frontend::Variable,
+    codegen::ir::{types, Block, InstBuilder},
+    frontend::Switch,
 };
 use miette::Result;
 
-use crate::compiler::parser::{self, Cond, ExitType, Literal, PerformType};
+use crate::compiler::parser::{
+    self, Cond, ExitType, Figurative, GoToData, Literal, PerformTarget, PerformType, ProcRef,
+    VaryingLoop,
+};
 
-use super::FuncTranslator;
+use super::{return_code_ref, FuncTranslator};
 
 impl<'a, 'src> FuncTranslator<'a, 'src> {
     /// Translates the given PERFORM statement into Cranelift IR.
     pub(super) fn translate_perform(&mut self, perform: &PerformType<'src>) -> Result<()> {
         match perform {
-            PerformType::Single(target) => self.translate_perform_single(target),
+            PerformType::Single(PerformTarget::Paragraph(target)) => {
+                self.translate_perform_single(target)
+            }
+            PerformType::Single(target) => self.translate_perform_once(target),
             PerformType::Times(target, times) => self.translate_perform_times(target, times),
             PerformType::Thru(start, end) => self.translate_perform_thru(start, end),
             PerformType::Until {
@@ -23,20 +26,61 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                 cond,
                 test_cond_before,
             } => self.translate_perform_until(target, cond, *test_cond_before),
+            PerformType::Varying {
+                target,
+                loops,
+                test_cond_before,
+            } => self.translate_perform_varying(target, loops, *test_cond_before),
         }
     }
 
     /// Translates a single-target PERFORM statement to Cranelift IR.
-    fn translate_perform_single(&mut self, target: &'src str) -> Result<()> {
-        let func_ref = self.funcs.get_ref(self.module, self.builder.func, target)?;
-        self.builder.ins().call(func_ref, &[]);
+    /// Performing a section executes all of its paragraphs in order.
+    fn translate_perform_single(&mut self, target: &ProcRef<'src>) -> Result<()> {
+        let (start_idx, end_idx) = self.resolve_proc(target)?;
+        self.translate_perform_range(start_idx, end_idx)
+    }
+
+    /// Translates a single execution of the given inline PERFORM target to Cranelift IR.
+    fn translate_perform_once(&mut self, target: &PerformTarget<'src>) -> Result<()> {
+        let trailing_block = self.builder.create_block();
+        self.translate_perform_target(target, trailing_block)?;
+        self.builder.ins().jump(trailing_block, &[]);
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
+        Ok(())
+    }
+
+    /// Translates a single execution of the given PERFORM target to Cranelift IR, given the block
+    /// to leave for once the PERFORM statement is complete.
+    /// Inline statements are translated directly into the current function, where "EXIT PERFORM"
+    /// leaves for the given block, and "EXIT PERFORM CYCLE" ends this execution early.
+    fn translate_perform_target(
+        &mut self,
+        target: &PerformTarget<'src>,
+        exit_block: Block,
+    ) -> Result<()> {
+        let stats = match target {
+            PerformTarget::Paragraph(name) => return self.translate_perform_single(name),
+            PerformTarget::Inline(stats) => stats,
+        };
+
+        let cycle_block = self.builder.create_block();
+        self.perform_exits.push((exit_block, cycle_block));
+        let block_self_terminates = self.translate_stats(stats)?;
+        self.perform_exits.pop();
+        if !block_self_terminates {
+            self.builder.ins().jump(cycle_block, &[]);
+        }
+        self.builder.seal_block(cycle_block);
+        self.switch_to_block(cycle_block);
         Ok(())
     }
 
     /// Translates a single-target repeating PERFORM statement to Cranelift IR.
     fn translate_perform_times(
         &mut self,
-        target: &'src str,
+        target: &PerformTarget<'src>,
         times: &parser::Value<'src>,
     ) -> Result<()> {
         // Ensure the number of times provided is an integer.
@@ -52,14 +96,14 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                         return Ok(());
                     }
                 }
+                Literal::Figurative(Figurative::Zeros) => return Ok(()),
                 _ => unreachable!(),
             }
         }
 
         // Evaluate the number of times to perform the block, create a variable to store this.
         let times_init_val = self.load_value(times)?;
-        let counter_var = Variable::new(0);
-        self.builder.declare_var(counter_var, types::I64);
+        let counter_var = self.declare_var(types::I64);
         self.builder.def_var(counter_var, times_init_val);
 
         // Create blocks required for this loop, transition to & seal loop block.
@@ -78,7 +122,7 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         // Fill the loop body block.
         self.switch_to_block(loop_body_block);
         self.builder.seal_block(loop_body_block);
-        self.translate_perform_single(target)?;
+        self.translate_perform_target(target, trailing_block)?;
         let counter_val = self.builder.use_var(counter_var);
         let new_counter_val = self.builder.ins().iadd_imm(counter_val, -1);
         self.builder.def_var(counter_var, new_counter_val);
@@ -93,34 +137,13 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
     }
 
     /// Translates a multi-target PERFORM statement to Cranelift IR.
-    fn translate_perform_thru(&mut self, start: &'src str, end: &'src str) -> Result<()> {
-        let start_idx = self
-            .ast
-            .proc_div
-            .paragraphs
-            .iter()
-            .enumerate()
-            .filter(|(_, para)| para.name.is_some_and(|n| n.0 == start))
-            .map(|x| x.0)
-            .next();
-        let end_idx = self
-            .ast
-            .proc_div
-            .paragraphs
-            .iter()
-            .enumerate()
-            .filter(|(_, para)| para.name.is_some_and(|n| n.0 == end))
-            .map(|x| x.0)
-            .next();
-
-        // Verify that both were found.
-        if start_idx.is_none() || end_idx.is_none() {
-            miette::bail!("Start or end paragraph could not be found for PERFORM THRU.");
-        }
-        let (start_idx, end_idx) = (start_idx.unwrap(), end_idx.unwrap());
+    /// Either end of the range may be a section, which includes all of its paragraphs.
+    fn translate_perform_thru(&mut self, start: &ProcRef<'src>, end: &ProcRef<'src>) -> Result<()> {
+        let (start_idx, start_last) = self.resolve_proc(start)?;
+        let (end_first, end_idx) = self.resolve_proc(end)?;
 
         // Verify that the order makes sense.
-        if start_idx == end_idx {
+        if (start_idx, start_last) == (end_first, end_idx) {
             miette::bail!("Cannot execute a PERFORM THRU on a single paragraph.");
         }
         if start_idx > end_idx {
@@ -129,26 +152,14 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
             );
         }
 
-        // Iterate & generate calls.
-        for para in self
-            .ast
-            .proc_div
-            .paragraphs
-            .iter()
-            .skip(start_idx)
-            .take(end_idx - start_idx + 1)
-        {
-            if let Some((name, _)) = para.name {
-                self.translate_perform_single(name)?;
-            }
-        }
-        Ok(())
+        // Execute the range, falling through between paragraphs.
+        self.translate_perform_range(start_idx, end_idx)
     }
 
     /// Translates a single-target repeating conditional PERFORM statement to Cranelift IR.
     fn translate_perform_until(
         &mut self,
-        target: &'src str,
+        target: &PerformTarget<'src>,
         cond: &Cond<'src>,
         test_before: bool,
     ) -> Result<()> {
@@ -175,7 +186,7 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         // Emit the body block.
         self.switch_to_block(loop_body_block);
         self.builder.seal_block(loop_body_block);
-        self.translate_perform_single(target)?;
+        self.translate_perform_target(target, trailing_block)?;
         self.builder.ins().jump(loop_test_block, &[]);
 
         // Switch to the trailing block, we're done!
@@ -185,13 +196,211 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         Ok(())
     }
 
+    /// Translates a single-target PERFORM VARYING statement to Cranelift IR.
+    /// Each loop has a test block checking its condition and a step block advancing its item,
+    /// which also resets the items of all inner loops to their initial values.
+    /// When testing before, each loop's test is performed outermost first ahead of the body, with a
+    /// finished inner loop stepping the loop outside it. When testing after, each loop's test is
+    /// performed innermost first following the body, with a finished inner loop moving on to the test
+    /// of the loop outside it.
+    fn translate_perform_varying(
+        &mut self,
+        target: &PerformTarget<'src>,
+        loops: &[VaryingLoop<'src>],
+        test_before: bool,
+    ) -> Result<()> {
+        // Initialise the items of all loops.
+        for varying in loops.iter() {
+            self.verify_varying_loop(varying)?;
+            self.translate_varying_reset(varying)?;
+        }
+
+        // Create the blocks required for this statement.
+        let test_blocks: Vec<Block> = loops.iter().map(|_| self.builder.create_block()).collect();
+        let step_blocks: Vec<Block> = loops.iter().map(|_| self.builder.create_block()).collect();
+        let loop_body_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+        let (first_test_block, last_test_block) = (test_blocks[0], test_blocks[loops.len() - 1]);
+        let last_step_block = step_blocks[loops.len() - 1];
+
+        // Jump to the correct block for the test location.
+        let next_block = if test_before {
+            first_test_block
+        } else {
+            loop_body_block
+        };
+        self.builder.ins().jump(next_block, &[]);
+
+        // Emit the check blocks.
+        for (idx, varying) in loops.iter().enumerate() {
+            let (done_block, continue_block) = match (test_before, idx) {
+                (true, 0) => (trailing_block, test_blocks.get(1).copied()),
+                (true, _) => (step_blocks[idx - 1], test_blocks.get(idx + 1).copied()),
+                (false, 0) => (trailing_block, Some(step_blocks[idx])),
+                (false, _) => (test_blocks[idx - 1], Some(step_blocks[idx])),
+            };
+            self.switch_to_block(test_blocks[idx]);
+            let cond_val = self.translate_cond_eval(&varying.until)?;
+            self.builder.ins().brif(
+                cond_val,
+                done_block,
+                &[],
+                continue_block.unwrap_or(loop_body_block),
+                &[],
+            );
+        }
+
+        // Emit the body block.
+        self.switch_to_block(loop_body_block);
+        self.translate_perform_target(target, trailing_block)?;
+        let next_block = if test_before {
+            last_step_block
+        } else {
+            last_test_block
+        };
+        self.builder.ins().jump(next_block, &[]);
+
+        // Emit the step blocks.
+        for (idx, varying) in loops.iter().enumerate() {
+            self.switch_to_block(step_blocks[idx]);
+            self.translate_varying_step(varying)?;
+            for inner in loops.iter().skip(idx + 1) {
+                self.translate_varying_reset(inner)?;
+            }
+            let next_block = if test_before {
+                test_blocks[idx]
+            } else {
+                loop_body_block
+            };
+            self.builder.ins().jump(next_block, &[]);
+        }
+
+        // Switch to the trailing block, we're done!
+        for block in test_blocks.into_iter().chain(step_blocks) {
+            self.builder.seal_block(block);
+        }
+        self.builder.seal_block(loop_body_block);
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
+        Ok(())
+    }
+
+    /// Translates setting the item of the given PERFORM VARYING loop to its initial value.
+    fn translate_varying_reset(&mut self, varying: &VaryingLoop<'src>) -> Result<()> {
+        let from = self.load_num(&varying.from)?;
+        let var_ptr = self.load_sym_ptr(&varying.var)?;
+        let var_pic = self.data.sym_pic(&varying.var)?.clone();
+        self.store_num(var_ptr, &var_pic, from)
+    }
+
+    /// Translates a single step of the given PERFORM VARYING loop, increasing its item.
+    fn translate_varying_step(&mut self, varying: &VaryingLoop<'src>) -> Result<()> {
+        let cur = self.load_num(&parser::Value::Variable(varying.var.clone()))?;
+        let by = self.load_num(&varying.by)?;
+        let next = self.add_nums(cur, by);
+        let var_ptr = self.load_sym_ptr(&varying.var)?;
+        let var_pic = self.data.sym_pic(&varying.var)?.clone();
+        self.store_num(var_ptr, &var_pic, next)
+    }
+
+    /// Verifies that the given PERFORM VARYING loop only varies numeric items by numeric values.
+    fn verify_varying_loop(&self, varying: &VaryingLoop<'src>) -> Result<()> {
+        if self.data.sym_pic(&varying.var)?.is_str() {
+            miette::bail!(
+                "The item '{}' varied by a PERFORM statement must be numeric.",
+                varying.var
+            );
+        }
+        if varying.from.is_str(self.data)? || varying.by.is_str(self.data)? {
+            miette::bail!(
+                "The 'FROM' and 'BY' values varying '{}' must be numeric.",
+                varying.var
+            );
+        }
+        Ok(())
+    }
+
     /// Translates a single exit instruction to Cranelift IR.
     pub(super) fn translate_exit(&mut self, exit_type: &ExitType) -> Result<()> {
         match exit_type {
             ExitType::Paragraph => {
-                self.builder.ins().return_(&[]);
+                let Some(para_idx) = self.cur_para else {
+                    miette::bail!("EXIT PARAGRAPH statements must be within a paragraph.");
+                };
+                self.builder.ins().jump(self.paras[para_idx].exit, &[]);
             }
+            ExitType::Section => {
+                let Some(para_idx) = self.cur_para else {
+                    miette::bail!("EXIT SECTION statements must be within a section.");
+                };
+                let (_, last_para) = self.sections[self.paras[para_idx].section];
+                self.builder.ins().jump(self.paras[last_para].exit, &[]);
+            }
+            ExitType::Program => {}
+            ExitType::Perform | ExitType::PerformCycle => {
+                let Some((exit_block, cycle_block)) = self.perform_exits.last().copied() else {
+                    miette::bail!(
+                        "EXIT PERFORM statements must be within an inline PERFORM statement."
+                    );
+                };
+                let target_block = if *exit_type == ExitType::Perform {
+                    exit_block
+                } else {
+                    cycle_block
+                };
+                self.builder.ins().jump(target_block, &[]);
+            }
+        }
+        Ok(())
+    }
+
+    /// Translates a single "STOP RUN" statement to Cranelift IR.
+    /// Any exit status given is stored in the "RETURN-CODE" special register before terminating.
+    pub(super) fn translate_stop_run(
+        &mut self,
+        status: &Option<parser::Value<'src>>,
+    ) -> Result<()> {
+        if let Some(status) = status {
+            let status = self.load_num(status)?;
+            let return_code = return_code_ref();
+            let return_code_ptr = self.load_sym_ptr(&return_code)?;
+            let return_code_pic = self.data.sym_pic(&return_code)?.clone();
+            self.store_num(return_code_ptr, &return_code_pic, status)?;
         }
+        self.translate_terminate()?;
+        self.translate_return()
+    }
+
+    /// Translates a single "GO TO" statement to Cranelift IR.
+    /// With a "DEPENDING ON" phrase, control is transferred to the paragraph selected by the item's
+    /// value, or continues in a new block when the value is out of range.
+    pub(super) fn translate_go_to(&mut self, go_to: &GoToData<'src>) -> Result<()> {
+        let target_blocks = go_to
+            .targets
+            .iter()
+            .map(|target| Ok(self.paras[self.resolve_proc(target)?.0].entry))
+            .collect::<Result<Vec<Block>>>()?;
+        let Some(depending_on) = &go_to.depending_on else {
+            self.builder.ins().jump(target_blocks[0], &[]);
+            return Ok(());
+        };
+
+        if self.data.sym_pic(depending_on)?.is_str() {
+            miette::bail!(
+                "The item '{}' selecting a GO TO paragraph must be numeric.",
+                depending_on
+            );
+        }
+        let selector = self.load_num(&parser::Value::Variable(depending_on.clone()))?;
+        let selector = self.rescale_num(selector, 0);
+        let mut switch = Switch::new();
+        for (idx, target_block) in target_blocks.into_iter().enumerate() {
+            switch.set_entry(idx as u128 + 1, target_block);
+        }
+        let trailing_block = self.builder.create_block();
+        switch.emit(&mut self.builder, selector, trailing_block);
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
         Ok(())
     }
 }
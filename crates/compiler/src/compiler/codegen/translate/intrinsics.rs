@@ -16,16 +16,23 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         let intrinsic = self.intrinsics.resolve_name(call.name)?;
         let sig = self.intrinsics.get_signature(self.module, intrinsic);
 
-        // Verify that the call arguments match the signature.
-        if call.args.len() != sig.params.len() {
-            miette::bail!("Number of arguments to intrinsic function '{}' do not match signature ({} instead of {}).", call.name, call.args.len(), sig.params.len());
-        }
+        // Load all arguments, verifying that they match the signature as we go.
+        // String arguments are passed as a pointer, followed by the maximum length of the string.
         let ptr_type = self.module.target_config().pointer_type();
-        for (idx, (val, param)) in call.args.iter().zip(sig.params.iter()).enumerate() {
+        let mut params = sig.params.iter();
+        let mut arg_vals: Vec<Value> = Vec::new();
+        for (idx, val) in call.args.iter().enumerate() {
+            let Some(param) = params.next() else {
+                miette::bail!(
+                    "Number of arguments to intrinsic function '{}' do not match signature.",
+                    call.name
+                );
+            };
             let (is_str, is_float) = (val.is_str(self.data)?, val.is_float(self.data)?);
             if is_str && param.value_type != ptr_type
                 || is_float && param.value_type != types::F64
                 || !is_str && !is_float && param.value_type != types::I64
+                || is_str && params.next().is_none()
             {
                 miette::bail!(
                     "Mismatched argument type for argument {} in intrinsic function '{}'.",
@@ -33,14 +40,29 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                     call.name
                 );
             }
+
+            // Fixed point arguments are converted to floating point at the call boundary.
+            if is_float {
+                let num = self.load_num(val)?;
+                let fval = self.builder.ins().fcvt_from_sint(types::F64, num.val);
+                let divisor = self.builder.ins().f64const(10f64.powi(num.scale as i32));
+                arg_vals.push(self.builder.ins().fdiv(fval, divisor));
+            } else {
+                arg_vals.push(self.load_value(val)?);
+            }
+            if is_str {
+                let max_len = self.str_value_len(val)?;
+                arg_vals.push(self.builder.ins().iconst(types::I64, max_len as i64));
+            }
+        }
+        if params.next().is_some() {
+            miette::bail!(
+                "Number of arguments to intrinsic function '{}' do not match signature.",
+                call.name
+            );
         }
 
         // Prepare the call.
-        let arg_vals = call
-            .args
-            .iter()
-            .map(|arg| self.load_value(arg))
-            .collect::<Result<Vec<_>>>()?;
         let intrinsic_ref = self
             .intrinsics
             .get_ref(self.module, self.builder.func, intrinsic)?;
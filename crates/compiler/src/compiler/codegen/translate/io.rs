@@ -1,51 +1,123 @@
-use crate::compiler::{codegen::intrinsics::CobaltIntrinsic, parser};
-use cranelift::codegen::ir::{immediates::Offset32, InstBuilder, MemFlags};
+use crate::compiler::{
+    codegen::intrinsics::CobaltIntrinsic,
+    parser::{self, DataRef, Figurative, Literal, Pic},
+};
+use cranelift::codegen::ir::{types, InstBuilder, Value};
 use cranelift_module::Module;
 use miette::Result;
 
-use super::{value::CodegenLiteral, FuncTranslator};
+use super::FuncTranslator;
 
 impl<'a, 'src> FuncTranslator<'a, 'src> {
     /// Generates Cranelift IR for a single "DISPLAY" statement.
     pub(super) fn translate_display(&mut self, values: &Vec<parser::Value<'src>>) -> Result<()> {
         // Print each value in order.
         for val in values {
-            // Load the value to display.
-            let display_val = self.load_value(val)?;
+            match val {
+                parser::Value::Variable(sym) => {
+                    // Group items are displayed as each of their elementary items in turn.
+                    let sym_id = self.data.resolve_ref(sym)?;
+                    let sym_ptr = self.load_sym_ptr(sym)?;
+                    self.translate_for_each_elementary(
+                        sym_id,
+                        sym_ptr,
+                        &mut |this, elem_id, elem_ptr| {
+                            let elem_pic = this.data.sym(elem_id).pic.clone();
+                            this.translate_display_elementary(elem_ptr, &elem_pic)
+                        },
+                    )?;
+                }
+                parser::Value::Literal(lit) => {
+                    let display_val = self.load_lit(lit)?;
+                    match lit {
+                        Literal::Int(_) | Literal::Figurative(Figurative::Zeros) => {
+                            self.translate_display_num(display_val, CobaltIntrinsic::PrintInt)?
+                        }
+                        Literal::Decimal(d) => self.translate_display_dec(display_val, d.scale)?,
+                        Literal::String(_) | Literal::Figurative(_) => {
+                            let str_len = self.str_value_len(val)?;
+                            self.translate_display_str(display_val, str_len)?;
+                        }
+                    }
+                }
+            }
+        }
 
-            // Get the relevant intrinsic to output the value.
-            let display_intrinsic = if val.is_str(self.data)? {
-                CobaltIntrinsic::PrintStr
-            } else if val.is_float(self.data)? {
-                CobaltIntrinsic::PrintFloat
-            } else {
-                CobaltIntrinsic::PrintInt
-            };
+        // All values displayed, now print a newline.
+        let print_nl =
+            self.intrinsics
+                .get_ref(self.module, self.builder.func, CobaltIntrinsic::PrintNl)?;
+        self.builder.ins().call(print_nl, &[]);
 
-            // Call intrinsic to print the value.
-            let display_func =
-                self.intrinsics
-                    .get_ref(self.module, self.builder.func, display_intrinsic)?;
-            self.builder.ins().call(display_func, &[display_val]);
+        Ok(())
+    }
+
+    /// Generates Cranelift IR for displaying a single elementary item, stored at the given pointer.
+    fn translate_display_elementary(&mut self, ptr: Value, pic: &Pic) -> Result<()> {
+        if pic.is_decimal() {
+            return self.translate_display_decimal(ptr, pic);
+        }
+        let display_val = self.load_pic_value(ptr, pic)?;
+        if pic.is_str() {
+            self.translate_display_str(display_val, pic.storage_size())
+        } else if pic.is_float() {
+            self.translate_display_dec(display_val, pic.scale())
+        } else {
+            self.translate_display_num(display_val, CobaltIntrinsic::PrintInt)
         }
+    }
 
-        // All values displayed, now call "putchar" and insert a newline.
-        let putchar = self.intrinsics.get_ref(
-            self.module,
-            self.builder.func,
-            CobaltIntrinsic::LibcPutchar,
-        )?;
-        let newline = self.load_cg_lit(&CodegenLiteral::Char('\n'))?;
-        self.builder.ins().call(putchar, &[newline]);
+    /// Generates Cranelift IR for displaying a single decimal (DISPLAY or COMP-3) numeric item,
+    /// stored at the given pointer. All digits of the item are printed, along with the sign and
+    /// decimal point where present.
+    fn translate_display_decimal(&mut self, ptr: Value, pic: &Pic) -> Result<()> {
+        let val = self.load_decimal_raw(ptr, pic)?;
+        let digits = self.builder.ins().iconst(types::I64, pic.digits() as i64);
+        let scale = self.builder.ins().iconst(types::I64, pic.scale() as i64);
+        let signed = self.builder.ins().iconst(types::I8, pic.is_signed() as i64);
+        let print_fixed =
+            self.intrinsics
+                .get_ref(self.module, self.builder.func, CobaltIntrinsic::PrintFixed)?;
+        self.builder
+            .ins()
+            .call(print_fixed, &[val, digits, scale, signed]);
+        Ok(())
+    }
+
+    /// Generates Cranelift IR for displaying a single string of the given maximum length.
+    fn translate_display_str(&mut self, str_ptr: Value, max_len: usize) -> Result<()> {
+        let print_str =
+            self.intrinsics
+                .get_ref(self.module, self.builder.func, CobaltIntrinsic::PrintStr)?;
+        let len_val = self.builder.ins().iconst(types::I64, max_len as i64);
+        self.builder.ins().call(print_str, &[str_ptr, len_val]);
+        Ok(())
+    }
 
+    /// Generates Cranelift IR for displaying a single numeric value with the given print intrinsic.
+    fn translate_display_num(&mut self, val: Value, intrinsic: CobaltIntrinsic) -> Result<()> {
+        let display_func = self
+            .intrinsics
+            .get_ref(self.module, self.builder.func, intrinsic)?;
+        self.builder.ins().call(display_func, &[val]);
+        Ok(())
+    }
+
+    /// Generates Cranelift IR for displaying a single fixed point value with the given decimal places.
+    fn translate_display_dec(&mut self, val: Value, scale: u32) -> Result<()> {
+        let print_dec =
+            self.intrinsics
+                .get_ref(self.module, self.builder.func, CobaltIntrinsic::PrintDec)?;
+        let scale = self.builder.ins().iconst(types::I64, scale as i64);
+        self.builder.ins().call(print_dec, &[val, scale]);
         Ok(())
     }
 
     /// Generates Cranelift IR for a single "ACCEPT" statement.
-    pub(super) fn translate_accept(&mut self, target: &'src str) -> Result<()> {
+    pub(super) fn translate_accept(&mut self, target: &DataRef<'src>) -> Result<()> {
         // Determine the type of variable to accept.
-        let var_ptr = self.load_static_ptr(self.data.sym_data_id(target)?)?;
-        let target_pic = self.data.sym_pic(target)?;
+        let var_ptr = self.load_sym_ptr(target)?;
+        let target_pic = self.data.sym_pic(target)?.clone();
         if target_pic.is_str() {
             // Read a string.
             let readstr = self.intrinsics.get_ref(
@@ -60,22 +132,30 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                 .iconst(ptr_type, target_pic.byte_len as i64);
             self.builder.ins().call(readstr, &[var_ptr, buf_len]);
         } else {
-            // Read a value out.
-            let intrinsic = if target_pic.is_float() {
-                CobaltIntrinsic::ReadFloat
+            // Read a value out, scaled to the decimal places of the target.
+            let call_inst = if target_pic.is_float() {
+                let readdec = self.intrinsics.get_ref(
+                    self.module,
+                    self.builder.func,
+                    CobaltIntrinsic::ReadDec,
+                )?;
+                let scale = self
+                    .builder
+                    .ins()
+                    .iconst(types::I64, target_pic.scale() as i64);
+                self.builder.ins().call(readdec, &[scale])
             } else {
-                CobaltIntrinsic::ReadInt
+                let readint = self.intrinsics.get_ref(
+                    self.module,
+                    self.builder.func,
+                    CobaltIntrinsic::ReadInt,
+                )?;
+                self.builder.ins().call(readint, &[])
             };
-            let intrinsic_ref =
-                self.intrinsics
-                    .get_ref(self.module, self.builder.func, intrinsic)?;
-            let call_inst = self.builder.ins().call(intrinsic_ref, &[]);
             let result = *self.builder.inst_results(call_inst).first().unwrap();
 
             // Save the value.
-            self.builder
-                .ins()
-                .store(MemFlags::new(), result, var_ptr, Offset32::new(0));
+            self.store_pic_value(var_ptr, &target_pic, result)?;
         }
         Ok(())
     }
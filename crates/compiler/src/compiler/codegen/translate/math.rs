@@ -1,9 +1,19 @@
-use cranelift::codegen::ir::{immediates::Offset32, types, InstBuilder, MemFlags, Value};
+use cranelift::codegen::ir::{condcodes::IntCC, types, InstBuilder, Value};
 use miette::Result;
 
-use crate::compiler::parser::{self, BasicMathOpData, DivideData};
+use crate::compiler::{
+    codegen::intrinsics::CobaltIntrinsic,
+    parser::{
+        self, ArithDest, BasicMathOpData, BinOp, ComputeData, DivideData, Expr, RoundingMode,
+        SizeErrorPhrases,
+    },
+};
 
-use super::FuncTranslator;
+use super::{value::NumValue, FuncTranslator};
+
+/// The number of decimal places kept beyond those of the destinations for inexact intermediate
+/// results within an expression, such that the final result can be rounded correctly.
+pub(super) const EXPR_GUARD_DIGITS: u32 = 4;
 
 /// Variations of basic mathematical operations output by the code generator.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -35,158 +45,201 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         op_data: &BasicMathOpData<'src>,
         op_type: BasicMathOp,
     ) -> Result<()> {
+        if op_data.corresponding {
+            return self.translate_basic_op_corresponding(op_data, op_type);
+        }
+
         // Verify this instruction is sane.
         self.verify_basic_op_data(op_data)?;
 
         // First, load all the sources.
-        let mut src_vals: Vec<Value> = Vec::new();
+        let mut src_vals: Vec<NumValue> = Vec::new();
         for src in op_data.sources.iter() {
-            src_vals.push(self.load_value(src)?);
+            src_vals.push(self.load_num(src)?);
         }
 
-        // Determine which types of outputs exist in order to calculate sums.
-        let int_outputs_exist = op_data
-            .dests
-            .iter()
-            .map(|sym| self.data.sym_pic(sym).is_ok_and(|pic| !pic.is_float()))
-            .any(|b| b);
-        let float_outputs_exist = op_data
-            .dests
-            .iter()
-            .map(|sym| self.data.sym_pic(sym).is_ok_and(|pic| pic.is_float()))
-            .any(|b| b);
-
-        // If any of the destinations require float output, get a set of converted sources
-        // to float values only.
-        let float_srcs = if float_outputs_exist {
-            let mut out: Vec<Value> = Vec::new();
-            for (i, val) in src_vals.iter().enumerate() {
-                if !op_data.sources.iter().nth(i).unwrap().is_float(self.data)? {
-                    // Convert from [`i64`] to [`f64`].
-                    let fval = self.builder.ins().fcvt_from_sint(types::F64, *val);
-                    out.push(fval);
-                } else {
-                    // Already a float, no need to convert.
-                    out.push(*val);
-                }
-            }
-            Some(out)
-        } else {
-            None
-        };
-
         // Combine all of the source values to get a total without including the destination.
-        // We repeat this for float output if required.
-        let combined_srcs = int_outputs_exist
-            .then(|| self.combine_sources_vec(src_vals, op_type, op_data.overwrite_dests, false));
-        let combined_float_srcs = float_srcs
-            .map(|f_srcs| self.combine_sources_vec(f_srcs, op_type, op_data.overwrite_dests, true));
+        let src_sum_val = self.combine_sources_vec(src_vals, op_type, op_data.overwrite_dests);
 
         // For each destination, we need to generate a new calculation.
+        let mut size_errors: Vec<Value> = Vec::new();
         for dest in op_data.dests.iter() {
-            // Check whether this destination results in a floating point output.
-            let results_in_float = self.data.sym_pic(dest)?.is_float();
-
-            // Fetch the appropriate combined value for this destination.
-            let src_sum_val = if results_in_float {
-                *combined_float_srcs.as_ref().unwrap()
-            } else {
-                combined_srcs.unwrap()
-            };
-
             // If the destination is non-overwrite, we need to load it once and operate on that to
             // create our final result.
             let final_val = if !op_data.overwrite_dests {
-                // Load the destination as either a float/integer.
-                let dest_val = self.load_var(dest)?;
+                let dest_val = self.load_num(&parser::Value::Variable(dest.sym.clone()))?;
 
                 // Perform the operation.
                 match op_type {
-                    BasicMathOp::Add => {
-                        // out = src + dest
-                        if results_in_float {
-                            self.builder.ins().fadd(src_sum_val, dest_val)
-                        } else {
-                            self.builder.ins().iadd(src_sum_val, dest_val)
-                        }
-                    }
-                    BasicMathOp::Subtract => {
-                        // out = dest - src
-                        if results_in_float {
-                            self.builder.ins().fsub(dest_val, src_sum_val)
-                        } else {
-                            self.builder.ins().isub(dest_val, src_sum_val)
-                        }
-                    }
-                    BasicMathOp::Multiply => {
-                        // out = dest * src
-                        if results_in_float {
-                            self.builder.ins().fmul(src_sum_val, dest_val)
-                        } else {
-                            self.builder.ins().imul(src_sum_val, dest_val)
-                        }
-                    }
+                    // out = src + dest
+                    BasicMathOp::Add => self.combine_sources(src_sum_val, dest_val, op_type),
+                    // out = dest - src
+                    BasicMathOp::Subtract => self.sub_nums(dest_val, src_sum_val),
+                    // out = dest * src
+                    BasicMathOp::Multiply => self.combine_sources(src_sum_val, dest_val, op_type),
                 }
             } else {
                 // The destination is overwrite, so just use our combined source values.
                 src_sum_val
             };
 
-            // Save the resulting value in the destination.
-            let dest_ptr = self.load_static_ptr(self.data.sym_data_id(dest)?)?;
+            // Save the resulting value in the destination, rounded or truncated to its decimal places.
+            size_errors.extend(self.store_result(dest, final_val, &op_data.size_error)?);
+        }
+
+        self.translate_size_error_branch(size_errors, &op_data.size_error)
+    }
+
+    /// Translates a single corresponding basic math operation (ADD, SUB) into Cranelift IR,
+    /// applying the operation between each pair of corresponding numeric items within the
+    /// source and destination groups.
+    fn translate_basic_op_corresponding(
+        &mut self,
+        op_data: &BasicMathOpData<'src>,
+        op_type: BasicMathOp,
+    ) -> Result<()> {
+        let ([parser::Value::Variable(src)], [dest]) =
+            (op_data.sources.as_slice(), op_data.dests.as_slice())
+        else {
+            unreachable!("Corresponding operations must have a single source and destination.");
+        };
+
+        let mut size_errors: Vec<Value> = Vec::new();
+        for (src_sym, dest_sym) in self.corresponding_items(src, &dest.sym, true)? {
+            let src_val = self.load_num(&parser::Value::Variable(src_sym))?;
+            let dest_val = self.load_num(&parser::Value::Variable(dest_sym.clone()))?;
+            let final_val = match op_type {
+                BasicMathOp::Subtract => self.sub_nums(dest_val, src_val),
+                _ => self.combine_sources(src_val, dest_val, op_type),
+            };
+            let dest = ArithDest {
+                sym: dest_sym,
+                rounding: dest.rounding,
+            };
+            size_errors.extend(self.store_result(&dest, final_val, &op_data.size_error)?);
+        }
+
+        self.translate_size_error_branch(size_errors, &op_data.size_error)
+    }
+
+    /// Stores the given arithmetic result in the given destination, applying its rounding mode.
+    /// If size error phrases are present, the destination is only modified if the result fits within
+    /// its digits, and a flag is returned which is set when the result does not fit.
+    fn store_result(
+        &mut self,
+        dest: &ArithDest<'src>,
+        result: NumValue,
+        size_error: &SizeErrorPhrases<'src>,
+    ) -> Result<Option<Value>> {
+        let dest_ptr = self.load_sym_ptr(&dest.sym)?;
+        let dest_pic = self.data.sym_pic(&dest.sym)?.clone();
+        let scale = dest_pic.scale();
+        let scaled = self.round_num(result, scale, dest.rounding);
+        if !size_error.is_present() {
+            self.store_num(dest_ptr, &dest_pic, NumValue { val: scaled, scale })?;
+            return Ok(None);
+        }
+
+        // Check whether the result's magnitude exceeds the largest value the destination can hold.
+        let magnitude = self.builder.ins().iabs(scaled);
+        let max = 10i64
+            .checked_pow(dest_pic.digits() as u32)
+            .unwrap_or(i64::MAX);
+        let size_error =
             self.builder
                 .ins()
-                .store(MemFlags::new(), final_val, dest_ptr, Offset32::new(0));
+                .icmp_imm(IntCC::SignedGreaterThanOrEqual, magnitude, max);
+
+        // Only store the result if it fits.
+        let store_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+        self.builder
+            .ins()
+            .brif(size_error, trailing_block, &[], store_block, &[]);
+        self.builder.seal_block(store_block);
+        self.switch_to_block(store_block);
+        self.store_num(dest_ptr, &dest_pic, NumValue { val: scaled, scale })?;
+        self.builder.ins().jump(trailing_block, &[]);
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
+        Ok(Some(size_error))
+    }
+
+    /// Translates the size error phrases of an arithmetic statement, given the size error flags
+    /// for each of the statement's destinations.
+    fn translate_size_error_branch(
+        &mut self,
+        size_errors: Vec<Value>,
+        size_error: &SizeErrorPhrases<'src>,
+    ) -> Result<()> {
+        let Some(mut any_error) = size_errors.first().copied() else {
+            return Ok(());
+        };
+        for flag in size_errors.into_iter().skip(1) {
+            any_error = self.builder.ins().bor(any_error, flag);
         }
 
+        // Branch to the relevant phrase, then continue on.
+        let error_block = self.builder.create_block();
+        let ok_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+        self.builder
+            .ins()
+            .brif(any_error, error_block, &[], ok_block, &[]);
+        self.builder.seal_block(error_block);
+        self.builder.seal_block(ok_block);
+        for (block, stats) in [
+            (error_block, &size_error.on_error),
+            (ok_block, &size_error.not_on_error),
+        ] {
+            self.switch_to_block(block);
+            let block_self_terminates = match stats {
+                Some(stats) => self.translate_stats(stats)?,
+                None => false,
+            };
+            if !block_self_terminates {
+                self.builder.ins().jump(trailing_block, &[]);
+            }
+        }
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
         Ok(())
     }
 
     /// Combines a vector of source values, returning their combined value.
-    /// Supports source combination for both integer and floating point addition, subtraction and multiplication.
+    /// Supports source combination for addition, subtraction and multiplication.
     fn combine_sources_vec(
         &mut self,
-        vals: Vec<Value>,
+        vals: Vec<NumValue>,
         op_type: BasicMathOp,
         is_overwrite: bool,
-        is_float: bool,
-    ) -> Value {
+    ) -> NumValue {
         assert!(!vals.is_empty());
 
         // If there's only one input value, no need to combine anything.
         if vals.len() == 1 {
-            return vals.into_iter().next().unwrap();
+            return vals[0];
         }
 
         // If this is a subtract, & there are only two sources (& we're overwriting), simply emit a subtract.
-        let (first_val, second_val) = (*vals.iter().nth(0).unwrap(), *vals.iter().nth(1).unwrap());
+        let (first_val, second_val) = (vals[0], vals[1]);
         if vals.len() == 2 && is_overwrite && op_type == BasicMathOp::Subtract {
-            if is_float {
-                return self.builder.ins().fsub(second_val, first_val);
-            } else {
-                return self.builder.ins().isub(second_val, first_val);
-            }
+            return self.sub_nums(second_val, first_val);
         }
 
         // Combine the first two values.
-        let mut cur_val = self.combine_sources(first_val, second_val, op_type, is_float);
+        let mut cur_val = self.combine_sources(first_val, second_val, op_type);
 
         // Get an iterator & combine the remaining values.
         let mut val_iter = vals.into_iter().skip(2).peekable();
-        while val_iter.peek().is_some() {
-            let src_val = val_iter.next().unwrap();
-
+        while let Some(src_val) = val_iter.next() {
             // We subtract the final value if we're overwriting and this is a SUB instruction.
             if val_iter.peek().is_none() && is_overwrite && op_type == BasicMathOp::Subtract {
-                if is_float {
-                    cur_val = self.builder.ins().fsub(src_val, cur_val);
-                } else {
-                    cur_val = self.builder.ins().isub(src_val, cur_val);
-                }
+                cur_val = self.sub_nums(src_val, cur_val);
                 break;
             }
 
-            cur_val = self.combine_sources(cur_val, src_val, op_type, is_float);
+            cur_val = self.combine_sources(cur_val, src_val, op_type);
         }
         cur_val
     }
@@ -194,30 +247,132 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
     /// Combines the two given  source values for the given basic mathematical operation.
     fn combine_sources(
         &mut self,
-        left: Value,
-        right: Value,
+        left: NumValue,
+        right: NumValue,
         op_type: BasicMathOp,
-        is_float: bool,
-    ) -> Value {
+    ) -> NumValue {
         match op_type {
             // We actually perform an add for the "SUBTRACT" instruction, as the real calculation
             // for SUBTRACT is `dest - sum(sources)`.
-            BasicMathOp::Add | BasicMathOp::Subtract => {
-                if is_float {
-                    self.builder.ins().fadd(left, right)
-                } else {
-                    self.builder.ins().iadd(left, right)
-                }
+            BasicMathOp::Add | BasicMathOp::Subtract => self.add_nums(left, right),
+            // The product of two fixed point values carries the decimal places of both.
+            BasicMathOp::Multiply => NumValue {
+                val: self.builder.ins().imul(left.val, right.val),
+                scale: left.scale + right.scale,
+            },
+        }
+    }
+
+    /// Adds the two given fixed point values, returning the result.
+    pub(super) fn add_nums(&mut self, left: NumValue, right: NumValue) -> NumValue {
+        let scale = left.scale.max(right.scale);
+        let (left, right) = (
+            self.rescale_num(left, scale),
+            self.rescale_num(right, scale),
+        );
+        NumValue {
+            val: self.builder.ins().iadd(left, right),
+            scale,
+        }
+    }
+
+    /// Subtracts the right fixed point value from the left, returning the result.
+    fn sub_nums(&mut self, left: NumValue, right: NumValue) -> NumValue {
+        let scale = left.scale.max(right.scale);
+        let (left, right) = (
+            self.rescale_num(left, scale),
+            self.rescale_num(right, scale),
+        );
+        NumValue {
+            val: self.builder.ins().isub(left, right),
+            scale,
+        }
+    }
+
+    /// Rescales the given fixed point value to the given number of decimal places.
+    /// Excess decimal places are truncated.
+    pub(super) fn rescale_num(&mut self, num: NumValue, scale: u32) -> Value {
+        if num.scale < scale {
+            let factor = 10i64.pow(scale - num.scale);
+            self.builder.ins().imul_imm(num.val, factor)
+        } else if num.scale > scale {
+            let factor = self
+                .builder
+                .ins()
+                .iconst(types::I64, 10i64.pow(num.scale - scale));
+            self.builder.ins().sdiv(num.val, factor)
+        } else {
+            num.val
+        }
+    }
+
+    /// Rescales the given fixed point value to the given number of decimal places.
+    /// Excess decimal places are rounded using the given rounding mode, or truncated if there is none.
+    fn round_num(&mut self, num: NumValue, scale: u32, rounding: Option<RoundingMode>) -> Value {
+        let Some(mode) = rounding.filter(|_| num.scale > scale) else {
+            return self.rescale_num(num, scale);
+        };
+        let factor = self
+            .builder
+            .ins()
+            .iconst(types::I64, 10i64.pow(num.scale - scale));
+        let quot = self.builder.ins().sdiv(num.val, factor);
+        let rem = self.builder.ins().srem(num.val, factor);
+        let negative = self
+            .builder
+            .ins()
+            .icmp_imm(IntCC::SignedLessThan, num.val, 0);
+        self.round_quotient(quot, rem, factor, negative, mode)
+    }
+
+    /// Rounds the given truncated quotient of a division using the given rounding mode.
+    /// Requires the remainder and divisor of the division, as well as a flag which is set when the
+    /// exact quotient is negative.
+    fn round_quotient(
+        &mut self,
+        quot: Value,
+        rem: Value,
+        divisor: Value,
+        negative: Value,
+        mode: RoundingMode,
+    ) -> Value {
+        // Compare twice the remainder against the divisor to find whether we're past halfway.
+        let rem_abs = self.builder.ins().iabs(rem);
+        let rem_twice = self.builder.ins().imul_imm(rem_abs, 2);
+        let divisor_abs = self.builder.ins().iabs(divisor);
+        let round_away = match mode {
+            RoundingMode::Truncation => return quot,
+            RoundingMode::AwayFromZero => self.builder.ins().icmp_imm(IntCC::NotEqual, rem, 0),
+            RoundingMode::NearestAwayFromZero => {
+                self.builder
+                    .ins()
+                    .icmp(IntCC::SignedGreaterThanOrEqual, rem_twice, divisor_abs)
             }
-            BasicMathOp::Multiply => {
-                if is_float {
-                    let x = self.builder.ins().fmul(left, right);
-                    x
-                } else {
-                    self.builder.ins().imul(left, right)
-                }
+            RoundingMode::NearestEven => {
+                let past_half =
+                    self.builder
+                        .ins()
+                        .icmp(IntCC::SignedGreaterThan, rem_twice, divisor_abs);
+                let at_half = self
+                    .builder
+                    .ins()
+                    .icmp(IntCC::Equal, rem_twice, divisor_abs);
+                let odd_bit = self.builder.ins().band_imm(quot, 1);
+                let is_odd = self.builder.ins().icmp_imm(IntCC::NotEqual, odd_bit, 0);
+                let odd_at_half = self.builder.ins().band(at_half, is_odd);
+                self.builder.ins().bor(past_half, odd_at_half)
             }
-        }
+        };
+
+        // Step the quotient one unit away from zero if required.
+        let (up, down, zero) = (
+            self.builder.ins().iconst(types::I64, 1),
+            self.builder.ins().iconst(types::I64, -1),
+            self.builder.ins().iconst(types::I64, 0),
+        );
+        let step = self.builder.ins().select(negative, down, up);
+        let step = self.builder.ins().select(round_away, step, zero);
+        self.builder.ins().iadd(quot, step)
     }
 
     /// Verifies the given basic (ADD, SUB, MUL) mathematical operation data.
@@ -232,7 +387,6 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         }
 
         // Check that the source types are sane.
-        let mut results_in_float = false;
         for src in op_data.sources.iter() {
             match src {
                 parser::Value::Variable(sym) => {
@@ -240,98 +394,317 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                     if pic.is_str() {
                         miette::bail!("Cannot perform an arithmetic operation on a string.");
                     }
-                    if pic.is_float() {
-                        results_in_float = true;
-                    }
                 }
                 parser::Value::Literal(lit) => match lit {
-                    parser::Literal::String(_) => {
+                    parser::Literal::Int(_)
+                    | parser::Literal::Decimal(_)
+                    | parser::Literal::Figurative(parser::Figurative::Zeros) => {}
+                    parser::Literal::String(_) | parser::Literal::Figurative(_) => {
                         miette::bail!("Cannot perform an arithmetic operation on a string.")
                     }
-                    parser::Literal::Int(_) => {}
-                    parser::Literal::Float(_) => {
-                        results_in_float = true;
-                    }
                 },
             }
         }
 
         // Check that the destination types are sane.
-        for sym in op_data.dests.iter() {
-            let pic = self.data.sym_pic(sym)?;
-            if pic.is_str() {
+        // Numeric-edited items can only receive results, as they cannot be read as numbers.
+        for dest in op_data.dests.iter() {
+            let pic = self.data.sym_pic(&dest.sym)?;
+            if pic.is_str() && !(pic.is_edited() && op_data.overwrite_dests) {
                 miette::bail!(
                     "Cannot save the result of an arithmetic operation in a string-typed variable."
                 );
             }
-            if results_in_float && !pic.is_float() {
-                miette::bail!("Cannot save the result of a floating point arithmetic operation in an integer-typed variable.");
-            }
         }
 
         Ok(())
     }
 
-    /// Translates a single DIVIDE statement into Cranelift IR.
-    pub(super) fn translate_divide(&mut self, div_data: &DivideData<'src>) -> Result<()> {
-        // Verify that this division is sane.
-        self.verify_divide(div_data)?;
-
-        // Load the dividend, divisor variable.
-        let (mut dd_val, mut dv_val) = (
-            self.load_var(div_data.dividend)?,
-            self.load_var(div_data.divisor)?,
-        );
+    /// Generates Cranelift IR for a single "COMPUTE" statement.
+    pub(super) fn translate_compute(&mut self, compute: &ComputeData<'src>) -> Result<()> {
+        // Verify the destinations, finding the most decimal places of any destination.
+        let mut scale = 0;
+        for dest in compute.dests.iter() {
+            let pic = self.data.sym_pic(&dest.sym)?;
+            if pic.is_str() && !pic.is_edited() {
+                miette::bail!(
+                    "Cannot save the result of a COMPUTE operation in a string-typed variable."
+                );
+            }
+            scale = scale.max(pic.scale());
+        }
 
-        // Convert either of them to float if necessary.
-        let (dd_pic, dv_pic) = (
-            self.data.sym_pic(div_data.dividend)?,
-            self.data.sym_pic(div_data.divisor)?,
-        );
-        let out_pic = self.data.sym_pic(div_data.out_var)?;
-        let results_in_float = dd_pic.is_float() || dv_pic.is_float() || out_pic.is_float();
-        if results_in_float {
-            if !dd_pic.is_float() {
-                dd_val = self.builder.ins().fcvt_from_sint(types::F64, dd_val);
+        // Evaluate the expression once, then store it in each destination.
+        let (result, by_zero) = self.translate_expr(&compute.expr, scale + EXPR_GUARD_DIGITS)?;
+        let Some(by_zero) = by_zero else {
+            let mut size_errors: Vec<Value> = Vec::new();
+            for dest in compute.dests.iter() {
+                size_errors.extend(self.store_result(dest, result, &compute.size_error)?);
             }
-            if !dv_pic.is_float() {
-                dv_val = self.builder.ins().fcvt_from_sint(types::F64, dv_val);
+            return self.translate_size_error_branch(size_errors, &compute.size_error);
+        };
+
+        // Dividing by zero is always a size error, leaving all receiving items unchanged.
+        let store_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+        let size_error = self.builder.append_block_param(trailing_block, types::I8);
+        self.builder
+            .ins()
+            .brif(by_zero, trailing_block, &[by_zero], store_block, &[]);
+        self.builder.seal_block(store_block);
+        self.switch_to_block(store_block);
+        let mut any_error = self.builder.ins().iconst(types::I8, 0);
+        for dest in compute.dests.iter() {
+            if let Some(flag) = self.store_result(dest, result, &compute.size_error)? {
+                any_error = self.builder.ins().bor(any_error, flag);
             }
         }
+        self.builder.ins().jump(trailing_block, &[any_error]);
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
+        if !compute.size_error.is_present() {
+            return Ok(());
+        }
+        self.translate_size_error_branch(vec![size_error], &compute.size_error)
+    }
 
-        // Perform the division.
-        let out_val = if results_in_float {
-            self.builder.ins().fdiv(dd_val, dv_val)
-        } else {
-            self.builder.ins().sdiv(dd_val, dv_val)
+    /// Generates Cranelift IR evaluating the given arithmetic expression, returning the result.
+    /// If the expression contains a division, a flag is also returned which is set when any
+    /// division is by zero, in which case the result is meaningless.
+    /// Inexact intermediate results (quotients, powers and floating point intrinsic results) are
+    /// truncated to at least the given number of decimal places.
+    pub(super) fn translate_expr(
+        &mut self,
+        expr: &Expr<'src>,
+        scale: u32,
+    ) -> Result<(NumValue, Option<Value>)> {
+        let (left, op, right) = match expr {
+            Expr::Value(val) => return Ok((self.load_num(val)?, None)),
+            Expr::Intrinsic(call) => {
+                return Ok((self.translate_expr_intrinsic(call, scale)?, None))
+            }
+            Expr::Neg(inner) => {
+                let (inner, by_zero) = self.translate_expr(inner, scale)?;
+                let negated = NumValue {
+                    val: self.builder.ins().ineg(inner.val),
+                    scale: inner.scale,
+                };
+                return Ok((negated, by_zero));
+            }
+            Expr::BinOp(left, op, right) => (left, op, right),
+        };
+        let (left, left_by_zero) = self.translate_expr(left, scale)?;
+        let (right, right_by_zero) = self.translate_expr(right, scale)?;
+        let mut by_zero = match (left_by_zero, right_by_zero) {
+            (Some(l), Some(r)) => Some(self.builder.ins().bor(l, r)),
+            (l, r) => l.or(r),
         };
+        let out_scale = scale.max(left.scale).max(right.scale);
+        let result = match op {
+            BinOp::Add => self.combine_sources(left, right, BasicMathOp::Add),
+            BinOp::Subtract => self.sub_nums(left, right),
+            BinOp::Multiply => {
+                // Products carry the decimal places of both operands, so truncate any excess.
+                let product = self.combine_sources(left, right, BasicMathOp::Multiply);
+                let scale = out_scale.min(product.scale);
+                NumValue {
+                    val: self.rescale_num(product, scale),
+                    scale,
+                }
+            }
+            BinOp::Divide => {
+                // Dividing by zero would trap, so divide by one instead and flag the division.
+                let is_zero = self.builder.ins().icmp_imm(IntCC::Equal, right.val, 0);
+                let one = self.builder.ins().iconst(types::I64, 1);
+                let divisor = self.builder.ins().select(is_zero, one, right.val);
+                by_zero = Some(match by_zero {
+                    Some(flag) => self.builder.ins().bor(flag, is_zero),
+                    None => is_zero,
+                });
+
+                // See `translate_divide` for details on scaling the dividend.
+                let dividend = self.rescale_num(left, out_scale + right.scale);
+                NumValue {
+                    val: self.builder.ins().sdiv(dividend, divisor),
+                    scale: out_scale,
+                }
+            }
+            BinOp::Power => {
+                let args = [
+                    left.val,
+                    self.builder.ins().iconst(types::I64, left.scale as i64),
+                    right.val,
+                    self.builder.ins().iconst(types::I64, right.scale as i64),
+                    self.builder.ins().iconst(types::I64, out_scale as i64),
+                ];
+                let pow_ref = self.intrinsics.get_ref(
+                    self.module,
+                    self.builder.func,
+                    CobaltIntrinsic::PowDec,
+                )?;
+                let inst = self.builder.ins().call(pow_ref, &args);
+                NumValue {
+                    val: self.builder.inst_results(inst)[0],
+                    scale: out_scale,
+                }
+            }
+        };
+        Ok((result, by_zero))
+    }
+
+    /// Generates Cranelift IR for an intrinsic function call within an arithmetic expression.
+    /// Floating point results are converted to fixed point with the given number of decimal places.
+    fn translate_expr_intrinsic(
+        &mut self,
+        call: &parser::IntrinsicCall<'src>,
+        scale: u32,
+    ) -> Result<NumValue> {
+        let (ret_val, ret_type) = self.translate_intrinsic_call(call)?;
+        if ret_type == types::F64 {
+            let multiplier = self.builder.ins().f64const(10f64.powi(scale as i32));
+            let scaled = self.builder.ins().fmul(ret_val, multiplier);
+            Ok(NumValue {
+                val: self.builder.ins().fcvt_to_sint_sat(types::I64, scaled),
+                scale,
+            })
+        } else if ret_type == types::I64 {
+            Ok(NumValue {
+                val: ret_val,
+                scale: 0,
+            })
+        } else {
+            miette::bail!(
+                "Intrinsic function '{}' cannot be used within an arithmetic expression.",
+                call.name
+            );
+        }
+    }
 
-        // Store the output.
-        let dest_ptr = self.load_static_ptr(self.data.sym_data_id(div_data.out_var)?)?;
+    /// Translates a single DIVIDE statement into Cranelift IR.
+    /// Division by zero is treated as a size error, leaving all receiving items unchanged.
+    pub(super) fn translate_divide(&mut self, div_data: &DivideData<'src>) -> Result<()> {
+        // Verify that this division is sane.
+        self.verify_divide(div_data)?;
+
+        // Load the divisor & dividend, if the dividend isn't each destination.
+        let dv_val = self.load_num(&div_data.divisor)?;
+        let dd_val = div_data
+            .dividend
+            .as_ref()
+            .map(|dd| self.load_num(dd))
+            .transpose()?;
+
+        // Only perform the division when the divisor is non-zero.
+        let div_block = self.builder.create_block();
+        let zero_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+        let size_error = self.builder.append_block_param(trailing_block, types::I8);
+        let by_zero = self.builder.ins().icmp_imm(IntCC::Equal, dv_val.val, 0);
         self.builder
             .ins()
-            .store(MemFlags::new(), out_val, dest_ptr, Offset32::new(0));
-        Ok(())
+            .brif(by_zero, zero_block, &[], div_block, &[]);
+        self.builder.seal_block(div_block);
+        self.builder.seal_block(zero_block);
+
+        // Calculate & store the quotient for each destination.
+        self.switch_to_block(div_block);
+        let mut size_errors: Vec<Value> = Vec::new();
+        for dest in div_data.dests.iter() {
+            let dd_val = match dd_val {
+                Some(dd_val) => dd_val,
+                None => self.load_num(&parser::Value::Variable(dest.sym.clone()))?,
+            };
+            let (quot, trunc_quot) = self.translate_quotient(dd_val, dv_val, dest)?;
+            size_errors.extend(self.store_result(dest, quot, &div_data.size_error)?);
+
+            // The remainder is the difference between the dividend and the product of the
+            // divisor with the unrounded quotient.
+            if let Some(rem_ref) = &div_data.remainder {
+                let product = self.combine_sources(trunc_quot, dv_val, BasicMathOp::Multiply);
+                let rem = self.sub_nums(dd_val, product);
+                let rem_dest = ArithDest {
+                    sym: rem_ref.clone(),
+                    rounding: None,
+                };
+                size_errors.extend(self.store_result(&rem_dest, rem, &div_data.size_error)?);
+            }
+        }
+        let mut any_error = self.builder.ins().iconst(types::I8, 0);
+        for flag in size_errors {
+            any_error = self.builder.ins().bor(any_error, flag);
+        }
+        self.builder.ins().jump(trailing_block, &[any_error]);
+
+        // Dividing by zero is always a size error.
+        self.switch_to_block(zero_block);
+        let is_error = self.builder.ins().iconst(types::I8, 1);
+        self.builder.ins().jump(trailing_block, &[is_error]);
+
+        self.builder.seal_block(trailing_block);
+        self.switch_to_block(trailing_block);
+        if !div_data.size_error.is_present() {
+            return Ok(());
+        }
+        self.translate_size_error_branch(vec![size_error], &div_data.size_error)
+    }
+
+    /// Divides the given dividend by the given non-zero divisor, returning the quotient with the
+    /// decimal places of the given destination, along with that quotient prior to any rounding.
+    fn translate_quotient(
+        &mut self,
+        dd_val: NumValue,
+        dv_val: NumValue,
+        dest: &ArithDest<'src>,
+    ) -> Result<(NumValue, NumValue)> {
+        // Scale the operands such that the quotient has the decimal places of the output.
+        // As `(dd / 10^a) / (dv / 10^b) = (dd * 10^b / dv) / 10^a`, we need the dividend at
+        // a scale of `out + b` when dividing by the raw divisor.
+        let scale = self.data.sym_pic(&dest.sym)?.scale();
+        let dd_scaled = self.rescale_num(dd_val, scale + dv_val.scale);
+
+        // Perform the division, truncating any excess decimal places unless rounding.
+        let trunc_quot = self.builder.ins().sdiv(dd_scaled, dv_val.val);
+        let mut quot = trunc_quot;
+        if let Some(mode) = dest.rounding {
+            let rem = self.builder.ins().srem(dd_scaled, dv_val.val);
+            let signs = self.builder.ins().bxor(dd_scaled, dv_val.val);
+            let negative = self.builder.ins().icmp_imm(IntCC::SignedLessThan, signs, 0);
+            quot = self.round_quotient(quot, rem, dv_val.val, negative, mode);
+        }
+        Ok((
+            NumValue { val: quot, scale },
+            NumValue {
+                val: trunc_quot,
+                scale,
+            },
+        ))
     }
 
     /// Verifies that the given DIVIDE instruction data is valid.
-    /// todo: Add support for rounding, remainder!
     fn verify_divide(&self, div_data: &DivideData<'src>) -> Result<()> {
         // Verify that both input types are numbers.
-        let (dividend_pic, divisor_pic) = (
-            self.data.sym_pic(div_data.dividend)?,
-            self.data.sym_pic(div_data.divisor)?,
-        );
-        if dividend_pic.is_str() || divisor_pic.is_str() {
-            miette::bail!("Cannot perform a DIVIDE operation on string type variables.");
+        let operands = div_data.dividend.iter().chain([&div_data.divisor]);
+        for operand in operands {
+            if operand.is_str(self.data)? {
+                miette::bail!("Cannot perform a DIVIDE operation on string type values.");
+            }
         }
 
-        // If one of the input types is a float, verify that the output type is a float.
-        let out_pic = self.data.sym_pic(div_data.out_var)?;
-        if (dividend_pic.is_float() || divisor_pic.is_float()) && !out_pic.is_float() {
-            miette::bail!(
-                "Cannot output the result of a floating point division to an integer variable."
-            );
+        // Destinations that are divided in place must also be read as numbers.
+        for dest in div_data.dests.iter() {
+            let out_pic = self.data.sym_pic(&dest.sym)?;
+            if out_pic.is_str() && !(out_pic.is_edited() && div_data.dividend.is_some()) {
+                miette::bail!(
+                    "Cannot save the result of a DIVIDE operation in a string-typed variable."
+                );
+            }
+        }
+        if let Some(rem_ref) = &div_data.remainder {
+            if self.data.sym_pic(rem_ref)?.is_str() {
+                miette::bail!(
+                    "Cannot save the remainder of a DIVIDE operation in a string-typed variable."
+                );
+            }
         }
         Ok(())
     }
@@ -6,15 +6,30 @@ use cranelift_module::{DataId, Module};
 use miette::Result;
 
 use crate::compiler::{
-    codegen::{data::DataManager, intrinsics::CobaltIntrinsic},
-    parser::{self, IntrinsicCall, Literal, MoveData, MoveRef, MoveSource, MoveSpan, Pic},
+    codegen::{
+        data::{zoned_sign_mode, DataManager, DataSymId},
+        intrinsics::CobaltIntrinsic,
+    },
+    parser::{
+        self, DataItem, DataItemKind, DataRef, Figurative, InitCategory, InitializeData,
+        IntrinsicCall, Literal, MoveData, MoveRef, MoveSource, MoveSpan, Pic,
+    },
 };
 
-use super::{value::CodegenLiteral, FuncTranslator};
+use super::{
+    value::{CodegenLiteral, NumValue},
+    FuncTranslator,
+};
 
 impl<'a, 'src> FuncTranslator<'a, 'src> {
     /// Generates Cranelift IR for a single "MOVE" statement.
     pub(super) fn translate_move(&mut self, mov_data: &MoveData<'src>) -> Result<()> {
+        if mov_data.corresponding {
+            let MoveSource::MoveRef(src) = &mov_data.source else {
+                unreachable!("Corresponding moves must have a referenced source.");
+            };
+            return self.translate_move_corresponding(&src.sym, &mov_data.dest.sym);
+        }
         match &mov_data.source {
             MoveSource::Intrinsic(ic_call) => {
                 self.translate_move_intrinsic(ic_call, &mov_data.dest)
@@ -24,6 +39,109 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         }
     }
 
+    /// Generates Cranelift IR for a single "MOVE CORRESPONDING" statement, moving each subordinate
+    /// item of the source group into the destination group item of the same name.
+    fn translate_move_corresponding(
+        &mut self,
+        src: &DataRef<'src>,
+        dest: &DataRef<'src>,
+    ) -> Result<()> {
+        for (src_sym, dest_sym) in self.corresponding_items(src, dest, false)? {
+            let src = MoveRef {
+                sym: src_sym,
+                span: None,
+            };
+            let dest = MoveRef {
+                sym: dest_sym,
+                span: None,
+            };
+            self.translate_mov_ref(&src, &dest)?;
+        }
+        Ok(())
+    }
+
+    /// Returns qualified references to each pair of corresponding items within the given source and
+    /// destination groups, as (source, destination) pairs in declaration order.
+    /// Items correspond when they share the same name and the same chain of group names up to the
+    /// given groups, with at least one of the pair elementary. Items with a REDEFINES or OCCURS
+    /// clause (and all items subordinate to them) never correspond. When `numeric` is set, only
+    /// pairs of elementary numeric items correspond.
+    pub(super) fn corresponding_items(
+        &self,
+        src: &DataRef<'src>,
+        dest: &DataRef<'src>,
+        numeric: bool,
+    ) -> Result<Vec<(DataRef<'src>, DataRef<'src>)>> {
+        let (src_item, src_ancestors) = self.find_data_item(src)?;
+        let (dest_item, dest_ancestors) = self.find_data_item(dest)?;
+        let (DataItemKind::Group(src_items), DataItemKind::Group(dest_items)) =
+            (&src_item.kind, &dest_item.kind)
+        else {
+            miette::bail!(
+                "Both '{}' and '{}' must be group items to be used with 'CORRESPONDING'.",
+                src,
+                dest
+            );
+        };
+
+        let mut found: Vec<(Vec<&'src str>, &'src str)> = Vec::new();
+        find_corresponding(src_items, dest_items, numeric, &mut Vec::new(), &mut found);
+        if found.is_empty() {
+            miette::bail!(
+                "No items within '{}' correspond to items within '{}'.",
+                src,
+                dest
+            );
+        }
+
+        // Fully qualify each item, such that it always resolves to the item within each group.
+        let src_quals: Vec<&'src str> = std::iter::once(src_item.name)
+            .chain(src_ancestors)
+            .collect();
+        let dest_quals: Vec<&'src str> = std::iter::once(dest_item.name)
+            .chain(dest_ancestors)
+            .collect();
+        Ok(found
+            .into_iter()
+            .map(|(path, name)| {
+                (
+                    qualify_corresponding(name, &path, &src_quals, src),
+                    qualify_corresponding(name, &path, &dest_quals, dest),
+                )
+            })
+            .collect())
+    }
+
+    /// Finds the declaration of the data item referred to by the given reference, along with the
+    /// names of the groups containing it, from the innermost outwards.
+    fn find_data_item(&self, sym: &DataRef<'src>) -> Result<(&'a DataItem<'src>, Vec<&'src str>)> {
+        let sym_id = self.data.resolve_ref(sym)?;
+        let mut path: Vec<&str> = self.data.ancestor_names(sym_id).collect();
+        path.reverse();
+        path.push(sym.name);
+
+        // Walk down from the top level item, following the path of names to the referenced item.
+        let mut items = self
+            .ast
+            .data_div
+            .as_ref()
+            .map_or(&[][..], |d| d.ws_section.items.as_slice());
+        let mut ancestors: Vec<&'src str> = Vec::new();
+        let mut cur: Option<&'a DataItem<'src>> = None;
+        for name in path {
+            if let Some(item) = cur {
+                ancestors.insert(0, item.name);
+                let DataItemKind::Group(children) = &item.kind else {
+                    unreachable!("Data item with subordinate items is not a group.");
+                };
+                items = children.as_slice();
+            }
+            cur = items.iter().find(|item| item.name == name);
+        }
+        let item = cur.expect("Resolved data item has no declaration.");
+        Ok((item, ancestors))
+    }
+
     /// Generates Cranelift IR for a single move instruction resulting from an intrinsic call.
     fn translate_move_intrinsic(
         &mut self,
@@ -32,10 +150,10 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
     ) -> Result<()> {
         // Translate the intrinsic call, load a pointer to the destination.
         let (ret_val, ret_type) = self.translate_intrinsic_call(call)?;
-        let dest_ptr = self.load_static_ptr(self.data.sym_data_id(dest.sym)?)?;
+        let dest_ptr = self.load_sym_ptr(&dest.sym)?;
 
         // Determine whether the output type of that intrinsic call is valid for the destination.
-        let dest_pic = self.data.sym_pic(dest.sym)?;
+        let dest_pic = self.data.sym_pic(&dest.sym)?.clone();
         let ptr_type = self.module.target_config().pointer_type();
         if dest_pic.is_float() && ret_type != types::F64
             || dest_pic.is_str() && ret_type != ptr_type
@@ -48,13 +166,20 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         }
 
         // Verify the destination reference is valid.
-        dest.validate(dest_pic, self.data)?;
+        dest.validate(&dest_pic, self.data)?;
 
         // Perform a store of the value.
-        if dest_pic.is_float() || (!dest_pic.is_float() && !dest_pic.is_str()) {
-            self.builder
+        // Floating point results are truncated to the decimal places of the destination.
+        if dest_pic.is_float() {
+            let multiplier = self
+                .builder
                 .ins()
-                .store(MemFlags::new(), ret_val, dest_ptr, Offset32::new(0));
+                .f64const(10f64.powi(dest_pic.scale() as i32));
+            let scaled = self.builder.ins().fmul(ret_val, multiplier);
+            let scaled = self.builder.ins().fcvt_to_sint_sat(types::I64, scaled);
+            self.store_pic_value(dest_ptr, &dest_pic, scaled)?;
+        } else if !dest_pic.is_str() {
+            self.store_pic_value(dest_ptr, &dest_pic, ret_val)?;
         } else {
             miette::bail!("String copy intrinsics are currently unimplemented.");
         }
@@ -63,10 +188,8 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
 
     /// Moves the given literal into the provided global data slot.
     pub(super) fn translate_mov_lit(&mut self, lit: &Literal, dest: &MoveRef<'src>) -> Result<()> {
-        let dest_id = self.data.sym_data_id(dest.sym)?;
-
         // Load the relevant PIC, verify the destination is valid.
-        let dest_pic = self.data.sym_pic(dest.sym)?.clone();
+        let dest_pic = self.data.sym_pic(&dest.sym)?.clone();
         dest.validate(&dest_pic, self.data)?;
 
         // Verify the source literal actually fits within the destination.
@@ -75,62 +198,74 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                 "Attempted to move incompatible literal '{}' into variable '{}' ({} bytes).",
                 lit.text(&self.ast.str_lits),
                 &dest.sym,
-                dest_pic.comp_size()
+                dest_pic.storage_size()
             );
         }
 
         // Import the destination variable into the function, get a pointer to it.
-        let ptr_type = self.module.target_config().pointer_type();
-        let dest_ptr = self.load_static_ptr(dest_id)?;
+        let dest_ptr = self.load_sym_ptr(&dest.sym)?;
+
+        // Strings moved into a span of the destination must be copied with an intrinsic.
+        // If the destination is only a single character, the span can only cover that character.
+        let dest_len = dest_pic.storage_size();
+        let is_str_move = match lit {
+            Literal::Int(_) | Literal::Decimal(_) => false,
+            Literal::Figurative(Figurative::Zeros) => dest_pic.is_str() && !dest_pic.is_edited(),
+            Literal::String(_) | Literal::Figurative(_) => true,
+        };
+        match &dest.span {
+            Some(span) if is_str_move && dest_len > 1 => {
+                let (src_val, src_len) = match lit {
+                    Literal::Figurative(fig) => (self.load_figurative(fig, dest_len)?, dest_len),
+                    _ => {
+                        let src_len = self.str_value_len(&parser::Value::Literal(*lit))?;
+                        (self.load_lit(lit)?, src_len)
+                    }
+                };
+                self.translate_mov_lit_spanned(
+                    src_val, src_len, &dest_pic, span, dest_len, dest_ptr,
+                )
+            }
+            _ => self.translate_store_lit(lit, dest_ptr, &dest_pic),
+        }
+    }
 
+    /// Stores the given literal into the whole of the item with the given layout, stored at the
+    /// given pointer. Assumes that the literal has already been verified to fit the layout.
+    pub(super) fn translate_store_lit(
+        &mut self,
+        lit: &Literal,
+        dest_ptr: Value,
+        dest_pic: &Pic,
+    ) -> Result<()> {
+        let ptr_type = self.module.target_config().pointer_type();
         match lit {
-            Literal::Int(_) | Literal::Float(_) => {
-                let src_val = self.load_lit(lit)?;
-                self.builder
-                    .ins()
-                    .store(MemFlags::new(), src_val, dest_ptr, Offset32::new(0));
+            Literal::Int(_) | Literal::Decimal(_) => {
+                let src_val = self.load_num(&parser::Value::Literal(*lit))?;
+                self.store_num(dest_ptr, dest_pic, src_val)?;
             }
             Literal::String(sid) => {
                 // Get the size of the string to copy.
-                let src_len = self
-                    .ast
-                    .str_lits
-                    .get(*sid)
-                    .ok_or(miette::diagnostic!(
-                        "Failed to fetch string data for literal ID '{}'.",
-                        sid
-                    ))?
-                    .len();
+                let src_len = self.str_value_len(&parser::Value::Literal(*lit))?;
 
                 // Get total possible length for destination string.
-                // We take one here to remove the null terminator.
-                let dest_len = dest_pic.comp_size() - 1;
+                let dest_len = dest_pic.storage_size();
 
                 // If the destination length is only a single character, we can use an optimised single store.
                 // Since it's a literal, we can even skip the load half altogether and use an immediate.
                 if dest_len == 1 {
-                    let char = self.ast.str_lits.get(*sid).unwrap().chars().next().unwrap();
-                    let char_val = self.load_cg_lit(&CodegenLiteral::Char(char))?;
+                    let char = self.ast.str_lits.get(*sid).unwrap().chars().next();
+                    let char_val = self.load_cg_lit(&CodegenLiteral::Char(char.unwrap_or('\0')))?;
                     self.builder
                         .ins()
                         .store(MemFlags::new(), char_val, dest_ptr, Offset32::new(0));
                 } else {
-                    // Load source literal for copying.
-                    let src_val = self.load_lit(lit)?;
-
-                    // Cannot optimise to a single store.
-                    // Check if there's a span, if there's no span we can just use a standard memcpy.
-                    if let Some(span) = &dest.span {
-                        self.translate_mov_lit_spanned(
-                            src_val, src_len, &dest_pic, &span, dest_len, dest_ptr,
-                        )?;
-                    } else {
-                        // No span found, just use a simple memcpy.
-                        let src_size = src_len + 1; // +1 for the null terminator
-                        let size_val = self.builder.ins().iconst(ptr_type, src_size as i64);
-
-                        // Sanity check.
-                        assert!(src_size <= dest_pic.comp_size());
+                    // Cannot optimise to a single store, just use a simple memcpy.
+                    // Sanity check.
+                    assert!(src_len <= dest_len);
+                    if src_len > 0 {
+                        let src_val = self.load_lit(lit)?;
+                        let size_val = self.builder.ins().iconst(ptr_type, src_len as i64);
                         self.builder.call_memcpy(
                             self.module.target_config(),
                             dest_ptr,
@@ -138,8 +273,57 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                             size_val,
                         );
                     }
+                    self.translate_space_fill(dest_ptr, src_len, dest_len);
                 }
             }
+            // "ZERO" moves as numeric zero into numeric destinations, which may be edited.
+            Literal::Figurative(Figurative::Zeros)
+                if !dest_pic.is_str() || dest_pic.is_edited() =>
+            {
+                let src_val = self.load_num(&parser::Value::Literal(Literal::Int(0)))?;
+                self.store_num(dest_ptr, dest_pic, src_val)?;
+            }
+            // Other figurative constants fill the entire destination, rather than being padded
+            // out with spaces.
+            Literal::Figurative(fig) => {
+                let fill = fig.fill(&self.ast.str_lits, dest_pic.storage_size());
+                self.translate_fill(dest_ptr, &fill);
+            }
+        }
+        Ok(())
+    }
+
+    /// Generates Cranelift IR for a single "INITIALIZE" statement.
+    /// Each elementary item within the targets is initialized to the default value for its
+    /// category. When a REPLACING phrase is present, only the elementary items of the categories
+    /// named are initialized, to the literal given for their category instead.
+    /// Items which redefine another item are never initialized.
+    pub(super) fn translate_initialize(&mut self, init: &InitializeData<'src>) -> Result<()> {
+        for target in init.targets.iter() {
+            let sym_id = self.data.resolve_ref(target)?;
+            let sym_ptr = self.load_sym_ptr(target)?;
+            self.translate_for_each_elementary(sym_id, sym_ptr, &mut |this, elem_id, elem_ptr| {
+                let elem_pic = this.data.sym(elem_id).pic.clone();
+                let category = InitCategory::of(&elem_pic);
+                let lit = if init.replacing.is_empty() {
+                    category.default_value()
+                } else {
+                    match init.replacing.iter().find(|(cat, _)| *cat == category) {
+                        Some((_, lit)) => *lit,
+                        None => return Ok(()),
+                    }
+                };
+                if !elem_pic.verify_lit(&this.ast.str_lits, &lit) {
+                    miette::bail!(
+                        "Cannot initialize {} item '{}' ({} bytes) with incompatible literal '{}'.",
+                        category,
+                        this.data.sym(elem_id).name,
+                        elem_pic.storage_size(),
+                        lit.text(&this.ast.str_lits)
+                    );
+                }
+                this.translate_store_lit(&lit, elem_ptr, &elem_pic)
+            })?;
         }
         Ok(())
     }
@@ -184,19 +368,12 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
     fn translate_mov_ref(&mut self, src: &MoveRef<'src>, dest: &MoveRef<'src>) -> Result<()> {
         // Import both variables as global values, get pointers to them.
         let ptr_type = self.module.target_config().pointer_type();
-        let (src_id, dest_id) = (
-            self.data.sym_data_id(src.sym)?,
-            self.data.sym_data_id(dest.sym)?,
-        );
-        let (src_ptr, dest_ptr) = (
-            self.load_static_ptr(src_id)?,
-            self.load_static_ptr(dest_id)?,
-        );
+        let (src_ptr, dest_ptr) = (self.load_sym_ptr(&src.sym)?, self.load_sym_ptr(&dest.sym)?);
 
         // Load the PIC for the source/destination.
         let (src_pic, dest_pic) = (
-            self.data.sym_pic(src.sym)?.clone(),
-            self.data.sym_pic(dest.sym)?.clone(),
+            self.data.sym_pic(&src.sym)?.clone(),
+            self.data.sym_pic(&dest.sym)?.clone(),
         );
 
         // Verify that the references are valid.
@@ -204,19 +381,34 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         dest.validate(&dest_pic, self.data)?;
 
         // If neither variable contains a span, we can statically verify that the move is valid by checking their PIC layouts.
-        if src.span.is_none() && dest.span.is_none() && !src_pic.fits_within_comp(&dest_pic) {
+        if src.span.is_none() && dest.span.is_none() && !src_pic.fits_within(&dest_pic) {
             miette::bail!("Attempted to move incompatible variable '{}' ({} bytes) into variable '{}' ({} bytes).",
-            src.sym, src_pic.comp_size(), dest.sym, dest_pic.comp_size());
+            src.sym, src_pic.storage_size(), dest.sym, dest_pic.storage_size());
         }
 
         // Based on the source type, determine the copy mechanism.
-        if src_pic.is_str() {
+        // DISPLAY numeric items are stored as text, so are copied as strings into string destinations.
+        // Numeric items moved into numeric-edited items are instead edited as numbers.
+        let is_edit = dest_pic.is_edited() && !src_pic.is_str() && dest.span.is_none();
+        if !is_edit
+            && src_pic.is_zoned()
+            && dest_pic.is_str()
+            && src.span.is_none()
+            && dest.span.is_none()
+        {
+            // Whole DISPLAY numerics are moved into strings as their unsigned digits, dropping
+            // any sign and implied decimal point.
+            let digits_pic = Pic::unsigned_numeric(src_pic.digits());
+            let val = self.load_decimal_raw(src_ptr, &src_pic)?;
+            self.store_pic_value(dest_ptr, &digits_pic, val)?;
+            self.translate_space_fill(dest_ptr, digits_pic.storage_size(), dest_pic.storage_size());
+        } else if !is_edit && (src_pic.is_str() || dest_pic.is_str()) {
             if src.span.is_some() || dest.span.is_some() {
                 // Requires a spanned copy. If we can make an optimised load/store move, (e.g. the src/dest is only 1 character)
-                // do that instead. Currently, we can only perform this when the destination is also 1 character long (2 bytes)
-                // as we haven't got the infra for adjusting the terminator when optimising like this.
+                // do that instead. Currently, we can only perform this when the destination is also 1 character long
+                // as we haven't got the infra for adjusting the trailing zeroes when optimising like this.
                 if (src.has_static_length_of(1) || dest.has_static_length_of(1))
-                    && dest_pic.comp_size() == 2
+                    && dest_pic.storage_size() == 1
                 {
                     self.translate_mov_char(src, dest, src_ptr, dest_ptr)?;
                 } else {
@@ -227,48 +419,119 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
                 }
             } else {
                 // No spans specified, a simple copy is fine.
-                // If the destination happens to be a single character long (2 bytes), we can also optimise down to a load/store.
-                if dest_pic.comp_size() == 2 {
+                let (src_id, dest_id) = (
+                    self.data.resolve_ref(&src.sym)?,
+                    self.data.resolve_ref(&dest.sym)?,
+                );
+                if self.data.variable_table(src_id).is_some()
+                    || self.data.variable_table(dest_id).is_some()
+                {
+                    // Groups ending in variable length tables are only copied up to their
+                    // current length, so the copy must be sized at runtime.
+                    let src_len = self.load_item_len(src_id)?;
+                    let dest_len = self.load_item_len(dest_id)?;
+                    let copy_len = self.builder.ins().smin(src_len, dest_len);
+                    self.builder.call_memcpy(
+                        self.module.target_config(),
+                        dest_ptr,
+                        src_ptr,
+                        copy_len,
+                    );
+                    let fill_ptr = self.builder.ins().iadd(dest_ptr, copy_len);
+                    let fill_len = self.builder.ins().isub(dest_len, copy_len);
+                    let space = self.builder.ins().iconst(types::I8, b' ' as i64);
+                    self.builder.call_memset(
+                        self.module.target_config(),
+                        fill_ptr,
+                        space,
+                        fill_len,
+                    );
+                } else if dest_pic.storage_size() == 1 {
+                    // The destination is a single character long, so we can optimise down to a load/store.
                     self.translate_mov_char(src, dest, src_ptr, dest_ptr)?;
                 } else {
                     // Cannot optimise, perform a standard memcpy().
                     let size_val = self
                         .builder
                         .ins()
-                        .iconst(ptr_type, src_pic.comp_size() as i64);
+                        .iconst(ptr_type, src_pic.storage_size() as i64);
 
                     // Sanity check.
-                    assert!(src_pic.comp_size() <= dest_pic.comp_size());
+                    assert!(src_pic.storage_size() <= dest_pic.storage_size());
 
-                    // Perform a memcpy().
+                    // Perform a memcpy(), clearing any remaining space in the destination.
                     self.builder.call_memcpy(
                         self.module.target_config(),
                         dest_ptr,
                         src_ptr,
                         size_val,
                     );
+                    self.translate_space_fill(
+                        dest_ptr,
+                        src_pic.storage_size(),
+                        dest_pic.storage_size(),
+                    );
                 }
             }
-        } else if src_pic.is_float() {
-            // Load & then re-store the float.
-            let temp =
-                self.builder
-                    .ins()
-                    .load(types::F64, MemFlags::new(), src_ptr, Offset32::new(0));
-            self.builder
-                .ins()
-                .store(MemFlags::new(), temp, dest_ptr, Offset32::new(0));
         } else {
-            // Load & then re-store the integer.
-            let temp =
-                self.builder
-                    .ins()
-                    .load(types::I64, MemFlags::new(), src_ptr, Offset32::new(0));
+            // Load & then re-store the number, converting between layouts.
+            let temp = self.load_pic_value(src_ptr, &src_pic)?;
+            let temp = NumValue {
+                val: temp,
+                scale: src_pic.scale(),
+            };
+            self.store_num(dest_ptr, &dest_pic, temp)?;
+        }
+        Ok(())
+    }
+
+    /// Pads out the trailing bytes of a string destination with spaces, from the given start index
+    /// up to the given total length. Used to fill remaining space after copying a shorter string.
+    fn translate_space_fill(&mut self, dest_ptr: Value, start: usize, total_len: usize) {
+        if start >= total_len {
+            return;
+        }
+        let fill_ptr = self.builder.ins().iadd_imm(dest_ptr, start as i64);
+        self.translate_fill(fill_ptr, &vec![b' '; total_len - start]);
+    }
+
+    /// Stores the given bytes into memory starting at the given pointer.
+    /// A single repeated byte is stored with a memset, otherwise each byte is stored in turn.
+    pub(super) fn translate_fill(&mut self, dest_ptr: Value, bytes: &[u8]) {
+        let Some(first) = bytes.first() else {
+            return;
+        };
+        if bytes.iter().all(|byte| byte == first) {
+            self.builder.emit_small_memset(
+                self.module.target_config(),
+                dest_ptr,
+                *first,
+                bytes.len() as u64,
+                1,
+                MemFlags::new(),
+            );
+            return;
+        }
+        for (offset, byte) in bytes.iter().enumerate() {
+            let byte_val = self.builder.ins().iconst(types::I8, *byte as i64);
             self.builder
                 .ins()
-                .store(MemFlags::new(), temp, dest_ptr, Offset32::new(0));
+                .store(MemFlags::new(), byte_val, dest_ptr, offset as i32);
         }
-        Ok(())
+    }
+
+    /// Loads a pointer to a temporary string of the given length, filled with the given
+    /// figurative constant.
+    pub(super) fn load_figurative(&mut self, fig: &Figurative, len: usize) -> Result<Value> {
+        let ptr_type = self.module.target_config().pointer_type();
+        let slot = self.builder.create_sized_stack_slot(StackSlotData::new(
+            StackSlotKind::ExplicitSlot,
+            len.max(1) as u32,
+        ));
+        let ptr = self.builder.ins().stack_addr(ptr_type, slot, 0);
+        let fill = fig.fill(&self.ast.str_lits, len);
+        self.translate_fill(ptr, &fill);
+        Ok(ptr)
     }
 
     /// Attempts to translate a single character spanned move of a string variable into an
@@ -326,11 +589,11 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         let src_len = self
             .builder
             .ins()
-            .iconst(types::I64, src_pic.comp_size() as i64);
+            .iconst(types::I64, src_pic.storage_size() as i64);
         let dest_len = self
             .builder
             .ins()
-            .iconst(types::I64, dest_pic.comp_size() as i64);
+            .iconst(types::I64, dest_pic.storage_size() as i64);
 
         // Call the intrinsic.
         let strcpy_ref =
@@ -363,22 +626,20 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
             let len = if let Some(len) = &span.len {
                 self.load_value(len)?
             } else {
-                // -1 to remove the null terminator
                 let total_len = self
                     .builder
                     .ins()
-                    .iconst(types::I64, pic.comp_size() as i64 - 1);
+                    .iconst(types::I64, pic.storage_size() as i64);
                 self.builder.ins().isub(total_len, idx)
             };
             Ok((idx, len))
         } else {
             // No span specified for source, use whole string.
             let idx = self.builder.ins().iconst(ptr_type, 0);
-            // -1 to remove the null terminator
             let len = self
                 .builder
                 .ins()
-                .iconst(ptr_type, pic.comp_size() as i64 - 1);
+                .iconst(ptr_type, pic.storage_size() as i64);
             Ok((idx, len))
         }
     }
@@ -394,16 +655,46 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
 
     /// Loads the given variable into the function as a Cranelift [`Value`].
     /// If the variable is a string, loads a pointer to the string.
-    pub(super) fn load_var(&mut self, sym: &'src str) -> Result<Value> {
-        let ptr = self.load_static_ptr(self.data.sym_data_id(sym)?)?;
-        let pic = self.data.sym_pic(sym)?;
+    pub(super) fn load_var(&mut self, sym: &DataRef<'src>) -> Result<Value> {
+        let ptr = self.load_sym_ptr(sym)?;
+        let pic = self.data.sym_pic(sym)?.clone();
+        self.load_pic_value(ptr, &pic)
+    }
+
+    /// Loads the given numeric [`parser::Value`] into the function as a fixed point [`NumValue`].
+    pub(super) fn load_num(&mut self, val: &parser::Value<'src>) -> Result<NumValue> {
+        let scale = match val {
+            parser::Value::Variable(sym) => {
+                let pic = self.data.sym_pic(sym)?;
+                if pic.is_str() {
+                    miette::bail!(
+                        "Expected a numeric value, instead found non-numeric item '{}'.",
+                        sym
+                    );
+                }
+                pic.scale()
+            }
+            parser::Value::Literal(Literal::Int(_)) => 0,
+            parser::Value::Literal(Literal::Decimal(d)) => d.scale,
+            parser::Value::Literal(Literal::Figurative(Figurative::Zeros)) => 0,
+            parser::Value::Literal(lit) => {
+                miette::bail!("Expected a numeric value, instead found literal '{}'.", lit)
+            }
+        };
+        Ok(NumValue {
+            val: self.load_value(val)?,
+            scale,
+        })
+    }
+
+    /// Loads the value stored at the given pointer with the given [`Pic`] layout as a Cranelift [`Value`].
+    /// If the layout is a string, returns the pointer unchanged.
+    /// Numeric values are loaded as an integer, scaled by the number of decimal places in the layout.
+    pub(super) fn load_pic_value(&mut self, ptr: Value, pic: &Pic) -> Result<Value> {
         if pic.is_str() {
             Ok(ptr)
-        } else if pic.is_float() {
-            Ok(self
-                .builder
-                .ins()
-                .load(types::F64, MemFlags::new(), ptr, Offset32::new(0)))
+        } else if pic.is_decimal() {
+            self.load_decimal_raw(ptr, pic)
         } else {
             Ok(self
                 .builder
@@ -412,8 +703,118 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         }
     }
 
+    /// Loads the digits of the decimal (DISPLAY or COMP-3) numeric value stored at the given pointer
+    /// as a single integer, without applying any decimal scaling.
+    pub(super) fn load_decimal_raw(&mut self, ptr: Value, pic: &Pic) -> Result<Value> {
+        let digits = self.builder.ins().iconst(types::I64, pic.digits() as i64);
+        let call = if pic.is_zoned() {
+            let sign_mode = self.builder.ins().iconst(types::I64, zoned_sign_mode(pic));
+            let load_ref = self.intrinsics.get_ref(
+                self.module,
+                self.builder.func,
+                CobaltIntrinsic::ZonedLoad,
+            )?;
+            self.builder.ins().call(load_ref, &[ptr, digits, sign_mode])
+        } else {
+            let load_ref = self.intrinsics.get_ref(
+                self.module,
+                self.builder.func,
+                CobaltIntrinsic::PackedLoad,
+            )?;
+            self.builder.ins().call(load_ref, &[ptr, digits])
+        };
+        Ok(self.builder.inst_results(call)[0])
+    }
+
+    /// Stores the given numeric value at the given pointer with the given [`Pic`] layout.
+    /// The value must be an integer, scaled by the number of decimal places in the layout.
+    /// Decimal numeric values are converted from their native representation.
+    pub(super) fn store_pic_value(&mut self, ptr: Value, pic: &Pic, val: Value) -> Result<()> {
+        if !pic.is_decimal() {
+            // Binary items only hold their declared number of digits, so high order digits are truncated.
+            // Unsigned items cannot hold a sign, so only store the absolute value.
+            let val = if pic.is_signed() {
+                val
+            } else {
+                self.builder.ins().iabs(val)
+            };
+            let val = match 10i64.checked_pow(pic.digits() as u32) {
+                Some(max) => self.builder.ins().srem_imm(val, max),
+                None => val,
+            };
+            self.builder
+                .ins()
+                .store(MemFlags::new(), val, ptr, Offset32::new(0));
+            return Ok(());
+        }
+        let digits = self.builder.ins().iconst(types::I64, pic.digits() as i64);
+        if pic.is_zoned() {
+            let sign_mode = self.builder.ins().iconst(types::I64, zoned_sign_mode(pic));
+            let store_ref = self.intrinsics.get_ref(
+                self.module,
+                self.builder.func,
+                CobaltIntrinsic::ZonedStore,
+            )?;
+            self.builder
+                .ins()
+                .call(store_ref, &[val, ptr, digits, sign_mode]);
+        } else {
+            let signed = self.builder.ins().iconst(types::I8, pic.is_signed() as i64);
+            let store_ref = self.intrinsics.get_ref(
+                self.module,
+                self.builder.func,
+                CobaltIntrinsic::PackedStore,
+            )?;
+            self.builder
+                .ins()
+                .call(store_ref, &[val, ptr, digits, signed]);
+        }
+        Ok(())
+    }
+
+    /// Stores the given fixed point value at the given pointer with the given [`Pic`] layout.
+    /// The value is truncated to the decimal places of the layout, or edited for numeric-edited layouts.
+    pub(super) fn store_num(&mut self, ptr: Value, pic: &Pic, num: NumValue) -> Result<()> {
+        let Some(mask_id) = pic.edit_mask else {
+            let val = self.rescale_num(num, pic.scale());
+            return self.store_pic_value(ptr, pic, val);
+        };
+        let mask = self.load_lit(&Literal::String(mask_id))?;
+        let scale = self.builder.ins().iconst(types::I64, num.scale as i64);
+        let edit_ref =
+            self.intrinsics
+                .get_ref(self.module, self.builder.func, CobaltIntrinsic::EditNum)?;
+        self.builder
+            .ins()
+            .call(edit_ref, &[num.val, scale, mask, ptr]);
+        Ok(())
+    }
+
+    /// Returns the maximum length of the given string [`parser::Value`], in bytes.
+    pub(super) fn str_value_len(&self, val: &parser::Value<'src>) -> Result<usize> {
+        match val {
+            parser::Value::Variable(sym) => Ok(self.data.sym_pic(sym)?.storage_size()),
+            parser::Value::Literal(Literal::String(sid)) => Ok(self
+                .ast
+                .str_lits
+                .get(*sid)
+                .ok_or(miette::diagnostic!(
+                    "Failed to fetch string data for literal ID '{}'.",
+                    sid
+                ))?
+                .len()),
+            parser::Value::Literal(Literal::Figurative(fig)) => {
+                Ok(fig.pattern(&self.ast.str_lits).len())
+            }
+            parser::Value::Literal(lit) => {
+                miette::bail!("Expected a string value, instead found literal '{}'.", lit)
+            }
+        }
+    }
+
     /// Loads the given literal into the function as a Cranelift [`Value`].
     /// If the literal is a string, loads a pointer to the string.
+    /// Figurative constants other than "ZERO" are loaded as a single repetition of their string.
     pub(super) fn load_lit(&mut self, lit: &Literal) -> Result<Value> {
         // Is the value in cache?
         if let Some(val) = self.values.get_litv(lit) {
@@ -424,7 +825,12 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         let litv = match lit {
             Literal::String(sid) => self.load_static_ptr(self.data.str_data_id(*sid)?)?,
             Literal::Int(i) => self.builder.ins().iconst(types::I64, *i),
-            Literal::Float(f) => self.builder.ins().f64const(*f),
+            Literal::Decimal(d) => self.builder.ins().iconst(types::I64, d.digits),
+            Literal::Figurative(Figurative::Zeros) => self.builder.ins().iconst(types::I64, 0),
+            Literal::Figurative(fig) => {
+                let len = fig.pattern(&self.ast.str_lits).len();
+                self.load_figurative(fig, len)?
+            }
         };
         self.values.insert_litv(lit, litv)?;
         Ok(litv)
@@ -447,6 +853,111 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         Ok(cglitv)
     }
 
+    /// Loads a pointer to the storage of the given data reference into the function.
+    /// For items within tables, the provided subscripts are used to calculate the offset of the
+    /// targeted occurrence.
+    pub(super) fn load_sym_ptr(&mut self, sym: &DataRef<'src>) -> Result<Value> {
+        let sym_id = self.data.resolve_ref(sym)?;
+        let mut ptr = self.load_sym_id_ptr(sym_id)?;
+
+        // Verify we have exactly one subscript per table dimension.
+        let dims = self.data.dimensions(sym_id);
+        if dims.len() != sym.subscripts.len() {
+            miette::bail!(
+                "Reference to '{}' requires {} subscript(s), but {} were provided.",
+                sym.name,
+                dims.len(),
+                sym.subscripts.len()
+            );
+        }
+
+        // Offset the pointer by each subscript in turn.
+        for (subscript, (occurs, stride)) in sym.subscripts.iter().zip(dims) {
+            match subscript {
+                parser::Value::Literal(Literal::Int(idx)) => {
+                    // Literal subscripts can be bounds checked statically.
+                    if *idx < 1 || *idx as usize > occurs {
+                        miette::bail!(
+                            "Subscript {} is out of bounds for '{}', which occurs {} times.",
+                            idx,
+                            sym.name,
+                            occurs
+                        );
+                    }
+                    ptr = self.builder.ins().iadd_imm(ptr, (*idx - 1) * stride as i64);
+                }
+                parser::Value::Variable(_) => {
+                    if subscript.is_str(self.data)? || subscript.is_float(self.data)? {
+                        miette::bail!("Subscripts for '{}' must be of type integer.", sym.name);
+                    }
+
+                    // Subscripts begin at 1 in COBOL, so we need to step down here.
+                    // Variable subscripts can only be bounds checked at runtime.
+                    let idx = self.load_value(subscript)?;
+                    let idx = self.builder.ins().iadd_imm(idx, -1);
+                    self.translate_subscript_check(idx, occurs)?;
+                    let offset = self.builder.ins().imul_imm(idx, stride as i64);
+                    ptr = self.builder.ins().iadd(ptr, offset);
+                }
+                parser::Value::Literal(lit) => {
+                    miette::bail!(
+                        "Subscripts for '{}' must be of type integer, found '{}'.",
+                        sym.name,
+                        lit
+                    );
+                }
+            }
+        }
+        Ok(ptr)
+    }
+
+    /// Generates Cranelift IR checking that the given zero based subscript lies within a table
+    /// of the given number of occurrences. Out of bounds subscripts terminate the program.
+    fn translate_subscript_check(&mut self, idx: Value, occurs: usize) -> Result<()> {
+        // Negative subscripts wrap around when compared unsigned, so a single check suffices.
+        let in_bounds = self
+            .builder
+            .ins()
+            .icmp_imm(IntCC::UnsignedLessThan, idx, occurs as i64);
+        let fail_block = self.builder.create_block();
+        let ok_block = self.builder.create_block();
+        self.builder
+            .ins()
+            .brif(in_bounds, ok_block, &[], fail_block, &[]);
+
+        self.switch_to_block(fail_block);
+        self.builder.seal_block(fail_block);
+        let subscript_fail = self.intrinsics.get_ref(
+            self.module,
+            self.builder.func,
+            CobaltIntrinsic::SubscriptFail,
+        )?;
+        let subscript = self.builder.ins().iadd_imm(idx, 1);
+        let occurs = self.builder.ins().iconst(types::I64, occurs as i64);
+        self.builder
+            .ins()
+            .call(subscript_fail, &[subscript, occurs]);
+        self.builder.ins().trap(TrapCode::UnreachableCodeReached);
+
+        self.switch_to_block(ok_block);
+        self.builder.seal_block(ok_block);
+        Ok(())
+    }
+
+    /// Loads a pointer to the storage of the given registered data item into the function.
+    /// Subordinate items are addressed as an offset from their containing data object.
+    pub(super) fn load_sym_id_ptr(&mut self, sym_id: DataSymId) -> Result<Value> {
+        let (data_id, offset) = {
+            let sym = self.data.sym(sym_id);
+            (sym.data_id, sym.offset)
+        };
+        let base_ptr = self.load_static_ptr(data_id)?;
+        if offset == 0 {
+            return Ok(base_ptr);
+        }
+        Ok(self.builder.ins().iadd_imm(base_ptr, offset as i64))
+    }
+
     /// Loads an immutable pointer to the data associated with the given [`DataId`] into the function.
     /// Utilises static value cache when possible.
     pub(super) fn load_static_ptr(&mut self, data_id: DataId) -> Result<Value> {
@@ -514,3 +1025,61 @@ impl<'src> MoveRef<'src> {
         })
     }
 }
+
+/// Creates a reference to the item with the given name, found by following the given path of
+/// group names (from the outermost inwards) from the group referenced by the given operand.
+/// The operand's qualifiers must include the operand's name, and its subscripts are kept.
+fn qualify_corresponding<'src>(
+    name: &'src str,
+    path: &[&'src str],
+    operand_quals: &[&'src str],
+    operand: &DataRef<'src>,
+) -> DataRef<'src> {
+    DataRef {
+        name,
+        qualifiers: path
+            .iter()
+            .rev()
+            .chain(operand_quals.iter())
+            .copied()
+            .collect(),
+        subscripts: operand.subscripts.clone(),
+    }
+}
+
+/// Finds the names of all items within the given source items which correspond to items within
+/// the given destination items, along with the path of group names leading to each from the
+/// given items, from the outermost group inwards.
+fn find_corresponding<'src>(
+    src_items: &[DataItem<'src>],
+    dest_items: &[DataItem<'src>],
+    numeric: bool,
+    path: &mut Vec<&'src str>,
+    found: &mut Vec<(Vec<&'src str>, &'src str)>,
+) {
+    let excluded = |item: &DataItem<'_>| {
+        item.redefines.is_some() || item.occurs.is_some() || item.name == "FILLER"
+    };
+    for src_item in src_items.iter().filter(|item| !excluded(item)) {
+        let Some(dest_item) = dest_items
+            .iter()
+            .find(|item| item.name == src_item.name && !excluded(item))
+        else {
+            continue;
+        };
+        match (&src_item.kind, &dest_item.kind) {
+            (DataItemKind::Group(src_children), DataItemKind::Group(dest_children)) => {
+                path.push(src_item.name);
+                find_corresponding(src_children, dest_children, numeric, path, found);
+                path.pop();
+            }
+            (DataItemKind::Elementary(src_elem), DataItemKind::Elementary(dest_elem))
+                if numeric && (src_elem.pic.is_str() || dest_elem.pic.is_str()) => {}
+            (DataItemKind::Elementary(_), DataItemKind::Elementary(_)) => {
+                found.push((path.clone(), src_item.name))
+            }
+            _ if numeric => {}
+            _ => found.push((path.clone(), src_item.name)),
+        }
+    }
+}
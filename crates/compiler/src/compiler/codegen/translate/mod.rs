@@ -1,17 +1,21 @@
-use crate::compiler::parser::{Ast, Spanned, Stat};
+use std::collections::HashMap;
+
+use crate::compiler::parser::{self, Ast, DataRef, ExitType, GoToData, Spanned, Stat};
 use cranelift::{
-    codegen::ir::{Block, InstBuilder},
-    frontend::FunctionBuilder,
+    codegen::{
+        entity::EntityRef,
+        ir::{types, Block, InstBuilder, Type, Value},
+    },
+    frontend::{FunctionBuilder, Variable},
 };
 use cranelift_module::Module;
 use cranelift_object::ObjectModule;
 use miette::Result;
 
-use self::value::ValueCache;
+use self::{para::ParaBlocks, value::ValueCache};
 
 use super::{
-    data::DataManager,
-    func::FuncManager,
+    data::{DataManager, RETURN_CODE_REGISTER},
     intrinsics::{CobaltIntrinsic, IntrinsicManager},
 };
 
@@ -21,6 +25,8 @@ mod intrinsics;
 mod io;
 mod math;
 mod memory;
+mod para;
+mod table;
 mod value;
 
 /// Structure for translating function-level AST nodes to Cranelift IR.
@@ -40,11 +46,34 @@ pub(super) struct FuncTranslator<'a, 'src: 'a> {
     /// The data manager for this function.
     pub data: &'a mut DataManager,
 
-    /// The function manager for the program.
-    pub funcs: &'a mut FuncManager,
-
     /// Cache of values loaded for this function.
     values: ValueCache,
+
+    /// The index of the next Cranelift variable to be declared within this function.
+    next_var: usize,
+
+    /// The blocks and state of each paragraph within the procedure division, in order.
+    paras: Vec<ParaBlocks<'src>>,
+
+    /// A map of section indices and paragraph names to the paragraph's index within `paras`.
+    para_map: HashMap<(usize, &'src str), usize>,
+
+    /// The indices of the first and last paragraphs within each section, in order.
+    sections: Vec<(usize, usize)>,
+
+    /// A map of section names to their index within `sections`.
+    section_map: HashMap<&'src str, usize>,
+
+    /// The index of the paragraph currently being translated, if any.
+    cur_para: Option<usize>,
+
+    /// The block following the sentence currently being translated, if it is the target of a
+    /// "NEXT SENTENCE" statement.
+    next_sentence: Option<Block>,
+
+    /// The (exit, cycle) blocks of each inline PERFORM statement currently being translated,
+    /// from the outermost inwards. Used as the targets of "EXIT PERFORM [CYCLE]".
+    perform_exits: Vec<(Block, Block)>,
 }
 
 impl<'a, 'src> FuncTranslator<'a, 'src> {
@@ -55,7 +84,6 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         ast: &'a Ast<'src>,
         intrinsics: &'a mut IntrinsicManager,
         data: &'a mut DataManager,
-        funcs: &'a mut FuncManager,
     ) -> Self {
         Self {
             builder,
@@ -63,18 +91,21 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
             ast,
             intrinsics,
             data,
-            funcs,
             values: ValueCache::new(),
+            next_var: 0,
+            paras: Vec::new(),
+            para_map: HashMap::new(),
+            sections: Vec::new(),
+            section_map: HashMap::new(),
+            cur_para: None,
+            next_sentence: None,
+            perform_exits: Vec::new(),
         }
     }
 
-    /// Generates Cranelift IR for a single statement from the given set of statements.
-    pub fn translate(&mut self, stats: &Vec<Spanned<Stat<'src>>>) -> Result<()> {
-        // Reset the intrinsics manager, function manager since we're beginning a new function.
-        self.intrinsics.clear_refs();
-        self.funcs.clear_refs();
-
-        // Translate all statements within the function.
+    /// Generates Cranelift IR for the given set of statements, in order.
+    /// Returns whether the statements unconditionally terminate the current block.
+    fn translate_stats(&mut self, stats: &Vec<Spanned<Stat<'src>>>) -> Result<bool> {
         let mut block_self_terminates = false;
         for stat in stats {
             // If this block has already unconditionally terminated, we don't want to generate anything else.
@@ -83,21 +114,39 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
             }
             block_self_terminates |= self.translate_stat(stat)?;
         }
-        Ok(())
+        Ok(block_self_terminates)
     }
 
     /// Generates Cranelift IR for a program termination.
-    /// Required for paragraphs which unconditionally terminate the program (e.g. with `STOP RUN`).
-    pub fn translate_terminate(&mut self) -> Result<()> {
+    /// The program exits with the value of the "RETURN-CODE" special register as its status.
+    fn translate_terminate(&mut self) -> Result<()> {
         let libc_exit =
             self.intrinsics
                 .get_ref(self.module, self.builder.func, CobaltIntrinsic::LibcExit)?;
-        let ptr_type = self.module.target_config().pointer_type();
-        let exit_code = self.builder.ins().iconst(ptr_type, 0x0);
+        let exit_code = self.load_return_code()?;
         self.builder.ins().call(libc_exit, &[exit_code]);
         Ok(())
     }
 
+    /// Generates Cranelift IR for returning from the program, with the value of the
+    /// "RETURN-CODE" special register as the exit status.
+    fn translate_return(&mut self) -> Result<()> {
+        let ret_val = self.load_return_code()?;
+        self.builder.ins().return_(&[ret_val]);
+        Ok(())
+    }
+
+    /// Loads the value of the "RETURN-CODE" special register as a native integer.
+    fn load_return_code(&mut self) -> Result<Value> {
+        let return_code = self.load_num(&parser::Value::Variable(return_code_ref()))?;
+        let int = self.module.target_config().pointer_type();
+        if int == types::I64 {
+            Ok(return_code.val)
+        } else {
+            Ok(self.builder.ins().ireduce(int, return_code.val))
+        }
+    }
+
     /// Generates Cranelift IR for a single statement from the given set of statements.
     /// Returns whether this statement has filled the current block.
     fn translate_stat(&mut self, stat: &Spanned<Stat<'src>>) -> Result<bool> {
@@ -105,23 +154,55 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         match &stat.0 {
             Stat::Display(vals) => self.translate_display(vals)?,
             Stat::Move(mov_data) => self.translate_move(mov_data)?,
+            Stat::Initialize(init) => self.translate_initialize(init)?,
             Stat::Add(op_data) => self.translate_add(op_data)?,
             Stat::Subtract(op_data) => self.translate_subtract(op_data)?,
             Stat::Multiply(op_data) => self.translate_multiply(op_data)?,
             Stat::Divide(div_data) => self.translate_divide(div_data)?,
+            Stat::Compute(compute) => self.translate_compute(compute)?,
             Stat::If(if_data) => self.translate_if(if_data)?,
+            Stat::Evaluate(eval) => self.translate_evaluate(eval)?,
             Stat::Perform(perform) => self.translate_perform(perform)?,
             Stat::Accept(target) => self.translate_accept(target)?,
             Stat::Exit(exit_type) => self.translate_exit(exit_type)?,
+            Stat::GoTo(go_to) => self.translate_go_to(go_to)?,
+            Stat::StopRun(status) => self.translate_stop_run(status)?,
+            Stat::GoBack => self.translate_return()?,
+            Stat::Continue => {}
+            Stat::NextSentence => {
+                let next_sentence = *self
+                    .next_sentence
+                    .get_or_insert_with(|| self.builder.create_block());
+                self.builder.ins().jump(next_sentence, &[]);
+            }
+            Stat::Search(search) => self.translate_search(search)?,
+            Stat::SearchAll(search) => self.translate_search_all(search)?,
+            Stat::Set(set_data) => self.translate_set(set_data)?,
         }
 
         // Determine whether the statement has filled the block.
         match &stat.0 {
-            Stat::Exit(_) => Ok(true),
+            Stat::Exit(ExitType::Program) => Ok(false),
+            Stat::Exit(_)
+            | Stat::StopRun(_)
+            | Stat::GoBack
+            | Stat::NextSentence
+            | Stat::GoTo(GoToData {
+                depending_on: None,
+                ..
+            }) => Ok(true),
             _ => Ok(false),
         }
     }
 
+    /// Declares a new Cranelift variable of the given type within the current function.
+    fn declare_var(&mut self, ty: Type) -> Variable {
+        let var = Variable::new(self.next_var);
+        self.next_var += 1;
+        self.builder.declare_var(var, ty);
+        var
+    }
+
     /// Switches the function translator to point to the given block.
     /// The prior block must have a terminator instruction inserted before this is called.
     fn switch_to_block(&mut self, block: Block) {
@@ -129,3 +210,12 @@ impl<'a, 'src> FuncTranslator<'a, 'src> {
         self.builder.switch_to_block(block);
     }
 }
+
+/// Returns a reference to the "RETURN-CODE" special register.
+fn return_code_ref() -> DataRef<'static> {
+    DataRef {
+        name: RETURN_CODE_REGISTER,
+        qualifiers: Vec::new(),
+        subscripts: Vec::new(),
+    }
+}
@@ -0,0 +1,213 @@
+use cranelift::{
+    codegen::ir::{types, Block, InstBuilder},
+    frontend::{Switch, Variable},
+};
+use miette::Result;
+
+use crate::compiler::parser::{ProcDiv, ProcRef, Sentence};
+
+use super::FuncTranslator;
+
+/// The blocks and state of a single paragraph within the procedure division.
+/// Sections are flattened into a single sequence of paragraphs, each holding the index of the
+/// section containing it.
+///
+/// All paragraphs are translated into the same function, so control can be transferred between
+/// them with jumps alone. Rather than a call, a PERFORM statement records which of the end
+/// paragraph's return points to use, then jumps to the start paragraph. When the end paragraph
+/// completes, it jumps to the recorded return point, or falls through to the next paragraph if
+/// no PERFORM statement ending with it is active.
+pub(super) struct ParaBlocks<'src> {
+    /// The name of this paragraph, if it is not anonymous.
+    pub name: Option<&'src str>,
+
+    /// The index of the section containing this paragraph.
+    pub section: usize,
+
+    /// The block beginning the statements of this paragraph.
+    pub entry: Block,
+
+    /// The block reached once this paragraph completes, either by running out of statements or
+    /// with "EXIT PARAGRAPH".
+    pub exit: Block,
+
+    /// Holds the active return point for this paragraph, as an index into `return_blocks` offset
+    /// by one. When zero, there is no active PERFORM statement ending with this paragraph.
+    pub return_var: Variable,
+
+    /// The blocks following each PERFORM statement ending with this paragraph.
+    pub return_blocks: Vec<Block>,
+}
+
+impl<'a, 'src> FuncTranslator<'a, 'src> {
+    /// Generates Cranelift IR for the given procedure division, executing paragraphs in order
+    /// across all sections until the program is terminated, or the last paragraph completes.
+    pub fn translate_proc_div(&mut self, proc_div: &ProcDiv<'src>) -> Result<()> {
+        // Reset the intrinsics manager, since we're beginning a new function.
+        self.intrinsics.clear_refs();
+
+        // Create the blocks for each paragraph, with no return points active.
+        // Paragraph names only need to be unique within their section.
+        let zero = self.builder.ins().iconst(types::I64, 0);
+        for (sec_idx, section) in proc_div.sections.iter().enumerate() {
+            if let Some((name, _)) = section.name {
+                if self.section_map.insert(name, sec_idx).is_some() {
+                    miette::bail!("Duplicate sections defined with the symbol '{}'.", name);
+                }
+            }
+            let first_para = self.paras.len();
+            for para in section.paragraphs.iter() {
+                let name = para.name.map(|(name, _)| name);
+                if let Some(name) = name {
+                    if self
+                        .para_map
+                        .insert((sec_idx, name), self.paras.len())
+                        .is_some()
+                    {
+                        miette::bail!("Duplicate paragraphs defined with the symbol '{}'.", name);
+                    }
+                }
+                let return_var = self.declare_var(types::I64);
+                self.builder.def_var(return_var, zero);
+                self.paras.push(ParaBlocks {
+                    name,
+                    section: sec_idx,
+                    entry: self.builder.create_block(),
+                    exit: self.builder.create_block(),
+                    return_var,
+                    return_blocks: Vec::new(),
+                });
+            }
+            self.sections.push((first_para, self.paras.len() - 1));
+        }
+        let end_block = self.builder.create_block();
+        self.builder.ins().jump(self.paras[0].entry, &[]);
+
+        // Translate the statements of each paragraph.
+        let paragraphs = proc_div
+            .sections
+            .iter()
+            .flat_map(|section| section.paragraphs.iter());
+        for (idx, para) in paragraphs.enumerate() {
+            self.cur_para = Some(idx);
+            self.switch_to_block(self.paras[idx].entry);
+            if !self.translate_sentences(&para.sentences)? {
+                self.builder.ins().jump(self.paras[idx].exit, &[]);
+            }
+        }
+        self.cur_para = None;
+
+        // Return from each paragraph to the active PERFORM statement, if any, otherwise fall
+        // through to the next paragraph.
+        for idx in 0..self.paras.len() {
+            let next_block = self.paras.get(idx + 1).map_or(end_block, |para| para.entry);
+            let mut switch = Switch::new();
+            for (point, return_block) in self.paras[idx].return_blocks.iter().enumerate() {
+                switch.set_entry(point as u128 + 1, *return_block);
+            }
+            self.switch_to_block(self.paras[idx].exit);
+            if switch.entries().is_empty() {
+                self.builder.ins().jump(next_block, &[]);
+                continue;
+            }
+            let return_point = self.builder.use_var(self.paras[idx].return_var);
+            switch.emit(&mut self.builder, return_point, next_block);
+        }
+
+        // Once the last paragraph completes, return with the current return code.
+        self.switch_to_block(end_block);
+        self.translate_return()?;
+
+        // All jumps between paragraphs are now known.
+        self.builder.seal_all_blocks();
+        Ok(())
+    }
+
+    /// Generates Cranelift IR for the given sentences of a paragraph, in order.
+    /// Returns whether the sentences unconditionally terminate the current block.
+    fn translate_sentences(&mut self, sentences: &[Sentence<'src>]) -> Result<bool> {
+        let mut block_self_terminates = false;
+        for sentence in sentences {
+            if block_self_terminates {
+                miette::bail!("Unreachable statements detected in block: No statements should be placed after unconditional jumps.");
+            }
+            block_self_terminates = self.translate_stats(&sentence.stats)?;
+
+            // If "NEXT SENTENCE" was used, continue from the block it transfers control to.
+            if let Some(next_sentence) = self.next_sentence.take() {
+                if !block_self_terminates {
+                    self.builder.ins().jump(next_sentence, &[]);
+                }
+                self.builder.seal_block(next_sentence);
+                self.switch_to_block(next_sentence);
+                block_self_terminates = false;
+            }
+        }
+        Ok(block_self_terminates)
+    }
+
+    /// Returns the indices of the first and last paragraphs executed for the given reference,
+    /// which may name either a section or a paragraph.
+    /// Unqualified paragraph names are resolved within the current section first, and otherwise
+    /// must be unique within the program.
+    pub(super) fn resolve_proc(&self, proc_ref: &ProcRef<'src>) -> Result<(usize, usize)> {
+        if let Some(section) = proc_ref.section {
+            let Some(sec_idx) = self.section_map.get(section) else {
+                miette::bail!("No section with the name '{}' exists.", section);
+            };
+            let Some(para_idx) = self.para_map.get(&(*sec_idx, proc_ref.name)) else {
+                miette::bail!(
+                    "No paragraph with the name '{}' exists in section '{}'.",
+                    proc_ref.name,
+                    section
+                );
+            };
+            return Ok((*para_idx, *para_idx));
+        }
+        if let Some(sec_idx) = self.section_map.get(proc_ref.name) {
+            return Ok(self.sections[*sec_idx]);
+        }
+        if let Some(cur_para) = self.cur_para {
+            let cur_section = self.paras[cur_para].section;
+            if let Some(para_idx) = self.para_map.get(&(cur_section, proc_ref.name)) {
+                return Ok((*para_idx, *para_idx));
+            }
+        }
+
+        let matches: Vec<usize> = (0..self.paras.len())
+            .filter(|idx| self.paras[*idx].name == Some(proc_ref.name))
+            .collect();
+        match matches.as_slice() {
+            [para_idx] => Ok((*para_idx, *para_idx)),
+            [] => miette::bail!("No paragraph with the name '{}' exists.", proc_ref.name),
+            _ => miette::bail!(
+                "The paragraph name '{}' is ambiguous, and must be qualified with a section.",
+                proc_ref.name
+            ),
+        }
+    }
+
+    /// Translates a PERFORM of the paragraphs from `start` through `end` to Cranelift IR,
+    /// continuing in a new block once the end paragraph completes.
+    pub(super) fn translate_perform_range(&mut self, start: usize, end: usize) -> Result<()> {
+        let return_block = self.builder.create_block();
+        let end_para = &mut self.paras[end];
+        end_para.return_blocks.push(return_block);
+        let return_point = end_para.return_blocks.len() as i64;
+        let return_var = end_para.return_var;
+
+        // Save the return point of any enclosing PERFORM statement ending with the same paragraph,
+        // restoring it once this statement returns.
+        let saved_var = self.declare_var(types::I64);
+        let saved_point = self.builder.use_var(return_var);
+        self.builder.def_var(saved_var, saved_point);
+        let return_point = self.builder.ins().iconst(types::I64, return_point);
+        self.builder.def_var(return_var, return_point);
+        self.builder.ins().jump(self.paras[start].entry, &[]);
+
+        self.switch_to_block(return_block);
+        let saved_point = self.builder.use_var(saved_var);
+        self.builder.def_var(return_var, saved_point);
+        Ok(())
+    }
+}
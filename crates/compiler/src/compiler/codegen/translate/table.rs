@@ -0,0 +1,471 @@
+use cranelift::codegen::ir::{condcodes::IntCC, types, Block, InstBuilder, MemFlags, Value};
+use miette::Result;
+
+use crate::compiler::{
+    codegen::data::DataSymId,
+    parser::{
+        self, Cond, DataRef, Expr, MoveRef, Pic, SearchAllData, SearchData, SetAction, SetData,
+        Spanned, Stat,
+    },
+};
+
+use super::FuncTranslator;
+
+impl<'a, 'src> FuncTranslator<'a, 'src> {
+    /// Translates a single linear "SEARCH" statement into Cranelift IR.
+    pub(super) fn translate_search(&mut self, search: &SearchData<'src>) -> Result<()> {
+        let table_id = self.data.resolve(search.table)?;
+        let index = self.table_index(table_id, search.table)?;
+        if let Some(varying) = &search.varying {
+            self.verify_int_ref(varying, "The VARYING item of a SEARCH statement")?;
+        }
+        let count = self.load_table_count(table_id)?;
+
+        // Create blocks for the loop header, each condition check and body, the increment & the end.
+        let head_block = self.builder.create_block();
+        let check_blocks: Vec<Block> = search
+            .whens
+            .iter()
+            .map(|_| self.builder.create_block())
+            .collect();
+        let when_blocks: Vec<Block> = search
+            .whens
+            .iter()
+            .map(|_| self.builder.create_block())
+            .collect();
+        let incr_block = self.builder.create_block();
+        let at_end_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+        self.builder.ins().jump(head_block, &[]);
+
+        // Once the index has passed the final occurrence, the search has failed.
+        self.switch_to_block(head_block);
+        let idx_ptr = self.load_sym_id_ptr(index)?;
+        let idx = self.load_int(idx_ptr);
+        let past_end = self
+            .builder
+            .ins()
+            .icmp(IntCC::SignedGreaterThan, idx, count);
+        self.builder
+            .ins()
+            .brif(past_end, at_end_block, &[], check_blocks[0], &[]);
+
+        // Check each condition in turn against the current occurrence.
+        for (i, when) in search.whens.iter().enumerate() {
+            self.switch_to_block(check_blocks[i]);
+            let cond_result = self.translate_cond_eval(&when.cond)?;
+            let next_block = check_blocks.get(i + 1).copied().unwrap_or(incr_block);
+            self.builder
+                .ins()
+                .brif(cond_result, when_blocks[i], &[], next_block, &[]);
+
+            self.switch_to_block(when_blocks[i]);
+            self.translate_search_branch(&when.stats, trailing_block)?;
+        }
+
+        // No conditions matched, step forward the index (and VARYING item) and loop.
+        self.switch_to_block(incr_block);
+        let idx_ptr = self.load_sym_id_ptr(index)?;
+        self.translate_int_step(idx_ptr, &Pic::index(), 1)?;
+        if let Some(varying) = &search.varying {
+            let varying_ptr = self.load_sym_ptr(varying)?;
+            let varying_pic = self.data.sym_pic(varying)?.clone();
+            self.translate_int_step(varying_ptr, &varying_pic, 1)?;
+        }
+        self.builder.ins().jump(head_block, &[]);
+
+        // Translate the "AT END" branch.
+        self.switch_to_block(at_end_block);
+        self.translate_search_branch(
+            search.at_end.as_ref().unwrap_or(&Vec::new()),
+            trailing_block,
+        )?;
+
+        // All branches are now defined, we can seal everything.
+        for block in [head_block, incr_block, at_end_block]
+            .into_iter()
+            .chain(check_blocks)
+            .chain(when_blocks)
+        {
+            self.builder.seal_block(block);
+        }
+        self.switch_to_block(trailing_block);
+        self.builder.seal_block(trailing_block);
+        Ok(())
+    }
+
+    /// Translates a single binary "SEARCH ALL" statement into Cranelift IR.
+    pub(super) fn translate_search_all(&mut self, search: &SearchAllData<'src>) -> Result<()> {
+        let table_id = self.data.resolve(search.table)?;
+        let index = self.table_index(table_id, search.table)?;
+        let key_cmps = self.search_all_keys(table_id, search)?;
+        let count = self.load_table_count(table_id)?;
+
+        // Set up the lower & upper bounds of the search.
+        let lo_var = self.declare_var(types::I64);
+        let hi_var = self.declare_var(types::I64);
+        let one = self.builder.ins().iconst(types::I64, 1);
+        self.builder.def_var(lo_var, one);
+        self.builder.def_var(hi_var, count);
+
+        // Create blocks for the loop header, each key comparison, bound movement, and the results.
+        let head_block = self.builder.create_block();
+        let cmp_blocks: Vec<Block> = key_cmps
+            .iter()
+            .map(|_| self.builder.create_block())
+            .collect();
+        let ne_blocks: Vec<Block> = key_cmps
+            .iter()
+            .map(|_| self.builder.create_block())
+            .collect();
+        let go_up_block = self.builder.create_block();
+        let go_down_block = self.builder.create_block();
+        let found_block = self.builder.create_block();
+        let at_end_block = self.builder.create_block();
+        let trailing_block = self.builder.create_block();
+        self.builder.ins().jump(head_block, &[]);
+
+        // If the bounds have crossed, the search has failed.
+        // Otherwise, set the index to the midpoint of the bounds & compare.
+        self.switch_to_block(head_block);
+        let lo = self.builder.use_var(lo_var);
+        let hi = self.builder.use_var(hi_var);
+        let crossed = self.builder.ins().icmp(IntCC::SignedGreaterThan, lo, hi);
+        let mid_block = self.builder.create_block();
+        self.builder
+            .ins()
+            .brif(crossed, at_end_block, &[], mid_block, &[]);
+        self.switch_to_block(mid_block);
+        self.builder.seal_block(mid_block);
+        let sum = self.builder.ins().iadd(lo, hi);
+        let mid = self.builder.ins().sshr_imm(sum, 1);
+        let idx_ptr = self.load_sym_id_ptr(index)?;
+        self.builder.ins().store(MemFlags::new(), mid, idx_ptr, 0);
+        self.builder.ins().jump(cmp_blocks[0], &[]);
+
+        // Compare each key in order of significance. When a key differs, the occurrence at the
+        // midpoint is either before or after the target, depending on the key's ordering.
+        for (i, (key, target, ascending)) in key_cmps.iter().enumerate() {
+            self.switch_to_block(cmp_blocks[i]);
+            let eq = self.translate_cond_comp(key, target, IntCC::Equal)?;
+            let next_block = cmp_blocks.get(i + 1).copied().unwrap_or(found_block);
+            self.builder
+                .ins()
+                .brif(eq, next_block, &[], ne_blocks[i], &[]);
+
+            self.switch_to_block(ne_blocks[i]);
+            let lt = self.translate_cond_comp(key, target, IntCC::SignedLessThan)?;
+            let (lt_block, gt_block) = if *ascending {
+                (go_up_block, go_down_block)
+            } else {
+                (go_down_block, go_up_block)
+            };
+            self.builder.ins().brif(lt, lt_block, &[], gt_block, &[]);
+        }
+
+        // Move the bounds of the search past the midpoint.
+        for (block, bound_var, step) in [(go_up_block, lo_var, 1), (go_down_block, hi_var, -1)] {
+            self.switch_to_block(block);
+            let idx_ptr = self.load_sym_id_ptr(index)?;
+            let mid = self.load_int(idx_ptr);
+            let new_bound = self.builder.ins().iadd_imm(mid, step);
+            self.builder.def_var(bound_var, new_bound);
+            self.builder.ins().jump(head_block, &[]);
+        }
+
+        // Translate the result branches.
+        self.switch_to_block(found_block);
+        self.translate_search_branch(&search.when.stats, trailing_block)?;
+        self.switch_to_block(at_end_block);
+        self.translate_search_branch(
+            search.at_end.as_ref().unwrap_or(&Vec::new()),
+            trailing_block,
+        )?;
+
+        // All branches are now defined, we can seal everything.
+        for block in [
+            head_block,
+            go_up_block,
+            go_down_block,
+            found_block,
+            at_end_block,
+        ]
+        .into_iter()
+        .chain(cmp_blocks)
+        .chain(ne_blocks)
+        {
+            self.builder.seal_block(block);
+        }
+        self.switch_to_block(trailing_block);
+        self.builder.seal_block(trailing_block);
+        Ok(())
+    }
+
+    /// Translates a single "SET" statement into Cranelift IR.
+    pub(super) fn translate_set(&mut self, set_data: &SetData<'src>) -> Result<()> {
+        let (value, step_sign) = match &set_data.action {
+            SetAction::ToTrue => return self.translate_set_true(&set_data.targets),
+            SetAction::To(value) => (value, None),
+            SetAction::UpBy(value) => (value, Some(1)),
+            SetAction::DownBy(value) => (value, Some(-1)),
+        };
+        if value.is_str(self.data)? || value.is_float(self.data)? {
+            miette::bail!("The value provided to a SET statement must be an integer.");
+        }
+
+        for target in set_data.targets.iter() {
+            self.verify_int_ref(target, "The target of a SET statement")?;
+            let val = self.load_value(value)?;
+            let target_ptr = self.load_sym_ptr(target)?;
+            let new_val = match step_sign {
+                None => val,
+                Some(sign) => {
+                    let target_pic = self.data.sym_pic(target)?.clone();
+                    let cur = self.load_pic_value(target_ptr, &target_pic)?;
+                    if sign > 0 {
+                        self.builder.ins().iadd(cur, val)
+                    } else {
+                        self.builder.ins().isub(cur, val)
+                    }
+                }
+            };
+            let target_pic = self.data.sym_pic(target)?.clone();
+            self.store_pic_value(target_ptr, &target_pic, new_val)?;
+        }
+        Ok(())
+    }
+
+    /// Translates a single "SET ... TO TRUE" statement into Cranelift IR, moving the first value
+    /// of each target condition name into its item.
+    fn translate_set_true(&mut self, targets: &[DataRef<'src>]) -> Result<()> {
+        for target in targets {
+            let (item_ref, cond_name) = self.resolve_cond_name(target)?;
+            let dest = MoveRef {
+                sym: item_ref,
+                span: None,
+            };
+            self.translate_mov_lit(&cond_name.values[0].from, &dest)?;
+        }
+        Ok(())
+    }
+
+    /// Translates the statements of a single SEARCH branch, jumping to the given trailing block
+    /// afterwards if the statements do not terminate the block themselves.
+    fn translate_search_branch(
+        &mut self,
+        stats: &Vec<Spanned<Stat<'src>>>,
+        trailing_block: Block,
+    ) -> Result<()> {
+        if !self.translate_stats(stats)? {
+            self.builder.ins().jump(trailing_block, &[]);
+        }
+        Ok(())
+    }
+
+    /// Returns the first index of the given table, which is used to search it.
+    fn table_index(&self, table_id: DataSymId, name: &str) -> Result<DataSymId> {
+        let Some(table) = &self.data.sym(table_id).table else {
+            miette::bail!("Cannot search '{}', as it is not a table.", name);
+        };
+        let Some(index) = table.indexes.first() else {
+            miette::bail!(
+                "Cannot search table '{}', as it does not declare an index with INDEXED BY.",
+                name
+            );
+        };
+        self.data.resolve(index)
+    }
+
+    /// Generates Cranelift IR applying the given translation to each elementary item within the
+    /// given data item, stored at the given pointer, in storage order.
+    /// Subordinate tables are traversed with a loop over their current occurrences, rather than
+    /// repeating the translation for each occurrence.
+    pub(super) fn translate_for_each_elementary(
+        &mut self,
+        id: DataSymId,
+        ptr: Value,
+        translate: &mut dyn FnMut(&mut Self, DataSymId, Value) -> Result<()>,
+    ) -> Result<()> {
+        let sym = self.data.sym(id);
+        if !sym.is_group() {
+            return translate(self, id, ptr);
+        }
+        let (group_offset, children) = (sym.offset, sym.children.clone());
+        for child_id in children {
+            // Redefinitions overlay storage that is already covered by the item they redefine.
+            let child = self.data.sym(child_id);
+            if child.redefines.is_some() {
+                continue;
+            }
+            let stride = child.pic.storage_size();
+            let child_ptr = self
+                .builder
+                .ins()
+                .iadd_imm(ptr, (child.offset - group_offset) as i64);
+            if child.table.is_none() {
+                self.translate_for_each_elementary(child_id, child_ptr, translate)?;
+                continue;
+            }
+
+            // Loop over each current occurrence of the table.
+            let count = self.load_table_count(child_id)?;
+            let idx_var = self.declare_var(types::I64);
+            let zero = self.builder.ins().iconst(types::I64, 0);
+            self.builder.def_var(idx_var, zero);
+            let head_block = self.builder.create_block();
+            let body_block = self.builder.create_block();
+            let end_block = self.builder.create_block();
+            self.builder.ins().jump(head_block, &[]);
+
+            self.switch_to_block(head_block);
+            let idx = self.builder.use_var(idx_var);
+            let done = self
+                .builder
+                .ins()
+                .icmp(IntCC::SignedGreaterThanOrEqual, idx, count);
+            self.builder
+                .ins()
+                .brif(done, end_block, &[], body_block, &[]);
+
+            self.switch_to_block(body_block);
+            self.builder.seal_block(body_block);
+            let occurrence_offset = self.builder.ins().imul_imm(idx, stride as i64);
+            let occurrence_ptr = self.builder.ins().iadd(child_ptr, occurrence_offset);
+            self.translate_for_each_elementary(child_id, occurrence_ptr, translate)?;
+            let idx = self.builder.use_var(idx_var);
+            let next_idx = self.builder.ins().iadd_imm(idx, 1);
+            self.builder.def_var(idx_var, next_idx);
+            self.builder.ins().jump(head_block, &[]);
+            self.builder.seal_block(head_block);
+
+            self.switch_to_block(end_block);
+            self.builder.seal_block(end_block);
+        }
+        Ok(())
+    }
+
+    /// Loads the current length in bytes of the given data item.
+    /// For groups ending in a variable length table, only the current occurrences of the
+    /// table are counted.
+    pub(super) fn load_item_len(&mut self, id: DataSymId) -> Result<Value> {
+        let storage_size = self.data.sym(id).pic.storage_size() as i64;
+        let Some(table_id) = self.data.variable_table(id) else {
+            return Ok(self.builder.ins().iconst(types::I64, storage_size));
+        };
+        let (occurs, stride) = {
+            let table = self.data.sym(table_id);
+            (
+                table.table.as_ref().unwrap().occurs,
+                table.pic.storage_size(),
+            )
+        };
+        let count = self.load_table_count(table_id)?;
+        let unused = self.builder.ins().irsub_imm(count, occurs as i64);
+        let unused_len = self.builder.ins().imul_imm(unused, stride as i64);
+        Ok(self.builder.ins().irsub_imm(unused_len, storage_size))
+    }
+
+    /// Loads the current number of occurrences of the given table.
+    /// For variable length tables, this is the value of the DEPENDING ON item.
+    fn load_table_count(&mut self, table_id: DataSymId) -> Result<Value> {
+        let table = self
+            .data
+            .sym(table_id)
+            .table
+            .as_ref()
+            .expect("Attempted to load the occurrence count of a non-table item.");
+        let (occurs, depending_on) = (table.occurs, table.depending_on.clone());
+        let Some(depending_on) = depending_on else {
+            return Ok(self.builder.ins().iconst(types::I64, occurs as i64));
+        };
+
+        let depending_id = self.data.resolve(&depending_on)?;
+        let pic = &self.data.sym(depending_id).pic;
+        if pic.is_str() || pic.is_float() {
+            miette::bail!(
+                "The DEPENDING ON item of a table must be an integer, but '{}' is not.",
+                depending_on
+            );
+        }
+        let depending_ptr = self.load_sym_id_ptr(depending_id)?;
+        let pic = self.data.sym(depending_id).pic.clone();
+        self.load_pic_value(depending_ptr, &pic)
+    }
+
+    /// Extracts the key comparisons from the condition of a "SEARCH ALL" statement, as
+    /// (key, target, ascending) triples in order of key significance.
+    fn search_all_keys<'c>(
+        &self,
+        table_id: DataSymId,
+        search: &'c SearchAllData<'src>,
+    ) -> Result<Vec<(&'c Expr<'src>, &'c Expr<'src>, bool)>> {
+        let table = self.data.sym(table_id).table.as_ref().unwrap();
+        if table.keys.is_empty() {
+            miette::bail!(
+                "Cannot SEARCH ALL table '{}', as it does not declare a KEY.",
+                search.table
+            );
+        }
+
+        // Flatten out the condition into a list of equality comparisons.
+        let mut eqs = Vec::new();
+        let mut conds = vec![&search.when.cond];
+        while let Some(cond) = conds.pop() {
+            match cond {
+                Cond::And(l, r) => conds.extend([r.as_ref(), l.as_ref()]),
+                Cond::Eq(l, r) => eqs.push((l, r)),
+                _ => miette::bail!(
+                    "The condition of a SEARCH ALL statement may only contain equality checks joined by AND."
+                ),
+            }
+        }
+
+        // Match each comparison to its key.
+        let mut key_cmps = Vec::new();
+        for (l, r) in eqs {
+            let key_pos = |expr: &Expr<'src>| match expr {
+                Expr::Value(parser::Value::Variable(var)) => {
+                    table.keys.iter().position(|(key, _)| key == var.name)
+                }
+                _ => None,
+            };
+            let (key, target, pos) = match (key_pos(l), key_pos(r)) {
+                (Some(pos), _) => (l, r, pos),
+                (None, Some(pos)) => (r, l, pos),
+                (None, None) => miette::bail!(
+                    "The condition of a SEARCH ALL statement may only compare keys of table '{}'.",
+                    search.table
+                ),
+            };
+            self.verify_binary_eq_cmp(key, target)?;
+            key_cmps.push((pos, key, target, table.keys[pos].1));
+        }
+        key_cmps.sort_by_key(|(pos, ..)| *pos);
+        Ok(key_cmps
+            .into_iter()
+            .map(|(_, key, target, ascending)| (key, target, ascending))
+            .collect())
+    }
+
+    /// Verifies that the given data reference refers to an integer item.
+    fn verify_int_ref(&self, sym: &DataRef<'src>, desc: &str) -> Result<()> {
+        let pic = self.data.sym_pic(sym)?;
+        if pic.is_str() || pic.is_float() {
+            miette::bail!("{} must be an integer, but '{}' is not.", desc, sym);
+        }
+        Ok(())
+    }
+
+    /// Loads the integer stored at the given pointer.
+    /// Only valid for items with a COMP layout, such as index names.
+    fn load_int(&mut self, ptr: Value) -> Value {
+        self.builder.ins().load(types::I64, MemFlags::new(), ptr, 0)
+    }
+
+    /// Steps the integer stored at the given pointer with the given [`Pic`] layout by the given amount.
+    fn translate_int_step(&mut self, ptr: Value, pic: &Pic, step: i64) -> Result<()> {
+        let cur = self.load_pic_value(ptr, pic)?;
+        let new_val = self.builder.ins().iadd_imm(cur, step);
+        self.store_pic_value(ptr, pic, new_val)
+    }
+}
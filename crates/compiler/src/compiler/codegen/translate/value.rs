@@ -25,6 +25,14 @@ impl Display for CodegenLiteral {
     }
 }
 
+/// A single fixed point numeric value loaded into a function.
+/// The value is an integer, scaled by 10 to the power of the given number of decimal places.
+#[derive(Clone, Copy)]
+pub(super) struct NumValue {
+    pub val: Value,
+    pub scale: u32,
+}
+
 /// Collection of cached values used within a [`super::FuncTranslator`].
 pub(super) struct ValueCache {
     /// Cache of global values loaded for the current function.
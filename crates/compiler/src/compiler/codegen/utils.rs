@@ -1,30 +1,92 @@
 use miette::Result;
 
-use crate::compiler::parser::{Literal, Value};
+use crate::compiler::parser::{Expr, Figurative, InitCategory, Literal, Pic, Value};
 
 use super::data::DataManager;
 
 /// Codegen-related utility functions for [`Value`] structs from the parser.
 impl<'src> Value<'src> {
     /// Returns whether this value is a string or not.
+    /// All figurative constants other than "ZERO" are strings.
     pub(super) fn is_str(&self, dm: &DataManager) -> Result<bool> {
         match self {
             Value::Variable(sym) => Ok(dm.sym_pic(sym)?.is_str()),
             Value::Literal(lit) => Ok(match lit {
                 Literal::String(_) => true,
+                Literal::Figurative(fig) => *fig != Figurative::Zeros,
                 _ => false,
             }),
         }
     }
 
-    /// Returns whether this value is a float or not.
+    /// Returns whether this value is a fixed point number with a decimal point or not.
     pub(super) fn is_float(&self, dm: &DataManager) -> Result<bool> {
         match self {
             Value::Variable(sym) => Ok(dm.sym_pic(sym)?.is_float()),
             Value::Literal(lit) => Ok(match lit {
-                Literal::Float(_) => true,
+                Literal::Decimal(_) => true,
                 _ => false,
             }),
         }
     }
 }
+
+/// Codegen-related utility functions for [`InitCategory`] values from the parser.
+impl InitCategory {
+    /// Returns the category of elementary item with the given layout.
+    pub(super) fn of(pic: &Pic) -> Self {
+        if pic.is_edited() {
+            InitCategory::NumericEdited
+        } else if !pic.is_str() {
+            InitCategory::Numeric
+        } else if pic.is_alphabetic() {
+            InitCategory::Alphabetic
+        } else {
+            InitCategory::Alphanumeric
+        }
+    }
+
+    /// Returns the value items of this category are initialized to by default: spaces for
+    /// alphabetic and alphanumeric items, and zero for numeric and numeric-edited items.
+    pub(super) fn default_value(&self) -> Literal {
+        match self {
+            InitCategory::Alphabetic | InitCategory::Alphanumeric => {
+                Literal::Figurative(Figurative::Spaces)
+            }
+            InitCategory::Numeric | InitCategory::NumericEdited => {
+                Literal::Figurative(Figurative::Zeros)
+            }
+        }
+    }
+}
+
+/// Codegen-related utility functions for [`Expr`] structs from the parser.
+impl<'src> Expr<'src> {
+    /// Returns whether this expression is a string or not.
+    /// Only expressions consisting of a single value may be strings.
+    pub(super) fn is_str(&self, dm: &DataManager) -> Result<bool> {
+        match self {
+            Expr::Value(val) => val.is_str(dm),
+            _ => Ok(false),
+        }
+    }
+
+    /// Returns the figurative constant this expression consists of, if any.
+    pub(super) fn as_figurative(&self) -> Option<Figurative> {
+        match self {
+            Expr::Value(Value::Literal(Literal::Figurative(fig))) => Some(*fig),
+            _ => None,
+        }
+    }
+
+    /// Returns the most decimal places held by any single value within this expression.
+    pub(super) fn max_scale(&self, dm: &DataManager) -> Result<u32> {
+        match self {
+            Expr::Value(Value::Variable(sym)) => Ok(dm.sym_pic(sym)?.scale()),
+            Expr::Value(Value::Literal(Literal::Decimal(d))) => Ok(d.scale),
+            Expr::Value(_) | Expr::Intrinsic(_) => Ok(0),
+            Expr::Neg(inner) => inner.max_scale(dm),
+            Expr::BinOp(l, _, r) => Ok(l.max_scale(dm)?.max(r.max_scale(dm)?)),
+        }
+    }
+}
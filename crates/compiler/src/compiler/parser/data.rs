@@ -1,13 +1,16 @@
 use cranelift::codegen::ir::types;
-use miette::{Context, Result};
+use miette::Result;
 
-use super::{parser_bail, token::tok, Literal, Parser, ParserErrorContext, StrLitStore};
+use super::{
+    parser_bail, token::tok, Figurative, Literal, Parser, ParserErrorContext, StrLitId, StrLitStore,
+};
 
 /// Working storage section of a COBOL data division.
 #[derive(Debug)]
 pub(crate) struct WorkingStorageSec<'src> {
-    /// Vector of all atomic (non-grouped) data items.
-    pub elementary_data: Vec<ElementaryData<'src>>,
+    /// Vector of all top-level (level 01) data items.
+    /// Subordinate items are nested within their parent group items.
+    pub items: Vec<DataItem<'src>>,
 }
 
 impl<'src> Parser<'src> {
@@ -16,22 +19,68 @@ impl<'src> Parser<'src> {
         // Parse the header.
         self.consume_vec(&[tok![ws_section], tok![.], tok![eol]])?;
 
-        // Parse a sequence of elementary data items.
-        let mut elementary_data: Vec<ElementaryData<'src>> = Vec::new();
+        // Parse a sequence of top level data items.
+        let mut items: Vec<DataItem<'src>> = Vec::new();
         while self.peek() == tok![int_lit] {
-            elementary_data.push(self.wss_elem_data()?);
+            if self.peek_text() != "01" {
+                self.next()?;
+                parser_bail!(
+                    self,
+                    "Top level data items must begin with the level number '01'."
+                );
+            }
+            let item = self.wss_data_item()?;
+            self.verify_redefines(&items, &item)?;
+            items.push(item);
         }
 
-        Ok(WorkingStorageSec { elementary_data })
+        Ok(WorkingStorageSec { items })
     }
 }
 
-/// Description of a single elementary data item.
+/// Description of a single data item within a data division section.
+/// This may be either an elementary (atomic) item, or a group of subordinate items.
 #[derive(Debug)]
-pub(crate) struct ElementaryData<'src> {
-    /// The name of the variable.
+pub(crate) struct DataItem<'src> {
+    /// The name of the item.
     pub name: &'src str,
 
+    /// The name of the item this item redefines the storage of, if a REDEFINES clause is present.
+    pub redefines: Option<&'src str>,
+
+    /// The table layout of this item, if an OCCURS clause is present.
+    pub occurs: Option<OccursClause<'src>>,
+
+    /// The contents of the item.
+    pub kind: DataItemKind<'src>,
+
+    /// The level 88 condition names declared for this item.
+    pub conditions: Vec<CondName<'src>>,
+}
+
+/// Available kinds of data item within a data division section.
+#[derive(Debug)]
+pub(crate) enum DataItemKind<'src> {
+    /// A group item, containing a set of subordinate items in declaration order.
+    Group(Vec<DataItem<'src>>),
+
+    /// An elementary item, described by a PIC layout.
+    Elementary(ElementaryData),
+}
+
+impl<'src> DataItem<'src> {
+    /// Returns whether this item, or any of its subordinate items, declares an initial value.
+    fn has_initial_val(&self) -> bool {
+        match &self.kind {
+            DataItemKind::Group(children) => children.iter().any(|c| c.has_initial_val()),
+            DataItemKind::Elementary(elem) => elem.initial_val.is_some(),
+        }
+    }
+}
+
+/// Description of a single elementary data item.
+#[derive(Debug)]
+pub(crate) struct ElementaryData {
     /// The PIC description of the variable.
     pub pic: Pic,
 
@@ -39,71 +88,550 @@ pub(crate) struct ElementaryData<'src> {
     pub initial_val: Option<Literal>,
 }
 
+/// A single level 88 condition name, declared for the data item it is subordinate to.
+#[derive(Debug)]
+pub(crate) struct CondName<'src> {
+    /// The name of the condition.
+    pub name: &'src str,
+
+    /// The values of the item for which the condition holds.
+    /// There is always at least one value.
+    pub values: Vec<CondNameValue>,
+}
+
+/// A single value (or range of values) for which a condition name holds.
+#[derive(Debug)]
+pub(crate) struct CondNameValue {
+    /// The value, or the first value of the range if "THRU" is specified.
+    pub from: Literal,
+
+    /// The last value of the range (inclusive), if specified.
+    pub thru: Option<Literal>,
+}
+
+/// Description of a single OCCURS clause, declaring a data item as a table.
+#[derive(Debug)]
+pub(crate) struct OccursClause<'src> {
+    /// The maximum number of occurrences, used for determining the storage size of the table.
+    pub max: usize,
+
+    /// The item holding the current number of occurrences, for variable length tables.
+    pub depending_on: Option<&'src str>,
+
+    /// The keys the table's occurrences are ordered by, in order of significance.
+    pub keys: Vec<TableKey<'src>>,
+
+    /// The index names declared for this table.
+    pub indexed_by: Vec<&'src str>,
+}
+
+/// A single ASCENDING/DESCENDING key declared for a table.
+#[derive(Debug)]
+pub(crate) struct TableKey<'src> {
+    /// The name of the key item, subordinate to the table.
+    pub name: &'src str,
+
+    /// Whether the table is sorted in ascending order of this key.
+    pub ascending: bool,
+}
+
+/// The set of clauses parsed from a single data item description, prior to validation.
+#[derive(Default)]
+struct DataItemClauses<'src> {
+    pic: Option<Pic>,
+    usage: Option<PicUsage>,
+    sign_pos: Option<SignPosition>,
+    initial_val: Option<Literal>,
+    occurs: Option<OccursClause<'src>>,
+}
+
 impl<'src> Parser<'src> {
-    /// Parses a single elementary (atomic) data item from a COBOL working storage section.
-    /// These must begin with the code "01".
-    fn wss_elem_data(&mut self) -> Result<ElementaryData<'src>> {
-        let code = self.next()?;
-        if self.text(code) != "01" {
+    /// Parses a single data item from a COBOL working storage section, including any
+    /// subordinate items if the item is a group.
+    fn wss_data_item(&mut self) -> Result<DataItem<'src>> {
+        let level = self.parse_level()?;
+
+        // Parse the name, followed by all clauses until the terminating ".".
+        // A REDEFINES clause must immediately follow the name.
+        let name_tok = self.consume(tok![ident])?;
+        let name = self.text(name_tok);
+        let redefines = if self.peek() == tok![redefines] {
+            self.next()?;
+            let base_tok = self.consume(tok![ident])?;
+            Some(self.text(base_tok))
+        } else {
+            None
+        };
+        let clauses = self.wss_item_clauses(name)?;
+        self.consume_vec(&[tok![.], tok![eol]])?;
+
+        // Top level items cannot be tables.
+        if level == 1 && clauses.occurs.is_some() {
+            parser_bail!(
+                self,
+                "Data item '{}' cannot use an OCCURS clause, as it is a level 01 item.",
+                name
+            );
+        }
+
+        // If there is no PIC clause, this is a group item.
+        let Some(pic) = clauses.pic else {
+            if clauses.usage.is_some()
+                || clauses.sign_pos.is_some()
+                || clauses.initial_val.is_some()
+            {
+                parser_bail!(
+                    self,
+                    "Group item '{}' cannot have a USAGE, SIGN or VALUE clause.",
+                    name
+                );
+            }
+            let children = self.wss_group_children(level, name)?;
+            if redefines.is_some() && children.iter().any(|c| c.has_initial_val()) {
+                parser_bail!(
+                    self,
+                    "Items subordinate to redefining item '{}' cannot have a VALUE clause.",
+                    name
+                );
+            }
+            return Ok(DataItem {
+                name,
+                redefines,
+                occurs: clauses.occurs,
+                kind: DataItemKind::Group(children),
+                conditions: Vec::new(),
+            });
+        };
+
+        // Items are stored as DISPLAY unless otherwise specified. Only numeric items may be
+        // stored in any other format, and only DISPLAY items may have their sign position specified.
+        let usage = clauses.usage.unwrap_or(PicUsage::Display);
+        if pic.is_str() && usage != PicUsage::Display {
             parser_bail!(
                 self,
-                "Elementary data items must begin with the data type '01'."
+                "Alphanumeric data item '{}' must have DISPLAY usage.",
+                name
             );
         }
+        if clauses.sign_pos.is_some() && (!pic.is_signed() || usage != PicUsage::Display) {
+            parser_bail!(
+                self,
+                "Data item '{}' cannot have a SIGN clause, as it is not a signed DISPLAY numeric item.",
+                name
+            );
+        }
+        let pic = pic.with_usage(usage, clauses.sign_pos.unwrap_or_default());
 
-        // Parse the name.
+        // Redefining items share storage with another item, so cannot be initialised.
+        if redefines.is_some() && clauses.initial_val.is_some() {
+            parser_bail!(
+                self,
+                "Redefining item '{}' cannot have a VALUE clause.",
+                name
+            );
+        }
+
+        // Numeric-edited items are initialised with their edited text, rather than being edited.
+        if pic.is_edited()
+            && matches!(
+                clauses.initial_val,
+                Some(Literal::Int(_) | Literal::Decimal(_))
+            )
+        {
+            parser_bail!(
+                self,
+                "Initial value for numeric-edited item '{}' must be an alphanumeric literal.",
+                name
+            );
+        }
+
+        // Check any initial value fits the PIC layout. This also checks size bounds.
+        if clauses
+            .initial_val
+            .as_ref()
+            .is_some_and(|lit| !pic.verify_lit(&self.str_lits, lit))
+        {
+            parser_bail!(
+                self,
+                "Initial value for variable '{}' does not fit data layout.",
+                name
+            );
+        }
+
+        // Parse out any condition names declared for this item.
+        let mut conditions: Vec<CondName<'src>> = Vec::new();
+        while self.peek_level() == Some(88) {
+            conditions.push(self.wss_cond_name(&pic)?);
+        }
+
+        // Elementary items cannot contain any subordinate items.
+        if self.peek_level().is_some_and(|l| l > level) {
+            self.next()?;
+            parser_bail!(
+                self,
+                "Elementary data item '{}' cannot contain subordinate items.",
+                name
+            );
+        }
+
+        Ok(DataItem {
+            name,
+            redefines,
+            occurs: clauses.occurs,
+            kind: DataItemKind::Elementary(ElementaryData {
+                pic,
+                initial_val: clauses.initial_val,
+            }),
+            conditions,
+        })
+    }
+
+    /// Parses a single level 88 condition name entry for an item with the given PIC layout, in the form:
+    /// `88 name VALUE [IS] v1 [THRU v2] [, v3 [THRU v4]...].`
+    fn wss_cond_name(&mut self, pic: &Pic) -> Result<CondName<'src>> {
+        self.consume(tok![int_lit])?;
         let name_tok = self.consume(tok![ident])?;
         let name = self.text(name_tok);
+        self.consume(tok![value])?;
+        if self.peek() == tok![is] {
+            self.next()?;
+        }
 
-        // Parse the PIC layout.
-        self.consume(tok![pic])?;
-        let pic_tok = self.consume(tok![pic_clause])?;
-        let pic_parser = PicParser::new(self, self.text(pic_tok));
-        let pic = pic_parser.parse()?;
+        // Parse all values (there must be at least one).
+        // Unlike initial values, these are used within the procedure division, so must be stored.
+        let mut values: Vec<CondNameValue> = Vec::new();
+        loop {
+            let from = self.literal()?;
+            let thru = (self.peek() == tok![thru])
+                .then(|| {
+                    self.next()?;
+                    self.literal()
+                })
+                .transpose()?;
+            for lit in std::iter::once(&from).chain(thru.as_ref()) {
+                if !pic.verify_lit(&self.str_lits, lit) {
+                    parser_bail!(
+                        self,
+                        "Value '{}' for condition name '{}' does not fit the data layout of its item.",
+                        lit.text(&self.str_lits),
+                        name
+                    );
+                }
+            }
+            values.push(CondNameValue { from, thru });
 
-        // For now, non-string values *must* be COMP.
-        if !pic.is_str() {
-            self.consume(tok![comp])
-                .context("Non-string variables must be COMP.")?;
+            if self.peek() == tok![,] {
+                self.next()?;
+            } else if !matches!(
+                self.peek(),
+                tok![str_literal] | tok![int_lit] | tok![float_lit]
+            ) {
+                break;
+            }
         }
+        self.consume_vec(&[tok![.], tok![eol]])?;
 
-        // Parse an initial value, if present.
-        let initial_val = if self.peek() == tok![value] {
-            self.next()?;
+        Ok(CondName { name, values })
+    }
 
-            // For the data section specifically, we have to be a little careful:
-            // - String literals parsed here must not be stored in `.rodata` (since we're storing it elsewhere).
-            // - Using [`Self::literal()`] assumes that any strings found are stored in `.rodata`.
-            // Thus, here, we manually check for a string first before parsing out a literal.
-            let lit = if self.peek() == tok![str_literal] {
-                let txt = self.consume_str()?;
-                let lit_id = self.str_lits.insert_transient(txt);
-                Literal::String(lit_id)
-            } else {
-                self.literal()?
-            };
+    /// Parses the set of subordinate items for a group item of the given level.
+    /// There must be at least one subordinate item, and all direct children must share a level number.
+    fn wss_group_children(&mut self, level: u8, name: &'src str) -> Result<Vec<DataItem<'src>>> {
+        let child_level = match self.peek_level() {
+            Some(l) if l > level => l,
+            _ => parser_bail!(
+                self,
+                "Group item '{}' must contain at least one subordinate item.",
+                name
+            ),
+        };
+
+        // Parse children until we reach an item at or above the level of this group.
+        let mut children: Vec<DataItem<'src>> = Vec::new();
+        while let Some(next_level) = self.peek_level() {
+            if next_level <= level {
+                break;
+            }
+            if next_level != child_level {
+                self.next()?;
+                parser_bail!(
+                    self,
+                    "Level number {:02} does not match any enclosing level within group '{}'.",
+                    next_level,
+                    name
+                );
+            }
+            let child = self.wss_data_item()?;
+            self.verify_redefines(&children, &child)?;
+            children.push(child);
+        }
+        Ok(children)
+    }
+
+    /// Verifies that the REDEFINES clause of the given item (if any) is valid, given the
+    /// items preceding it at the same level.
+    /// An item may only redefine the closest preceding item at its level that is not itself a
+    /// redefinition, and that item cannot be a table.
+    fn verify_redefines(&self, preceding: &[DataItem<'src>], item: &DataItem<'src>) -> Result<()> {
+        let Some(base_name) = item.redefines else {
+            return Ok(());
+        };
+        match preceding.iter().rev().find(|i| i.redefines.is_none()) {
+            Some(base) if base.name == base_name => {
+                if base.occurs.is_some() {
+                    parser_bail!(
+                        self,
+                        "Data item '{}' cannot redefine '{}', as it is a table.",
+                        item.name,
+                        base_name
+                    );
+                }
+                Ok(())
+            }
+            _ => parser_bail!(
+                self,
+                "Data item '{}' can only redefine the item immediately preceding it at the same level, not '{}'.",
+                item.name,
+                base_name
+            ),
+        }
+    }
+
+    /// Parses a single level number from the current position.
+    /// Valid level numbers are between 01 and 49 inclusive.
+    fn parse_level(&mut self) -> Result<u8> {
+        let level_tok = self.consume(tok![int_lit])?;
+        let level = self
+            .text(level_tok)
+            .parse::<u8>()
+            .ctx(self, "Failed to parse data item level number.".into())?;
+        if !(1..=49).contains(&level) {
+            parser_bail!(
+                self,
+                "Invalid level number {:02}, expected a level between 01 and 49.",
+                level
+            );
+        }
+        Ok(level)
+    }
+
+    /// Peeks the level number of the next data item, if the next token is a level number.
+    fn peek_level(&mut self) -> Option<u8> {
+        if self.peek() != tok![int_lit] {
+            return None;
+        }
+        self.peek_text().parse::<u8>().ok()
+    }
 
-            // Check the given literal fits the PIC layout. This also checks size bounds.
-            if !pic.verify_lit(&self.str_lits, &lit) {
+    /// Parses all clauses describing a single data item, up until the terminating ".".
+    /// Clauses may appear in any order, but each may only appear once.
+    fn wss_item_clauses(&mut self, name: &'src str) -> Result<DataItemClauses<'src>> {
+        let mut clauses = DataItemClauses::default();
+        while self.peek() != tok![.] {
+            let clause_tok = self.peek();
+            let duplicate = match clause_tok {
+                // Clauses may be split over multiple lines.
+                tok![eol] => {
+                    self.next()?;
+                    false
+                }
+                tok![pic] => clauses.pic.replace(self.wss_pic()?).is_some(),
+                tok![usage] | tok![comp] | tok![comp_3] | tok![packed_decimal] | tok![display] => {
+                    clauses.usage.replace(self.wss_usage()?).is_some()
+                }
+                tok![sign] | tok![leading] | tok![trailing] => {
+                    clauses.sign_pos.replace(self.wss_sign()?).is_some()
+                }
+                tok![value] => clauses
+                    .initial_val
+                    .replace(self.wss_initial_val()?)
+                    .is_some(),
+                tok![occurs] => clauses.occurs.replace(self.wss_occurs()?).is_some(),
+                tok => {
+                    self.next()?;
+                    parser_bail!(
+                        self,
+                        "Unexpected token '{}' in the description of data item '{}'.",
+                        tok,
+                        name
+                    );
+                }
+            };
+            if duplicate {
                 parser_bail!(
                     self,
-                    "Initial value for variable '{}' does not fit data layout.",
+                    "Duplicate {} clause in the description of data item '{}'.",
+                    clause_tok,
                     name
                 );
             }
+        }
+        Ok(clauses)
+    }
 
-            Some(lit)
+    /// Parses a single PIC clause from the current position.
+    fn wss_pic(&mut self) -> Result<Pic> {
+        self.consume(tok![pic])?;
+        let pic_tok = self.consume(tok![pic_clause])?;
+        let pic_parser = PicParser::new(self, self.text(pic_tok));
+        pic_parser.parse()
+    }
+
+    /// Parses a single USAGE clause from the current position, in the form:
+    /// `[USAGE [IS]] COMP/COMP-3/PACKED-DECIMAL/DISPLAY`.
+    fn wss_usage(&mut self) -> Result<PicUsage> {
+        if self.peek() == tok![usage] {
+            self.next()?;
+            if self.peek() == tok![is] {
+                self.next()?;
+            }
+        }
+        match self.next()?.0 {
+            tok![comp] => Ok(PicUsage::Comp),
+            tok![comp_3] | tok![packed_decimal] => Ok(PicUsage::Packed),
+            tok![display] => Ok(PicUsage::Display),
+            tok => parser_bail!(
+                self,
+                "Invalid usage '{}', expected one of 'COMP', 'COMP-3' or 'DISPLAY'.",
+                tok
+            ),
+        }
+    }
+
+    /// Parses a single SIGN clause from the current position, in the form:
+    /// `[SIGN [IS]] LEADING/TRAILING [SEPARATE [CHARACTER]]`.
+    fn wss_sign(&mut self) -> Result<SignPosition> {
+        if self.peek() == tok![sign] {
+            self.next()?;
+            if self.peek() == tok![is] {
+                self.next()?;
+            }
+        }
+        let leading = match self.next()?.0 {
+            tok![leading] => true,
+            tok![trailing] => false,
+            tok => parser_bail!(
+                self,
+                "Invalid sign position '{}', expected one of 'LEADING' or 'TRAILING'.",
+                tok
+            ),
+        };
+        let separate = self.peek() == tok![separate];
+        if separate {
+            self.next()?;
+            if self.peek() == tok![character] {
+                self.next()?;
+            }
+        }
+        Ok(match (leading, separate) {
+            (true, true) => SignPosition::LeadingSeparate,
+            (true, false) => SignPosition::LeadingOverpunch,
+            (false, true) => SignPosition::TrailingSeparate,
+            (false, false) => SignPosition::TrailingOverpunch,
+        })
+    }
+
+    /// Parses a single VALUE clause from the current position.
+    fn wss_initial_val(&mut self) -> Result<Literal> {
+        self.consume(tok![value])?;
+
+        // For the data section specifically, we have to be a little careful:
+        // - String literals parsed here must not be stored in `.rodata` (since we're storing it elsewhere).
+        // - Using [`Self::literal()`] assumes that any strings found are stored in `.rodata`.
+        // Thus, here, we manually check for a string first before parsing out a literal.
+        if self.peek() == tok![str_literal] {
+            let txt = self.consume_str()?;
+            let lit_id = self.str_lits.insert_transient(txt);
+            Ok(Literal::String(lit_id))
+        } else {
+            self.literal()
+        }
+    }
+
+    /// Parses a single OCCURS clause from the current position, in the form:
+    /// `OCCURS [min TO] max [TIMES] [DEPENDING ON x] [ASCENDING/DESCENDING KEY IS k...] [INDEXED BY i...]`.
+    fn wss_occurs(&mut self) -> Result<OccursClause<'src>> {
+        self.consume(tok![occurs])?;
+        let min = self.consume_int()?;
+        let max = if self.peek() == tok![to] {
+            self.next()?;
+            self.consume_int()?
+        } else {
+            min
+        };
+        if min < 0 || max < 1 || min > max {
+            parser_bail!(
+                self,
+                "Invalid number of occurrences {} to {} for OCCURS clause.",
+                min,
+                max
+            );
+        }
+        if self.peek() == tok![times] {
+            self.next()?;
+        }
+
+        // Variable length tables must specify the item containing their length.
+        while self.peek() == tok![eol] {
+            self.next()?;
+        }
+        let depending_on = if self.peek() == tok![depending] {
+            self.next()?;
+            if self.peek() == tok![on] {
+                self.next()?;
+            }
+            let count_tok = self.consume(tok![ident])?;
+            Some(self.text(count_tok))
         } else {
             None
         };
+        // "OCCURS n DEPENDING ON x" is valid shorthand for "OCCURS 0 TO n DEPENDING ON x".
+        if depending_on.is_none() && min != max {
+            parser_bail!(
+                self,
+                "Variable length OCCURS clauses must include a DEPENDING ON phrase."
+            );
+        }
 
-        // Data elements must end with a ".", EOL.
-        self.consume_vec(&[tok![.], tok![eol]])?;
+        // Parse out any trailing keys, index names.
+        let mut keys: Vec<TableKey<'src>> = Vec::new();
+        let mut indexed_by: Vec<&'src str> = Vec::new();
+        loop {
+            // Phrases may be split over multiple lines.
+            while self.peek() == tok![eol] {
+                self.next()?;
+            }
+            match self.peek() {
+                tok![ascending] | tok![descending] => {
+                    let ascending = self.next()?.0 == tok![ascending];
+                    self.consume(tok![key])?;
+                    if self.peek() == tok![is] {
+                        self.next()?;
+                    }
+                    while self.peek() == tok![ident] {
+                        let key_tok = self.next()?;
+                        keys.push(TableKey {
+                            name: self.text(key_tok),
+                            ascending,
+                        });
+                    }
+                }
+                tok![indexed] => {
+                    self.consume_vec(&[tok![indexed], tok![by]])?;
+                    while self.peek() == tok![ident] {
+                        let idx_tok = self.next()?;
+                        indexed_by.push(self.text(idx_tok));
+                    }
+                }
+                _ => break,
+            }
+        }
 
-        Ok(ElementaryData {
-            name,
-            pic,
-            initial_val,
+        Ok(OccursClause {
+            max: max as usize,
+            depending_on,
+            keys,
+            indexed_by,
         })
     }
 }
@@ -115,70 +643,307 @@ pub(crate) struct Pic {
     pub layout_chunks: Vec<PicLayoutChunk>,
 
     /// The total length of this data layout, in bytes.
-    /// Only valid for uncompressed BCD non-comp variables or strings.
-    /// For other types of variable, instead reference [`Pic::comp_size()`].
+    /// Only valid for DISPLAY variables (numeric or string).
+    /// For other types of variable, instead reference [`Pic::storage_size()`].
     pub byte_len: usize,
+
+    /// The format this data layout is stored in.
+    pub usage: PicUsage,
+
+    /// The position of the sign for signed DISPLAY numeric layouts.
+    pub sign_pos: SignPosition,
+
+    /// For numeric-edited layouts, the editing mask applied when numeric values are stored.
+    /// Contains one character per editing symbol, e.g. `$,$$9.99CR`.
+    pub edit_mask: Option<StrLitId>,
+}
+
+/// Available storage formats for data layouts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PicUsage {
+    /// Each character (or digit) is stored as a single byte of text.
+    Display,
+
+    /// Numeric values are stored as a single native binary value.
+    Comp,
+
+    /// Numeric values are stored as packed binary coded decimal, with two digits per byte
+    /// followed by a sign nibble.
+    Packed,
+}
+
+/// Available positions for the sign of a signed DISPLAY numeric item.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SignPosition {
+    /// The sign is combined with the final digit.
+    #[default]
+    TrailingOverpunch,
+
+    /// The sign is combined with the first digit.
+    LeadingOverpunch,
+
+    /// The sign is stored as a separate '+' or '-' character after the digits.
+    TrailingSeparate,
+
+    /// The sign is stored as a separate '+' or '-' character before the digits.
+    LeadingSeparate,
+}
+
+impl SignPosition {
+    /// Returns whether the sign is stored as its own character.
+    pub fn is_separate(&self) -> bool {
+        matches!(
+            self,
+            SignPosition::TrailingSeparate | SignPosition::LeadingSeparate
+        )
+    }
 }
 
 impl Pic {
-    /// Returns the size of this data layout in COMP mode.
-    pub fn comp_size(&self) -> usize {
-        if self.is_str() {
+    /// Creates a new alphanumeric data layout of the given length, equivalent to `PIC X(len)`.
+    /// Used for treating group items as a single alphanumeric item.
+    pub fn alphanumeric(len: usize) -> Pic {
+        Pic {
+            layout_chunks: vec![PicLayoutChunk {
+                len,
+                chunk_type: PicChunkType::AlphaNumeric,
+            }],
+            byte_len: len,
+            usage: PicUsage::Display,
+            sign_pos: SignPosition::default(),
+            edit_mask: None,
+        }
+    }
+
+    /// Creates an unsigned DISPLAY numeric data layout of the given number of digits, equivalent
+    /// to `PIC 9(digits)`.
+    pub fn unsigned_numeric(digits: usize) -> Pic {
+        Pic {
+            layout_chunks: vec![PicLayoutChunk {
+                len: digits,
+                chunk_type: PicChunkType::Numeric,
+            }],
+            byte_len: digits,
+            usage: PicUsage::Display,
+            sign_pos: SignPosition::default(),
+            edit_mask: None,
+        }
+    }
+
+    /// Creates the data layout used for storing table index names, equivalent to `PIC 9(9) COMP`.
+    pub fn index() -> Pic {
+        Pic {
+            layout_chunks: vec![PicLayoutChunk {
+                len: 9,
+                chunk_type: PicChunkType::Numeric,
+            }],
+            byte_len: 9,
+            usage: PicUsage::Comp,
+            sign_pos: SignPosition::default(),
+            edit_mask: None,
+        }
+    }
+
+    /// Creates the data layout used for the "RETURN-CODE" special register, equivalent to
+    /// `PIC S9(4) COMP`.
+    pub fn return_code() -> Pic {
+        Pic {
+            layout_chunks: vec![
+                PicLayoutChunk {
+                    len: 1,
+                    chunk_type: PicChunkType::Sign,
+                },
+                PicLayoutChunk {
+                    len: 4,
+                    chunk_type: PicChunkType::Numeric,
+                },
+            ],
+            byte_len: 4,
+            usage: PicUsage::Comp,
+            sign_pos: SignPosition::default(),
+            edit_mask: None,
+        }
+    }
+
+    /// Returns this data layout with the given usage and sign position applied.
+    /// Separate signs occupy an additional byte of storage.
+    pub fn with_usage(mut self, usage: PicUsage, sign_pos: SignPosition) -> Pic {
+        self.usage = usage;
+        self.sign_pos = sign_pos;
+        if self.is_zoned() && self.is_signed() && sign_pos.is_separate() {
+            self.byte_len += 1;
+        }
+        self
+    }
+
+    /// Returns the size of this data layout in storage, in bytes.
+    pub fn storage_size(&self) -> usize {
+        if self.is_str() || self.usage == PicUsage::Display {
             return self.byte_len;
         }
-        if self.is_float() {
-            return types::F64.bytes().try_into().unwrap();
+        if self.usage == PicUsage::Packed {
+            return self.digits() / 2 + 1;
         }
+
+        // COMP values are stored as a single integer, scaled by the number of decimal places.
         types::I64.bytes().try_into().unwrap()
     }
 
     /// Verifies that the given literal fits within the data layout.
     pub fn verify_lit(&self, lits: &StrLitStore, lit: &Literal) -> bool {
         match lit {
-            Literal::Float(f) => self.is_float() && f.to_ne_bytes().len() <= self.comp_size(),
-            Literal::Int(i) => {
-                !self.is_str() && !self.is_float() && i.to_ne_bytes().len() <= self.comp_size()
+            // Numeric values are edited to fit numeric-edited layouts when moved into them.
+            Literal::Int(_) | Literal::Decimal(_) if self.is_edited() => true,
+            // Numerics are limited by their number of digits rather than their size.
+            Literal::Decimal(d) if !self.is_str() => {
+                self.is_float() && self.fits_digits(d.int_part().abs(), d.digits < 0)
             }
+            Literal::Int(i) if !self.is_str() => self.fits_digits(i.abs(), *i < 0),
+            Literal::Int(_) | Literal::Decimal(_) => false,
             Literal::String(sid) => {
-                self.is_str() && lits.get(*sid).unwrap().len() < self.comp_size()
+                self.is_str() && lits.get(*sid).unwrap().len() <= self.storage_size()
             }
+            // "ZERO" is numeric zero for numeric layouts, and fills all other layouts.
+            Literal::Figurative(Figurative::Zeros) if !self.is_str() || self.is_edited() => {
+                self.verify_lit(lits, &Literal::Int(0))
+            }
+            Literal::Figurative(_) => self.is_str(),
         }
     }
 
+    /// Returns whether the given integer part of a numeric value (and its sign) can be
+    /// represented by the digits of this data layout.
+    fn fits_digits(&self, int_part: i64, negative: bool) -> bool {
+        let int_digits = self.digits() as u32 - self.scale();
+        (!negative || self.is_signed())
+            && 10i64
+                .checked_pow(int_digits)
+                .is_none_or(|max| int_part < max)
+    }
+
     /// Whether this PIC layout would fit within the provided PIC layout.
-    /// Utilises the [`Self::comp_size()`] result to perform size checks.
-    pub fn fits_within_comp(&self, other: &Pic) -> bool {
-        // Check the base types match.
-        if self.is_str() && !other.is_str() || self.is_float() && !other.is_float() {
-            return false;
+    /// Utilises the [`Self::storage_size()`] result to perform size checks.
+    pub fn fits_within(&self, other: &Pic) -> bool {
+        // Numeric values are edited upon copy into numeric-edited layouts, so always fit.
+        if other.is_edited() && !self.is_str() {
+            return true;
+        }
+
+        // Strings can only be copied into other strings, and only DISPLAY numerics can be
+        // copied into strings, as their digits are already stored as characters.
+        // Only the digits of DISPLAY numerics are copied, without any sign.
+        if self.is_zoned() && other.is_str() {
+            return self.digits() <= other.storage_size();
+        }
+        if self.is_str() || other.is_str() {
+            return self.is_str() && other.is_str() && self.storage_size() <= other.storage_size();
         }
 
-        // Compare sizes.
-        self.comp_size() <= other.comp_size()
+        // Compare sizes. Decimal numerics are converted upon copy, so always fit.
+        // Any decimal places which do not fit within the destination are truncated.
+        self.is_decimal() || other.is_decimal() || self.storage_size() <= other.storage_size()
     }
 
-    /// Returns whether this data layout represents a string of some form, be that
-    /// alpha or alphanumeric.
-    pub fn is_str(&self) -> bool {
+    /// Returns whether this data layout represents a numeric value stored in DISPLAY
+    /// format, with a single digit per byte.
+    pub fn is_zoned(&self) -> bool {
+        !self.is_str() && self.usage == PicUsage::Display
+    }
+
+    /// Returns whether this data layout represents a numeric value stored in a decimal
+    /// format (DISPLAY or COMP-3), which must be converted upon load and store.
+    pub fn is_decimal(&self) -> bool {
+        !self.is_str() && self.usage != PicUsage::Comp
+    }
+
+    /// Returns whether this data layout contains a sign.
+    pub fn is_signed(&self) -> bool {
+        self.layout_chunks
+            .iter()
+            .any(|c| c.chunk_type == PicChunkType::Sign)
+    }
+
+    /// Returns the total number of digits within this data layout.
+    /// For numeric-edited layouts, this includes every digit position of the editing mask.
+    pub fn digits(&self) -> usize {
+        let count = |chunk_type: PicChunkType| -> usize {
+            self.layout_chunks
+                .iter()
+                .filter(|c| c.chunk_type == chunk_type)
+                .map(|c| c.len)
+                .sum()
+        };
+
+        // Floating insertion symbols hold a digit in every position but the first.
+        let floating: usize = [
+            PicChunkType::Currency,
+            PicChunkType::Plus,
+            PicChunkType::Minus,
+        ]
+        .into_iter()
+        .map(count)
+        .filter(|n| *n > 1)
+        .map(|n| n - 1)
+        .sum();
+        count(PicChunkType::Numeric)
+            + count(PicChunkType::ZeroSuppress)
+            + count(PicChunkType::CheckProtect)
+            + floating
+    }
+
+    /// Returns the number of digits following the decimal point within this data layout.
+    /// For numeric-edited layouts, this includes any zero suppressed digit positions.
+    pub fn scale(&self) -> u32 {
         self.layout_chunks
             .iter()
+            .skip_while(|c| {
+                c.chunk_type != PicChunkType::ImplicitDecimalPoint
+                    && c.chunk_type != PicChunkType::Period
+            })
             .filter(|c| {
-                c.chunk_type == PicChunkType::Alpha || c.chunk_type == PicChunkType::AlphaNumeric
+                c.chunk_type == PicChunkType::Numeric
+                    || c.chunk_type == PicChunkType::ZeroSuppress
+                    || c.chunk_type == PicChunkType::CheckProtect
             })
-            .count()
-            > 0
+            .map(|c| c.len as u32)
+            .sum()
+    }
+
+    /// Returns whether this data layout represents a string of only alphabetic characters.
+    pub fn is_alphabetic(&self) -> bool {
+        !self.is_edited()
+            && self
+                .layout_chunks
+                .iter()
+                .all(|c| c.chunk_type == PicChunkType::Alpha)
+    }
+
+    /// Returns whether this data layout represents a string of some form, be that
+    /// alpha, alphanumeric or numeric-edited.
+    pub fn is_str(&self) -> bool {
+        self.is_edited()
+            || self
+                .layout_chunks
+                .iter()
+                .filter(|c| {
+                    c.chunk_type == PicChunkType::Alpha
+                        || c.chunk_type == PicChunkType::AlphaNumeric
+                })
+                .count()
+                > 0
+    }
+
+    /// Returns whether this data layout is numeric-edited, storing numeric values as
+    /// formatted text.
+    pub fn is_edited(&self) -> bool {
+        self.edit_mask.is_some()
     }
 
-    /// Returns whether this data layout represents a float.
+    /// Returns whether this data layout represents a fixed point number with a decimal point.
     pub fn is_float(&self) -> bool {
         self.layout_chunks
             .iter()
-            .filter(|c| {
-                c.chunk_type == PicChunkType::DecimalPoint
-                    || c.chunk_type == PicChunkType::ImplicitDecimalPoint
-            })
-            .count()
-            > 0
+            .any(|c| c.chunk_type == PicChunkType::ImplicitDecimalPoint)
     }
 }
 
@@ -197,29 +962,78 @@ pub(crate) struct PicLayoutChunk {
 pub(crate) enum PicChunkType {
     Sign,
     ImplicitDecimalPoint,
-    DecimalPoint,
     Numeric,
     Alpha,
     AlphaNumeric,
+
+    // Editing symbols for numeric-edited layouts.
+    ZeroSuppress,
+    CheckProtect,
+    Currency,
+    Comma,
+    Period,
+    Plus,
+    Minus,
+    Credit,
+    Debit,
+    Space,
+    Zero,
+    Slash,
 }
 
 impl PicChunkType {
     /// Parses the relevant chunk type from a single character.
+    /// The two character editing symbols "CR" and "DB" are identified by their first character.
     pub fn from_char(parser: &mut Parser, c: char) -> Result<Self> {
         Ok(match c {
             '9' => PicChunkType::Numeric,
             'A' => PicChunkType::Alpha,
             'X' => PicChunkType::AlphaNumeric,
             'V' => PicChunkType::ImplicitDecimalPoint,
-            'P' => PicChunkType::DecimalPoint,
+            'P' => parser_bail!(
+                parser,
+                "Scaling positions ('P') are not supported within PIC clauses. Use 'V' for an implied decimal point."
+            ),
             'S' => PicChunkType::Sign,
+            'Z' => PicChunkType::ZeroSuppress,
+            '*' => PicChunkType::CheckProtect,
+            '$' => PicChunkType::Currency,
+            ',' => PicChunkType::Comma,
+            '.' => PicChunkType::Period,
+            '+' => PicChunkType::Plus,
+            '-' => PicChunkType::Minus,
+            'C' => PicChunkType::Credit,
+            'D' => PicChunkType::Debit,
+            'B' => PicChunkType::Space,
+            '0' => PicChunkType::Zero,
+            '/' => PicChunkType::Slash,
             c => parser_bail!(
                 parser,
-                "Invalid data type for chunk '{}'. Expected one of '9AXVSP'.",
+                "Invalid data type for chunk '{}'. Expected one of '9AXVSZ*$,.+-B0/', 'CR' or 'DB'.",
                 c
             ),
         })
     }
+
+    /// Returns the editing mask text for a single instance of this chunk type, if it is
+    /// an editing symbol.
+    pub fn edit_symbol(&self) -> Option<&'static str> {
+        Some(match self {
+            PicChunkType::ZeroSuppress => "Z",
+            PicChunkType::CheckProtect => "*",
+            PicChunkType::Currency => "$",
+            PicChunkType::Comma => ",",
+            PicChunkType::Period => ".",
+            PicChunkType::Plus => "+",
+            PicChunkType::Minus => "-",
+            PicChunkType::Credit => "CR",
+            PicChunkType::Debit => "DB",
+            PicChunkType::Space => "B",
+            PicChunkType::Zero => "0",
+            PicChunkType::Slash => "/",
+            _ => return None,
+        })
+    }
 }
 
 /// Mini-parser for interpreting PIC layout strings.
@@ -257,27 +1071,19 @@ impl<'src, 'prs> PicParser<'src, 'prs> {
         }
 
         // Calculate the total byte length of the combined chunks.
+        // Strings are stored at exactly their declared length, with no terminator. Unused
+        // trailing bytes are zeroed.
         let mut byte_len = 0;
-        let mut contains_alpha = false;
         for chunk in self.chunks.iter() {
             match chunk.chunk_type {
-                PicChunkType::DecimalPoint | PicChunkType::Numeric | PicChunkType::Sign => {
-                    byte_len += chunk.len;
-                }
-
-                PicChunkType::Alpha | PicChunkType::AlphaNumeric => {
-                    contains_alpha = true;
-                    byte_len += chunk.len;
-                }
-
                 // These types are implicit, and don't add any length.
-                PicChunkType::ImplicitDecimalPoint => {}
-            }
-        }
+                // Signs only take up space when stored separately, see [`Pic::with_usage()`].
+                PicChunkType::ImplicitDecimalPoint | PicChunkType::Sign => {}
 
-        // If the layout is a string, then we need an extra byte for a terminator.
-        if contains_alpha {
-            byte_len += 1;
+                // "CR" and "DB" are always two characters long.
+                PicChunkType::Credit | PicChunkType::Debit => byte_len += 2,
+                _ => byte_len += chunk.len,
+            }
         }
 
         // Check the byte length is valid.
@@ -291,12 +1097,35 @@ impl<'src, 'prs> PicParser<'src, 'prs> {
         // Sanity check the generated chunks.
         self.verify_chunks()?;
 
+        // Numeric-edited layouts store their editing mask for use when storing values.
+        let edit_mask = self.is_edited().then(|| {
+            let mut mask = String::new();
+            for chunk in self.chunks.iter() {
+                match chunk.chunk_type {
+                    PicChunkType::ImplicitDecimalPoint => mask.push('V'),
+                    PicChunkType::Numeric => mask.push_str(&"9".repeat(chunk.len)),
+                    ct => mask.push_str(&ct.edit_symbol().unwrap().repeat(chunk.len)),
+                }
+            }
+            self.parser.str_lits.insert(mask)
+        });
+
         Ok(Pic {
             byte_len,
             layout_chunks: self.chunks,
+            usage: PicUsage::Display,
+            sign_pos: SignPosition::default(),
+            edit_mask,
         })
     }
 
+    /// Returns whether the currently generated chunks contain any editing symbols.
+    fn is_edited(&self) -> bool {
+        self.chunks
+            .iter()
+            .any(|c| c.chunk_type.edit_symbol().is_some())
+    }
+
     /// Peeks the next character to parse.
     fn peek(&mut self) -> Option<char> {
         self.pic_str.chars().nth(self.cur_pos)
@@ -321,6 +1150,22 @@ impl<'src, 'prs> PicParser<'src, 'prs> {
         let next = self.next()?;
         let chunk_type = PicChunkType::from_char(self.parser, next)?;
 
+        // "CR" and "DB" are two character symbols, which cannot be repeated.
+        if matches!(chunk_type, PicChunkType::Credit | PicChunkType::Debit) {
+            let expected = if chunk_type == PicChunkType::Credit {
+                'R'
+            } else {
+                'B'
+            };
+            if self.next()? != expected {
+                parser_bail!(
+                    self.parser,
+                    "Invalid editing symbol in PIC clause, expected 'CR' or 'DB'."
+                );
+            }
+            return Ok(PicLayoutChunk { chunk_type, len: 1 });
+        }
+
         // Parse subsequent identical sections until we reach a new type.
         // We also parse any following defined lengths, e.g. A(4), and add those.
         let mut len = 1;
@@ -369,8 +1214,8 @@ impl<'src, 'prs> PicParser<'src, 'prs> {
             .chunks
             .iter()
             .filter(|c| {
-                c.chunk_type == PicChunkType::DecimalPoint
-                    || c.chunk_type == PicChunkType::ImplicitDecimalPoint
+                c.chunk_type == PicChunkType::ImplicitDecimalPoint
+                    || c.chunk_type == PicChunkType::Period
             })
             .count()
             > 1
@@ -388,8 +1233,8 @@ impl<'src, 'prs> PicParser<'src, 'prs> {
             .filter(|c| {
                 c.chunk_type == PicChunkType::Numeric
                     || c.chunk_type == PicChunkType::Sign
-                    || c.chunk_type == PicChunkType::DecimalPoint
                     || c.chunk_type == PicChunkType::ImplicitDecimalPoint
+                    || c.chunk_type.edit_symbol().is_some()
             })
             .count()
             > 0
@@ -409,6 +1254,19 @@ impl<'src, 'prs> PicParser<'src, 'prs> {
             );
         }
 
+        // Numeric-edited layouts carry their sign as editing symbols.
+        if self.is_edited()
+            && self
+                .chunks
+                .iter()
+                .any(|c| c.chunk_type == PicChunkType::Sign)
+        {
+            parser_bail!(
+                self.parser,
+                "Numeric-edited PIC clauses cannot contain 'S' terms."
+            );
+        }
+
         // Check individual chunk data.
         for (idx, chunk) in self.chunks.iter().enumerate() {
             match chunk.chunk_type {
@@ -426,7 +1284,7 @@ impl<'src, 'prs> PicParser<'src, 'prs> {
                         );
                     }
                 }
-                PicChunkType::DecimalPoint | PicChunkType::ImplicitDecimalPoint => {
+                PicChunkType::ImplicitDecimalPoint | PicChunkType::Period => {
                     if chunk.len > 1 {
                         parser_bail!(
                             self.parser,
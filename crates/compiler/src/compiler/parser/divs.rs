@@ -40,7 +40,19 @@ impl<'src> Parser<'src> {
 /// The procedure division of a single COBOL program.
 #[derive(Debug)]
 pub(crate) struct ProcDiv<'src> {
-    /// Paragraphs within the procedure division.
+    /// Sections within the procedure division.
+    /// Paragraphs before the first section header are contained within an anonymous section.
+    pub sections: Vec<Section<'src>>,
+}
+
+/// Represents a single section within a COBOL program, grouping a set of paragraphs.
+#[derive(Debug)]
+pub(crate) struct Section<'src> {
+    /// The name of this section.
+    /// If this is the anonymous section before any section header, the value is [`None`].
+    pub name: Option<Spanned<&'src str>>,
+
+    /// The paragraphs contained within the section, in order.
     pub paragraphs: Vec<Paragraph<'src>>,
 }
 
@@ -48,14 +60,18 @@ pub(crate) struct ProcDiv<'src> {
 #[derive(Debug)]
 pub(crate) struct Paragraph<'src> {
     /// The name of this paragraph.
-    /// If this is an anonymous entrypoint paragraph, the value is [`None`].
+    /// If this is an anonymous paragraph at the beginning of a section, the value is [`None`].
     pub name: Option<Spanned<&'src str>>,
 
-    /// The statements contained within the paragraph.
-    pub stats: Vec<Spanned<Stat<'src>>>,
+    /// The sentences contained within the paragraph.
+    pub sentences: Vec<Sentence<'src>>,
+}
 
-    /// Whether this paragraph terminates the program.
-    pub terminates: bool,
+/// Represents a single sentence within a paragraph, a set of statements ended by a period.
+#[derive(Debug)]
+pub(crate) struct Sentence<'src> {
+    /// The statements contained within the sentence.
+    pub stats: Vec<Spanned<Stat<'src>>>,
 }
 
 impl<'src> Parser<'src> {
@@ -64,10 +80,10 @@ impl<'src> Parser<'src> {
         // Parse header.
         self.consume_vec(&[tok![proc_div], tok![.], tok![eol]])?;
 
-        // Parse statements until we peek the end of the file.
-        let mut paragraphs: Vec<Paragraph<'src>> = Vec::new();
+        // Parse sections until we peek the end of the file.
+        let mut sections: Vec<Section<'src>> = Vec::new();
         while self.peek() != tok![eol] && self.peek() != tok![eof] {
-            paragraphs.push(self.paragraph()?);
+            sections.push(self.section()?);
         }
 
         // Consume the EOF.
@@ -78,7 +94,36 @@ impl<'src> Parser<'src> {
         }
         self.consume(tok![eof])?;
 
-        Ok(ProcDiv { paragraphs })
+        Ok(ProcDiv { sections })
+    }
+
+    /// Parses a single section from the current position.
+    /// If there is no section header, parses paragraphs up until the first section header.
+    fn section(&mut self) -> Result<Section<'src>> {
+        // If this section has a header, parse that out.
+        let name = if self.is_section_header() {
+            let name_tok = self.next()?;
+            self.consume_vec(&[tok![section], tok![.], tok![eol]])?;
+            Some((self.text(name_tok), name_tok.1))
+        } else {
+            None
+        };
+
+        // Keep parsing paragraphs until the next section. There must be at least 1 paragraph per section.
+        let mut paragraphs: Vec<Paragraph<'src>> = Vec::new();
+        loop {
+            paragraphs.push(self.paragraph()?);
+            if self.peek() == tok![eol] || self.peek() == tok![eof] || self.is_section_header() {
+                break;
+            }
+        }
+
+        Ok(Section { name, paragraphs })
+    }
+
+    /// Returns whether the parser is positioned at the header of a section.
+    fn is_section_header(&mut self) -> bool {
+        self.peek() == tok![ident] && self.peek_nth(1) == tok![section]
     }
 
     /// Parses a single paragraph from the current position.
@@ -92,41 +137,30 @@ impl<'src> Parser<'src> {
             None
         };
 
-        // Keep parsing statements until we hit the end. There must be at least 1 statement per paragraph.
-        let mut stats: Vec<Spanned<Stat<'src>>> = Vec::new();
+        // Keep parsing sentences until we hit the end. There must be at least 1 sentence per paragraph.
+        let mut sentences: Vec<Sentence<'src>> = Vec::new();
         loop {
-            stats.push(self.stat(true)?);
+            sentences.push(self.sentence()?);
 
-            // Consume any lines between statements, we don't care about those.
-            while self.peek() == tok![eol] {
-                self.next()?;
-            }
+            // Consume any lines between sentences, we don't care about those.
+            self.skip_eols()?;
 
             // Check if we have to stop.
-            if self.peek() == tok![stop_run]
-                || self.peek() == tok![ident]
-                || self.peek() == tok![eof]
-            {
+            if self.peek() == tok![ident] || self.peek() == tok![eof] {
                 break;
             }
         }
 
-        // If there's a STOP RUN statement, consume it.
-        let terminates = if self.peek() == tok![stop_run] {
-            self.consume_vec(&[tok![stop_run], tok![.]])?;
-            if self.peek() == tok![eol] {
-                self.next()?;
-            }
-            true
-        } else {
-            false
-        };
+        Ok(Paragraph { name, sentences })
+    }
 
-        Ok(Paragraph {
-            name,
-            stats,
-            terminates,
-        })
+    /// Parses a single sentence from the current position.
+    /// Sentences contain at least one statement, and end with a period.
+    fn sentence(&mut self) -> Result<Sentence<'src>> {
+        let mut stats = vec![self.stat()?];
+        stats.extend(self.stats()?);
+        self.consume(tok![.])?;
+        Ok(Sentence { stats })
     }
 }
 
@@ -1,14 +1,16 @@
+use std::fmt::Display;
+
 use miette::Result;
 
 use super::{
     token::{tok, Token},
-    Literal, Parser,
+    IntrinsicCall, Literal, Parser,
 };
 
 /// Represents a single stored or literal value expressed within the AST.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum Value<'src> {
-    Variable(&'src str),
+    Variable(DataRef<'src>),
     Literal(Literal),
 }
 
@@ -17,19 +19,206 @@ impl<'src> Value<'src> {
     pub(super) fn is_value(tok: Token) -> bool {
         match tok {
             tok![str_literal] | tok![int_lit] | tok![float_lit] | tok![ident] => true,
+            tok![space] | tok![zero] | tok![high_value] | tok![low_value] | tok![quote] => true,
+            tok![all] => true,
             _ => false,
         }
     }
 }
 
+/// Represents a single arithmetic expression, e.g. `(A + B) * C / 2 ** N`.
+#[derive(Debug)]
+pub(crate) enum Expr<'src> {
+    /// A single stored or literal value.
+    Value(Value<'src>),
+
+    /// The result of an intrinsic function call.
+    Intrinsic(IntrinsicCall<'src>),
+
+    /// The negation of an inner expression.
+    Neg(Box<Expr<'src>>),
+
+    /// A binary operation between two expressions.
+    BinOp(Box<Expr<'src>>, BinOp, Box<Expr<'src>>),
+}
+
+impl<'src> Expr<'src> {
+    /// Returns the single value this expression consists of, if it is not a compound expression.
+    pub fn as_value(&self) -> Option<&Value<'src>> {
+        match self {
+            Expr::Value(val) => Some(val),
+            _ => None,
+        }
+    }
+}
+
+/// All available binary operators within an arithmetic expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BinOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+}
+
+impl BinOp {
+    /// Returns the binary operator represented by the given token, along with its precedence.
+    /// Operators with a higher precedence bind more tightly.
+    fn from_token(tok: Token) -> Option<(BinOp, u8)> {
+        match tok {
+            tok![+] => Some((BinOp::Add, 0)),
+            tok![-] => Some((BinOp::Subtract, 0)),
+            tok![*] => Some((BinOp::Multiply, 1)),
+            tok![/] => Some((BinOp::Divide, 1)),
+            tok![**] => Some((BinOp::Power, 2)),
+            _ => None,
+        }
+    }
+}
+
+/// Represents a single reference to a stored data item.
+#[derive(Debug, Clone)]
+pub(crate) struct DataRef<'src> {
+    /// The name of the referenced data item.
+    pub name: &'src str,
+
+    /// The names of the groups qualifying this reference (`X OF Y IN Z`), from the innermost
+    /// group outwards. Empty when the reference is unqualified.
+    pub qualifiers: Vec<&'src str>,
+
+    /// The subscripts used to select a single table element, from the outermost table inwards.
+    /// Empty when the referenced item is not contained within a table.
+    pub subscripts: Vec<Value<'src>>,
+}
+
+impl Display for DataRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)?;
+        for qualifier in self.qualifiers.iter() {
+            write!(f, " OF {}", qualifier)?;
+        }
+        if !self.subscripts.is_empty() {
+            f.write_str("(")?;
+            for (idx, sub) in self.subscripts.iter().enumerate() {
+                if idx > 0 {
+                    f.write_str(", ")?;
+                }
+                match sub {
+                    Value::Variable(data_ref) => data_ref.fmt(f)?,
+                    Value::Literal(lit) => lit.fmt(f)?,
+                }
+            }
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
 impl<'src> Parser<'src> {
     /// Parses a single stored/literal value from the current position.
     pub(super) fn value(&mut self) -> Result<Value<'src>> {
         if self.peek() == tok![ident] {
-            let tok = self.next()?;
-            Ok(Value::Variable(self.text(tok)))
+            Ok(Value::Variable(self.data_ref()?))
         } else {
             Ok(Value::Literal(self.literal()?))
         }
     }
+
+    /// Parses a single arithmetic expression from the current position.
+    /// Unary signs bind most tightly, followed by exponentiation, multiplication and division,
+    /// then addition and subtraction. Operators of equal precedence are evaluated left to right.
+    /// An expression may continue onto the following line either before or after an operator.
+    pub(super) fn expr(&mut self) -> Result<Expr<'src>> {
+        self.expr_prec(0)
+    }
+
+    /// Parses an arithmetic expression containing only operators of at least the given precedence.
+    fn expr_prec(&mut self, min_prec: u8) -> Result<Expr<'src>> {
+        let mut left = self.expr_operand()?;
+        loop {
+            let offset = (self.peek() == tok![eol]) as usize;
+            let Some((op, prec)) = BinOp::from_token(self.peek_nth(offset)) else {
+                break;
+            };
+            if prec < min_prec {
+                break;
+            }
+            if offset > 0 {
+                self.next()?;
+            }
+            self.next()?;
+            let right = self.expr_prec(prec + 1)?;
+            left = Expr::BinOp(Box::new(left), op, Box::new(right));
+        }
+        Ok(left)
+    }
+
+    /// Parses a single operand of an arithmetic expression, including any unary signs.
+    fn expr_operand(&mut self) -> Result<Expr<'src>> {
+        if self.peek() == tok![eol] {
+            self.next()?;
+        }
+        match self.peek() {
+            tok![-] => {
+                self.next()?;
+                Ok(Expr::Neg(Box::new(self.expr_operand()?)))
+            }
+            tok![+] => {
+                self.next()?;
+                self.expr_operand()
+            }
+            tok![open_par] => {
+                self.next()?;
+                let inner = self.expr()?;
+                self.consume(tok![close_par])?;
+                Ok(inner)
+            }
+            tok![function] => {
+                self.next()?;
+                Ok(Expr::Intrinsic(self.intrinsic_call()?))
+            }
+            _ => Ok(Expr::Value(self.value()?)),
+        }
+    }
+
+    /// Parses a single reference to a data item from the current position, including any
+    /// qualifiers and subscripts, e.g. `WS-AMT OF WS-REC(I, 2)`.
+    pub(super) fn data_ref(&mut self) -> Result<DataRef<'src>> {
+        let name_tok = self.consume(tok![ident])?;
+        let name = self.text(name_tok);
+
+        // Parse out any qualifying group names.
+        let mut qualifiers: Vec<&'src str> = Vec::new();
+        while self.peek() == tok![of] || self.peek() == tok![in] {
+            self.next()?;
+            let qual_tok = self.consume(tok![ident])?;
+            qualifiers.push(self.text(qual_tok));
+        }
+
+        // Parse out a set of subscripts, if present.
+        // We need to be careful not to consume a substring span (e.g. `X(1:2)`) here.
+        let mut subscripts: Vec<Value<'src>> = Vec::new();
+        if self.peek() == tok![open_par] && self.peek_nth(2) != tok![:] {
+            self.next()?;
+            loop {
+                subscripts.push(self.value()?);
+
+                // Subscripts may be optionally separated by commas.
+                if self.peek() == tok![,] {
+                    self.next()?;
+                }
+                if self.peek() == tok![close_par] {
+                    break;
+                }
+            }
+            self.consume(tok![close_par])?;
+        }
+
+        Ok(DataRef {
+            name,
+            qualifiers,
+            subscripts,
+        })
+    }
 }
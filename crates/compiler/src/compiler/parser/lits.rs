@@ -1,4 +1,4 @@
-use std::{fmt::Display, hash::Hash};
+use std::fmt::Display;
 
 use bimap::BiMap;
 use miette::Result;
@@ -78,11 +78,12 @@ impl StrLitStore {
 }
 
 /// A single generic literal within a COBOL AST.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) enum Literal {
     String(StrLitId),
     Int(i64),
-    Float(f64),
+    Decimal(Decimal),
+    Figurative(Figurative),
 }
 
 impl Display for Literal {
@@ -90,59 +91,120 @@ impl Display for Literal {
         match self {
             Self::String(sid) => f.write_fmt(format_args!("str_{}", sid)),
             Self::Int(i) => f.write_fmt(format_args!("{}", i)),
-            Self::Float(fl) => f.write_fmt(format_args!("{}", fl)),
+            Self::Decimal(d) => f.write_fmt(format_args!("{}", d)),
+            Self::Figurative(fig) => f.write_fmt(format_args!("{}", fig)),
         }
     }
 }
 
-impl Hash for Literal {
-    /// Hashes the literal. Required for HashMap, etc.
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl Literal {
+    /// Extracts a text version of this literal, for display to users.
+    pub fn text(&self, str_lits: &StrLitStore) -> String {
         match self {
-            Literal::String(sid) => {
-                state.write_u8(1);
-                sid.hash(state);
-            }
-            Literal::Int(i) => {
-                state.write_u8(2);
-                i.hash(state);
-            }
-            Literal::Float(f) => {
-                state.write_u8(3);
-                // Yes, this is bad and jank.
-                // It doesn't really matter for us though, since we only use this hash for caching purposes,
-                // so all we're really leaking is another (very similar) entry in cache.
-                for b in f.to_ne_bytes() {
-                    state.write_u8(b);
-                }
+            Self::String(sid) => str_lits.get(*sid).unwrap().clone(),
+            Self::Int(i) => i.to_string(),
+            Self::Decimal(d) => d.to_string(),
+            Self::Figurative(Figurative::All(sid)) => {
+                format!("ALL \"{}\"", str_lits.get(*sid).unwrap())
             }
+            Self::Figurative(fig) => fig.to_string(),
         }
     }
 }
 
-impl Eq for Literal {}
+/// A figurative constant, which represents a single character (or string) repeated to fill
+/// the full length of the item it is used with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Figurative {
+    Spaces,
+    Zeros,
+    HighValues,
+    LowValues,
+    Quotes,
 
-impl PartialEq for Literal {
-    /// Returns whether this literal matches the other provided literal.
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Self::String(l0), Self::String(r0)) => l0 == r0,
-            (Self::Int(l0), Self::Int(r0)) => l0 == r0,
-            (Self::Float(l0), Self::Float(r0)) => l0 == r0,
-            _ => false,
+    /// The given string literal, repeated ("ALL literal").
+    All(StrLitId),
+}
+
+impl Display for Figurative {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spaces => f.write_str("SPACES"),
+            Self::Zeros => f.write_str("ZEROS"),
+            Self::HighValues => f.write_str("HIGH-VALUES"),
+            Self::LowValues => f.write_str("LOW-VALUES"),
+            Self::Quotes => f.write_str("QUOTES"),
+            Self::All(sid) => f.write_fmt(format_args!("ALL str_{}", sid)),
         }
     }
 }
 
-impl Literal {
-    /// Extracts a text version of this literal, for display to users.
-    pub fn text(&self, str_lits: &StrLitStore) -> String {
+impl Figurative {
+    /// Returns the bytes which are repeated to fill an item with this figurative constant.
+    pub fn pattern(&self, str_lits: &StrLitStore) -> Vec<u8> {
         match self {
-            Self::String(sid) => str_lits.get(*sid).unwrap().clone(),
-            Self::Int(i) => i.to_string(),
-            Self::Float(f) => f.to_string(),
+            Self::Spaces => vec![b' '],
+            Self::Zeros => vec![b'0'],
+            Self::HighValues => vec![0xFF],
+            Self::LowValues => vec![0x0],
+            Self::Quotes => vec![b'"'],
+            Self::All(sid) => str_lits.get(*sid).unwrap().clone().into_bytes(),
         }
     }
+
+    /// Returns the given number of bytes of this figurative constant, repeating the pattern
+    /// as many times as required (truncating the final repetition).
+    pub fn fill(&self, str_lits: &StrLitStore, len: usize) -> Vec<u8> {
+        self.pattern(str_lits)
+            .into_iter()
+            .cycle()
+            .take(len)
+            .collect()
+    }
+}
+
+/// An exact decimal number, stored as an integer scaled by a power of ten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Decimal {
+    /// The digits of the number, as an integer.
+    pub digits: i64,
+
+    /// The number of digits following the decimal point.
+    pub scale: u32,
+}
+
+impl Decimal {
+    /// Returns the digits of this number rescaled to the given number of decimal places.
+    /// Any excess decimal places are truncated.
+    pub fn rescale(&self, scale: u32) -> i64 {
+        if scale >= self.scale {
+            self.digits * 10i64.pow(scale - self.scale)
+        } else {
+            self.digits / 10i64.pow(self.scale - scale)
+        }
+    }
+
+    /// Returns the integer part of this number.
+    pub fn int_part(&self) -> i64 {
+        self.rescale(0)
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.scale == 0 {
+            return f.write_fmt(format_args!("{}", self.digits));
+        }
+        let divisor = 10u64.pow(self.scale);
+        let magnitude = self.digits.unsigned_abs();
+        f.write_fmt(format_args!(
+            "{}{}.{:0width$}",
+            if self.digits < 0 { "-" } else { "" },
+            magnitude / divisor,
+            magnitude % divisor,
+            width = self.scale as usize
+        ))
+    }
 }
 
 impl<'src> Parser<'src> {
@@ -160,8 +222,26 @@ impl<'src> Parser<'src> {
                 Ok(Literal::Int(int))
             }
             Token::FloatLiteral => {
-                let float = self.consume_float()?;
-                Ok(Literal::Float(float))
+                let decimal = self.consume_decimal()?;
+                Ok(Literal::Decimal(decimal))
+            }
+            tok![space] | tok![zero] | tok![high_value] | tok![low_value] | tok![quote] => {
+                Ok(Literal::Figurative(self.figurative()?))
+            }
+            tok![all] => {
+                // "ALL" with another figurative constant is equivalent to the constant alone.
+                self.next()?;
+                if self.peek() != tok![str_literal] {
+                    return Ok(Literal::Figurative(self.figurative()?));
+                }
+
+                // The repeated string is always written out in full, so need not be stored.
+                let text = self.consume_str()?;
+                if text.is_empty() {
+                    parser_bail!(self, "The literal following 'ALL' must not be empty.");
+                }
+                let id = self.str_lits.insert_transient(text);
+                Ok(Literal::Figurative(Figurative::All(id)))
             }
             _ => {
                 let next = self.next()?;
@@ -170,6 +250,23 @@ impl<'src> Parser<'src> {
         }
     }
 
+    /// Parses a single figurative constant (other than "ALL literal") from the current position.
+    fn figurative(&mut self) -> Result<Figurative> {
+        let next = self.next()?;
+        Ok(match next.0 {
+            tok![space] => Figurative::Spaces,
+            tok![zero] => Figurative::Zeros,
+            tok![high_value] => Figurative::HighValues,
+            tok![low_value] => Figurative::LowValues,
+            tok![quote] => Figurative::Quotes,
+            _ => parser_bail!(
+                self,
+                "Expected a figurative constant, instead found '{}'.",
+                next.0
+            ),
+        })
+    }
+
     /// Expects the next token to be a string literal, consumes it, and returns a parsed version
     /// of the string contained within. If the token is not a string, returns a parser error.
     pub fn consume_str(&mut self) -> Result<String> {
@@ -199,13 +296,19 @@ impl<'src> Parser<'src> {
             .ctx(self, format!("Failed to parse integer literal: {}", txt))
     }
 
-    /// Expects the next token to be an float literal, consumes it, and returns a
-    /// parsed version of the float contained within. Must fit within a [`f64`], otherwise
-    /// returns an error. Similarly errors on an invalid float value.
-    pub fn consume_float(&mut self) -> Result<f64> {
+    /// Expects the next token to be a decimal (float) literal, consumes it, and returns an
+    /// exact parsed version of the number contained within. All digits must fit within an [`i64`],
+    /// otherwise returns an error. Similarly errors on an invalid decimal value.
+    pub fn consume_decimal(&mut self) -> Result<Decimal> {
         let lit_tok = self.consume(tok![float_lit])?;
         let txt = self.text(lit_tok);
-        txt.parse::<f64>()
-            .ctx(self, format!("Failed to parse float literal: {}", txt))
+        let (int_txt, frac_txt) = txt.split_once('.').unwrap_or((txt, ""));
+        let digits = format!("{}{}", int_txt, frac_txt)
+            .parse::<i64>()
+            .ctx(self, format!("Failed to parse decimal literal: {}", txt))?;
+        Ok(Decimal {
+            digits,
+            scale: frac_txt.len() as u32,
+        })
     }
 }
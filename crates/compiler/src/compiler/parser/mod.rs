@@ -122,6 +122,21 @@ impl<'src> Parser<'src> {
         }
     }
 
+    /// Returns the token `n` tokens after the next peeked token, without moving the parser's cursor.
+    /// If there is no such token, returns EOF.
+    pub fn peek_nth(&mut self, n: usize) -> Token {
+        self.tokens.clone().nth(n).map(|tok| tok.0).unwrap_or(tok![eof])
+    }
+
+    /// Returns the text of the next peeked token, without moving the parser's cursor.
+    /// If there is no next token, returns an empty string.
+    pub fn peek_text(&mut self) -> &'src str {
+        match self.tokens.peek() {
+            Some(tok) => &self.input[tok.1.offset()..(tok.1.offset() + tok.1.len())],
+            None => "",
+        }
+    }
+
     /// Returns the next available token, if there is one.
     /// If there is no next token, returns a parser error.
     pub fn next(&mut self) -> Result<Spanned<Token>> {
@@ -1,6 +1,8 @@
 use miette::Result;
 
-use crate::compiler::parser::{parser_bail_spanned, token::tok, Parser, Spanned, Value};
+use crate::compiler::parser::{
+    parser_bail, parser_bail_spanned, token::tok, DataRef, Expr, Parser, Spanned, Value,
+};
 
 use super::Stat;
 
@@ -12,41 +14,94 @@ pub(crate) struct IfData<'src> {
     pub else_stats: Option<Vec<Spanned<Stat<'src>>>>,
 }
 
+/// Data required for a single "EVALUATE" statement.
+#[derive(Debug)]
+pub(crate) struct EvaluateData<'src> {
+    /// The subjects being evaluated, one for each "ALSO" separated subject.
+    pub subjects: Vec<EvalSubject<'src>>,
+
+    /// The "WHEN" branches of the statement, in the order they are checked.
+    pub whens: Vec<EvalWhen<'src>>,
+
+    /// The statements executed when no branch is selected, if "WHEN OTHER" is present.
+    pub other: Option<Vec<Spanned<Stat<'src>>>>,
+}
+
+/// A single subject of an "EVALUATE" statement.
+#[derive(Debug)]
+pub(crate) enum EvalSubject<'src> {
+    /// A "TRUE" or "FALSE" subject, selected by conditions with the same truth value.
+    Bool(bool),
+
+    /// An identifier, literal or arithmetic expression, selected by matching values.
+    Expr(Expr<'src>),
+}
+
+/// A single "WHEN" branch of an "EVALUATE" statement.
+#[derive(Debug)]
+pub(crate) struct EvalWhen<'src> {
+    /// The sets of objects selecting this branch, one for each consecutive "WHEN" phrase.
+    /// The branch is selected when each object within any one set matches its subject.
+    pub object_sets: Vec<Vec<EvalObject<'src>>>,
+
+    /// The statements executed when this branch is selected.
+    pub stats: Vec<Spanned<Stat<'src>>>,
+}
+
+/// A single object of a "WHEN" phrase, matched against the subject in the same position.
+#[derive(Debug)]
+pub(crate) enum EvalObject<'src> {
+    /// Matches any subject.
+    Any,
+
+    /// A condition, matching a "TRUE" or "FALSE" subject with the same truth value.
+    Cond(Cond<'src>),
+
+    /// A single value or "THRU" range, matching a subject equal to the value or within the range.
+    /// When negated, matches all other subjects.
+    Value {
+        negated: bool,
+        from: Expr<'src>,
+        thru: Option<Expr<'src>>,
+    },
+}
+
 /// A single generic condition within Cobalt.
+/// Either side of a comparison may be an arithmetic expression.
 #[derive(Debug)]
 pub(crate) enum Cond<'src> {
-    Eq(Value<'src>, Value<'src>),
-    Ge(Value<'src>, Value<'src>),
-    Le(Value<'src>, Value<'src>),
-    Gt(Value<'src>, Value<'src>),
-    Lt(Value<'src>, Value<'src>),
+    Eq(Expr<'src>, Expr<'src>),
+    Ge(Expr<'src>, Expr<'src>),
+    Le(Expr<'src>, Expr<'src>),
+    Gt(Expr<'src>, Expr<'src>),
+    Lt(Expr<'src>, Expr<'src>),
     And(Box<Cond<'src>>, Box<Cond<'src>>),
     Or(Box<Cond<'src>>, Box<Cond<'src>>),
     Not(Box<Cond<'src>>),
+
+    /// A level 88 condition name, holding when its item contains one of the condition's values.
+    Name(DataRef<'src>),
 }
 
 impl<'src> Parser<'src> {
     /// Parses a single "IF" statement from the current position.
+    /// The "THEN" keyword is optional, and the statement is ended either by "END-IF" or by the
+    /// period ending the sentence.
     pub(super) fn parse_if(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![if])?;
         let mut condition = self.parse_cond()?;
-        self.consume_vec(&[tok![then], tok![eol]])?;
+        if self.peek() == tok![then] {
+            self.next()?;
+        }
 
         // Iterate & parse out "IF" statement block.
-        let mut if_stats: Vec<Spanned<Stat<'src>>> = Vec::new();
-        while self.peek() != tok![end_if] && self.peek() != tok![else] {
-            if_stats.push(self.stat(false)?);
-        }
+        let if_stats = self.stats()?;
         let mut if_stats = (!if_stats.is_empty()).then_some(if_stats);
 
         // If there's an "ELSE" statement block, parse that out.
         let mut else_stats = if self.peek() == tok![else] {
-            self.consume_vec(&[tok![else], tok![eol]])?;
-            let mut stats: Vec<Spanned<Stat<'src>>> = Vec::new();
-            while self.peek() != tok![end_if] {
-                stats.push(self.stat(false)?);
-            }
-            Some(stats)
+            self.next()?;
+            Some(self.stats()?)
         } else {
             None
         };
@@ -58,7 +113,7 @@ impl<'src> Parser<'src> {
             condition = Cond::Not(Box::new(condition));
         }
 
-        self.consume(tok![end_if])?;
+        self.end_scope(tok![end_if])?;
 
         Ok(Stat::If(IfData {
             if_stats,
@@ -67,6 +122,110 @@ impl<'src> Parser<'src> {
         }))
     }
 
+    /// Parses a single "EVALUATE" statement from the current position.
+    /// Consecutive "WHEN" phrases without statements between them share the statements that follow.
+    pub(super) fn parse_evaluate(&mut self) -> Result<Stat<'src>> {
+        self.consume(tok![evaluate])?;
+
+        // Parse out all subjects.
+        let mut subjects = vec![self.parse_eval_subject()?];
+        while self.peek() == tok![also] {
+            self.next()?;
+            subjects.push(self.parse_eval_subject()?);
+        }
+        self.skip_eols()?;
+
+        // Parse out each branch, until we reach "WHEN OTHER" or the end of the statement.
+        let mut whens: Vec<EvalWhen<'src>> = Vec::new();
+        let mut other: Option<Vec<Spanned<Stat<'src>>>> = None;
+        while self.peek() == tok![when] {
+            if self.peek_nth(1) == tok![other] {
+                self.consume_vec(&[tok![when], tok![other]])?;
+                other = Some(self.stats()?);
+                break;
+            }
+
+            let mut object_sets: Vec<Vec<EvalObject<'src>>> = Vec::new();
+            while self.peek() == tok![when] && self.peek_nth(1) != tok![other] {
+                self.next()?;
+                object_sets.push(self.parse_eval_objects(&subjects)?);
+            }
+            whens.push(EvalWhen {
+                object_sets,
+                stats: self.stats()?,
+            });
+        }
+        if whens.is_empty() && other.is_none() {
+            parser_bail!(
+                self,
+                "EVALUATE statements must contain at least one 'WHEN' phrase."
+            );
+        }
+        self.end_scope(tok![end_evaluate])?;
+
+        Ok(Stat::Evaluate(EvaluateData {
+            subjects,
+            whens,
+            other,
+        }))
+    }
+
+    /// Parses a single subject of an "EVALUATE" statement from the current position.
+    fn parse_eval_subject(&mut self) -> Result<EvalSubject<'src>> {
+        match self.peek() {
+            tok![true] | tok![false] => Ok(EvalSubject::Bool(self.next()?.0 == tok![true])),
+            _ => Ok(EvalSubject::Expr(self.expr()?)),
+        }
+    }
+
+    /// Parses the "ALSO" separated objects of a single "WHEN" phrase from the current position,
+    /// one for each of the given subjects.
+    fn parse_eval_objects(
+        &mut self,
+        subjects: &[EvalSubject<'src>],
+    ) -> Result<Vec<EvalObject<'src>>> {
+        let mut objects: Vec<EvalObject<'src>> = Vec::new();
+        for (idx, subject) in subjects.iter().enumerate() {
+            if idx > 0 {
+                self.consume(tok![also])?;
+            }
+            if self.peek() == tok![any] {
+                self.next()?;
+                objects.push(EvalObject::Any);
+                continue;
+            }
+            objects.push(match subject {
+                EvalSubject::Bool(_) => EvalObject::Cond(self.parse_cond()?),
+                EvalSubject::Expr(_) => {
+                    let negated = self.peek() == tok![not];
+                    if negated {
+                        self.next()?;
+                    }
+                    let from = self.expr()?;
+                    let thru = (self.peek() == tok![thru])
+                        .then(|| {
+                            self.next()?;
+                            self.expr()
+                        })
+                        .transpose()?;
+                    EvalObject::Value {
+                        negated,
+                        from,
+                        thru,
+                    }
+                }
+            });
+        }
+        if self.peek() == tok![also] {
+            parser_bail!(
+                self,
+                "'WHEN' phrases must have exactly one object for each subject of the EVALUATE."
+            );
+        }
+        self.skip_eols()?;
+        Ok(objects)
+    }
+
     /// Parses a single condition from the current position.
     /// todo: Implement remaining conditional types.
     pub(super) fn parse_cond(&mut self) -> Result<Cond<'src>> {
@@ -76,12 +235,37 @@ impl<'src> Parser<'src> {
             return Ok(Cond::Not(Box::new(self.parse_cond()?)));
         }
 
-        // Parse out an initial value, operator & second value.
-        let first_op = self.value()?;
+        // Parse out an initial operand. If there's no following operator, this is a condition name.
+        let first_op = self.expr()?;
+        let is_operator = matches!(
+            self.peek(),
+            tok![=] | tok![<] | tok![>] | tok![<=] | tok![>=]
+        );
+        let mut cond =
+            if let (Expr::Value(Value::Variable(cond_name)), false) = (&first_op, is_operator) {
+                Cond::Name(cond_name.clone())
+            } else {
+                self.parse_cond_comp(first_op)?
+            };
+
+        // If there's a following condition, recursively parse that.
+        if self.peek() == tok![and] || self.peek() == tok![or] {
+            cond = match self.next()?.0 {
+                tok![and] => Cond::And(Box::new(cond), Box::new(self.parse_cond()?)),
+                tok![or] => Cond::Or(Box::new(cond), Box::new(self.parse_cond()?)),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(cond)
+    }
+
+    /// Parses the operator & second operand of a single comparison condition, given the first operand.
+    fn parse_cond_comp(&mut self, first_op: Expr<'src>) -> Result<Cond<'src>> {
         let operator = self.next()?;
-        let second_op = self.value()?;
+        let second_op = self.expr()?;
 
-        let mut cond = match operator.0 {
+        let cond = match operator.0 {
             tok![=] => Cond::Eq(first_op, second_op),
             tok![<] => Cond::Lt(first_op, second_op),
             tok![>] => Cond::Gt(first_op, second_op),
@@ -96,16 +280,6 @@ impl<'src> Parser<'src> {
                 );
             }
         };
-
-        // If there's a following condition, recursively parse that.
-        if self.peek() == tok![and] || self.peek() == tok![or] {
-            cond = match self.next()?.0 {
-                tok![and] => Cond::And(Box::new(cond), Box::new(self.parse_cond()?)),
-                tok![or] => Cond::Or(Box::new(cond), Box::new(self.parse_cond()?)),
-                _ => unreachable!(),
-            }
-        }
-
         Ok(cond)
     }
 }
@@ -1,42 +1,119 @@
-use crate::compiler::parser::{parser_bail, token::tok, Parser, Value};
+use crate::compiler::parser::{parser_bail, token::tok, DataRef, Literal, Parser, Spanned, Value};
 
 use super::{Cond, Stat};
 
 use miette::Result;
+use std::fmt::Display;
 
 /// Available variants for a single "PERFORM" instruction.
 #[derive(Debug)]
 pub(crate) enum PerformType<'src> {
-    Single(&'src str),
-    Thru(&'src str, &'src str),
+    Single(PerformTarget<'src>),
+    Thru(ProcRef<'src>, ProcRef<'src>),
     Until {
-        target: &'src str,
+        target: PerformTarget<'src>,
         cond: Cond<'src>,
         test_cond_before: bool,
     },
-    Times(&'src str, Value<'src>),
-    // Varying()
+    Times(PerformTarget<'src>, Value<'src>),
+    Varying {
+        target: PerformTarget<'src>,
+        loops: Vec<VaryingLoop<'src>>,
+        test_cond_before: bool,
+    },
+}
+
+/// The code executed by a single "PERFORM" instruction.
+#[derive(Debug)]
+pub(crate) enum PerformTarget<'src> {
+    /// A named paragraph or section, executed out of line.
+    Paragraph(ProcRef<'src>),
+
+    /// A block of statements terminated by "END-PERFORM", executed in line.
+    Inline(Vec<Spanned<Stat<'src>>>),
+}
+
+/// A single loop of a "PERFORM VARYING" statement, from either the "VARYING" phrase or one of the
+/// following "AFTER" phrases. Each loop is run to completion for every step of the loop before it.
+#[derive(Debug)]
+pub(crate) struct VaryingLoop<'src> {
+    /// The item varied by this loop.
+    pub var: DataRef<'src>,
+
+    /// The initial value of the varied item.
+    pub from: Value<'src>,
+
+    /// The amount the varied item is increased by on each step of the loop.
+    pub by: Value<'src>,
+
+    /// The condition ending this loop.
+    pub until: Cond<'src>,
+}
+
+/// A reference to a single paragraph or section within the procedure division.
+#[derive(Debug)]
+pub(crate) struct ProcRef<'src> {
+    /// The name of the referenced paragraph or section.
+    pub name: &'src str,
+
+    /// The section containing the referenced paragraph, if qualified with "OF" or "IN".
+    pub section: Option<&'src str>,
+}
+
+impl Display for ProcRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)?;
+        if let Some(section) = self.section {
+            write!(f, " OF {}", section)?;
+        }
+        Ok(())
+    }
 }
 
 impl<'src> Parser<'src> {
+    /// Parses a single reference to a paragraph or section from the current position,
+    /// in the form: `name [{OF|IN} section]`.
+    fn proc_ref(&mut self) -> Result<ProcRef<'src>> {
+        let name_tok = self.consume(tok![ident])?;
+        let section = if self.peek() == tok![of] || self.peek() == tok![in] {
+            self.next()?;
+            let section_tok = self.consume(tok![ident])?;
+            Some(self.text(section_tok))
+        } else {
+            None
+        };
+        Ok(ProcRef {
+            name: self.text(name_tok),
+            section,
+        })
+    }
+
     /// Parses a single "PERFORM" statement from the current position.
+    /// When no paragraph is named, the statements to perform follow the statement's phrases
+    /// in line, terminated by "END-PERFORM".
     pub(super) fn parse_perform(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![perform])?;
-        let first_para_tok = self.consume(tok![ident])?;
-        let first_para_txt = self.text(first_para_tok);
+        let first_para = if self.peek() == tok![ident] && self.peek_nth(1) != tok![times] {
+            Some(self.proc_ref()?)
+        } else {
+            None
+        };
         let perform = match self.peek() {
-            // PERFORM X
-            tok![.] | tok![eol] => PerformType::Single(first_para_txt),
-
             // PERFORM X THRU Y
             tok![thru] => {
+                let Some(first_para) = first_para else {
+                    parser_bail!(
+                        self,
+                        "Inline PERFORM statements cannot have a 'THRU' phrase."
+                    );
+                };
                 self.next()?;
-                let end_para_tok = self.consume(tok![ident])?;
-                PerformType::Thru(first_para_txt, self.text(end_para_tok))
+                PerformType::Thru(first_para, self.proc_ref()?)
             }
 
             // PERFORM X UNTIL Y=Z
-            tok![test_before] | tok![test_after] | tok![until] => {
+            // PERFORM X VARYING I FROM Y BY Z UNTIL I>N
+            tok![test_before] | tok![test_after] | tok![until] | tok![varying] => {
                 // Determine which side the check is on.
                 let test_cond_before = match self.peek() {
                     tok![test_after] => {
@@ -50,11 +127,20 @@ impl<'src> Parser<'src> {
                     _ => true,
                 };
 
+                if self.peek() == tok![varying] {
+                    let loops = self.parse_varying_loops()?;
+                    return Ok(Stat::Perform(PerformType::Varying {
+                        target: self.parse_perform_target(first_para)?,
+                        loops,
+                        test_cond_before,
+                    }));
+                }
+
                 self.consume(tok![until])?;
                 let cond = self.parse_cond()?;
 
                 PerformType::Until {
-                    target: first_para_txt,
+                    target: self.parse_perform_target(first_para)?,
                     cond,
                     test_cond_before,
                 }
@@ -64,24 +150,155 @@ impl<'src> Parser<'src> {
             tok![ident] | tok![int_lit] => {
                 let val = self.value()?;
                 self.consume(tok![times])?;
-                PerformType::Times(first_para_txt, val)
+                PerformType::Times(self.parse_perform_target(first_para)?, val)
             }
 
-            tok => {
+            // PERFORM X
+            _ => PerformType::Single(self.parse_perform_target(first_para)?),
+        };
+
+        Ok(Stat::Perform(perform))
+    }
+
+    /// Parses the target of a "PERFORM" statement, given the paragraph named by the statement.
+    /// If no paragraph was named, parses the statements to perform in line from the current position.
+    fn parse_perform_target(&mut self, para: Option<ProcRef<'src>>) -> Result<PerformTarget<'src>> {
+        if let Some(para) = para {
+            return Ok(PerformTarget::Paragraph(para));
+        }
+        let stats = self.stats()?;
+        self.consume(tok![end_perform])?;
+        Ok(PerformTarget::Inline(stats))
+    }
+
+    /// Parses the "VARYING" phrase of a "PERFORM" statement from the current position, followed
+    /// by any "AFTER" phrases, each of which may begin on a new line.
+    fn parse_varying_loops(&mut self) -> Result<Vec<VaryingLoop<'src>>> {
+        self.consume(tok![varying])?;
+        let mut loops = vec![self.parse_varying_loop()?];
+        loop {
+            let offset = (self.peek() == tok![eol]) as usize;
+            if self.peek_nth(offset) != tok![after] {
+                break;
+            }
+            if offset > 0 {
+                self.next()?;
+            }
+            self.next()?;
+            loops.push(self.parse_varying_loop()?);
+        }
+        Ok(loops)
+    }
+
+    /// Parses a single loop of a "PERFORM VARYING" statement from the current position, in the form:
+    /// `data-ref FROM value BY value UNTIL cond`.
+    fn parse_varying_loop(&mut self) -> Result<VaryingLoop<'src>> {
+        let var = self.data_ref()?;
+        self.consume(tok![from])?;
+        let from = self.value()?;
+        self.consume(tok![by])?;
+        let by = self.value()?;
+        self.consume(tok![until])?;
+        let until = self.parse_cond()?;
+        Ok(VaryingLoop {
+            var,
+            from,
+            by,
+            until,
+        })
+    }
+}
+
+/// Data for a single "GO TO" instruction.
+#[derive(Debug)]
+pub(crate) struct GoToData<'src> {
+    /// The paragraphs or sections control may be transferred to, in order.
+    pub targets: Vec<ProcRef<'src>>,
+
+    /// The item selecting which of the paragraphs to transfer control to, from 1 onwards.
+    /// When the value is out of range, execution continues with the next statement.
+    pub depending_on: Option<DataRef<'src>>,
+}
+
+impl<'src> Parser<'src> {
+    /// Parses a single "GO TO" statement from the current position, in the form:
+    /// `GO [TO] para {para} [DEPENDING [ON] data-ref]`.
+    pub(super) fn parse_go_to(&mut self) -> Result<Stat<'src>> {
+        self.consume(tok![go])?;
+        if self.peek() == tok![to] {
+            self.next()?;
+        }
+        let mut targets = Vec::new();
+        loop {
+            targets.push(self.proc_ref()?);
+            if self.peek() != tok![ident] {
+                break;
+            }
+        }
+
+        let depending_on = if self.peek() == tok![depending] {
+            self.next()?;
+            if self.peek() == tok![on] {
                 self.next()?;
-                parser_bail!(self, "Unknown token in PERFORM statement '{}'.", tok);
             }
+            Some(self.data_ref()?)
+        } else {
+            None
         };
+        if targets.len() > 1 && depending_on.is_none() {
+            parser_bail!(
+                self,
+                "GO TO statements with multiple paragraphs must have a 'DEPENDING ON' phrase."
+            );
+        }
 
-        Ok(Stat::Perform(perform))
+        Ok(Stat::GoTo(GoToData {
+            targets,
+            depending_on,
+        }))
+    }
+}
+
+impl<'src> Parser<'src> {
+    /// Parses a single "STOP RUN" statement from the current position, returning the exit status
+    /// given by either a "RETURNING" or "WITH ERROR STATUS" phrase, if present.
+    /// When "WITH ERROR STATUS" is given without a value, the exit status is 1.
+    pub(super) fn parse_stop_run(&mut self) -> Result<Stat<'src>> {
+        self.consume(tok![stop_run])?;
+        let status = match self.peek() {
+            tok![returning] => {
+                self.next()?;
+                Some(self.value()?)
+            }
+            tok![with_error_status] => {
+                self.next()?;
+                if Value::is_value(self.peek()) {
+                    Some(self.value()?)
+                } else {
+                    Some(Value::Literal(Literal::Int(1)))
+                }
+            }
+            _ => None,
+        };
+        Ok(Stat::StopRun(status))
     }
 }
 
 /// Available variants of the "EXIT" instruction.
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub(crate) enum ExitType {
     Paragraph,
-    // Section,
+    Section,
+    /// Leaves the innermost inline "PERFORM" statement, with "EXIT PERFORM".
+    Perform,
+
+    /// Ends the current iteration of the innermost inline "PERFORM" statement,
+    /// with "EXIT PERFORM CYCLE".
+    PerformCycle,
+
+    /// Returns from the program, with "EXIT PROGRAM".
+    /// As all programs are run as the main program, execution continues with the next statement.
+    Program,
 }
 
 impl<'src> Parser<'src> {
@@ -90,10 +307,17 @@ impl<'src> Parser<'src> {
         self.consume(tok![exit])?;
         let exit_type = match self.peek() {
             tok![paragraph] => ExitType::Paragraph,
+            tok![section] => ExitType::Section,
+            tok![program] => ExitType::Program,
+            tok![perform] if self.peek_nth(1) == tok![cycle] => {
+                self.next()?;
+                ExitType::PerformCycle
+            }
+            tok![perform] => ExitType::Perform,
             tok => {
                 parser_bail!(
                     self,
-                    "Expected one of: PARAGRAPH for exit statement, instead found {}.",
+                    "Expected one of: PARAGRAPH, SECTION, PERFORM, PROGRAM for exit statement, instead found {}.",
                     tok
                 );
             }
@@ -14,7 +14,7 @@ pub(crate) struct IntrinsicCall<'src> {
 
 impl<'src> Parser<'src> {
     /// Parses a single intrinsic call from the current position.
-    pub(super) fn intrinsic_call(&mut self) -> Result<IntrinsicCall<'src>> {
+    pub fn intrinsic_call(&mut self) -> Result<IntrinsicCall<'src>> {
         let name_tok = self.consume(tok![ident])?;
         let name = self.text(name_tok);
 
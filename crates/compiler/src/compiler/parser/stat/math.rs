@@ -3,7 +3,7 @@ use miette::Result;
 use crate::compiler::parser::{
     parser_bail,
     token::{tok, Token},
-    Parser, Value,
+    DataRef, Expr, Parser, Spanned, Value,
 };
 
 use super::Stat;
@@ -15,17 +15,74 @@ pub(crate) struct BasicMathOpData<'src> {
     pub sources: Vec<Value<'src>>,
 
     /// The destinations of the arithmetic instruction.
-    pub dests: Vec<&'src str>,
+    pub dests: Vec<ArithDest<'src>>,
 
     /// Whether to overwrite the destination value, instead of including it with the sources.
     pub overwrite_dests: bool,
+
+    /// Whether the operation is between the corresponding subordinate items of a single
+    /// source group and a single destination group.
+    pub corresponding: bool,
+
+    /// The size error phrases of the arithmetic instruction.
+    pub size_error: SizeErrorPhrases<'src>,
+}
+
+/// A single receiving item of an arithmetic instruction.
+#[derive(Debug, Clone)]
+pub(crate) struct ArithDest<'src> {
+    /// The variable that the result is stored in.
+    pub sym: DataRef<'src>,
+
+    /// The rounding applied to the result when stored, if "ROUNDED" was specified.
+    /// Without rounding, excess decimal places are truncated.
+    pub rounding: Option<RoundingMode>,
+}
+
+/// The rounding modes available for a "ROUNDED" receiving item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingMode {
+    /// Rounds to the nearest value, with halfway values rounded away from zero.
+    /// This is the default mode for "ROUNDED".
+    NearestAwayFromZero,
+
+    /// Rounds to the nearest value, with halfway values rounded to the nearest even value.
+    NearestEven,
+
+    /// Rounds any inexact value away from zero.
+    AwayFromZero,
+
+    /// Truncates any excess decimal places.
+    Truncation,
+}
+
+/// The optional "ON SIZE ERROR" and "NOT ON SIZE ERROR" phrases of an arithmetic instruction.
+#[derive(Debug, Default)]
+pub(crate) struct SizeErrorPhrases<'src> {
+    /// Statements to execute if a result does not fit within its destination.
+    pub on_error: Option<Vec<Spanned<Stat<'src>>>>,
+
+    /// Statements to execute if all results fit within their destinations.
+    pub not_on_error: Option<Vec<Spanned<Stat<'src>>>>,
+}
+
+impl<'src> SizeErrorPhrases<'src> {
+    /// Returns whether any size error phrase is present.
+    /// When present, destinations are left unchanged if their result does not fit.
+    pub fn is_present(&self) -> bool {
+        self.on_error.is_some() || self.not_on_error.is_some()
+    }
 }
 
 impl<'src> Parser<'src> {
     /// Parses a single "ADD" statement from the current position.
     pub(super) fn parse_add(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![add])?;
-        let op_data = self.parse_math_op(tok![to])?;
+        let op_data = if self.parse_corresponding()? {
+            self.parse_corresponding_op(tok![to], tok![end_add])?
+        } else {
+            self.parse_math_op(tok![to], true, tok![end_add])?
+        };
 
         Ok(Stat::Add(op_data))
     }
@@ -33,7 +90,11 @@ impl<'src> Parser<'src> {
     /// Parses a single "SUBTRACT" statement from the current position.
     pub(super) fn parse_subtract(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![subtract])?;
-        let op_data = self.parse_math_op(tok![from])?;
+        let op_data = if self.parse_corresponding()? {
+            self.parse_corresponding_op(tok![from], tok![end_subtract])?
+        } else {
+            self.parse_math_op(tok![from], false, tok![end_subtract])?
+        };
 
         Ok(Stat::Subtract(op_data))
     }
@@ -41,16 +102,31 @@ impl<'src> Parser<'src> {
     /// Parses a single "MULTIPLY" statement from the current position.
     pub(super) fn parse_multiply(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![multiply])?;
-        let op_data = self.parse_math_op(tok![by])?;
+        let op_data = self.parse_math_op(tok![by], false, tok![end_multiply])?;
+
+        // Each destination (or the single operand before "GIVING") is multiplied by a single value.
+        let expected_sources = if op_data.overwrite_dests { 2 } else { 1 };
+        if op_data.sources.len() != expected_sources {
+            parser_bail!(
+                self,
+                "MULTIPLY statements may only have a single value before 'BY'."
+            );
+        }
 
         Ok(Stat::Multiply(op_data))
     }
 
     /// Parses data for a single mathematical operation (ADD, SUB, MUL) from the current location.
-    /// Takes a separator which denotes the end of the source arguments and beginning of destination arguments.
-    fn parse_math_op(&mut self, sep: Token) -> Result<BasicMathOpData<'src>> {
+    /// Takes a separator which denotes the end of the source arguments and beginning of destination arguments,
+    /// whether that separator may be omitted before a "GIVING" clause, as well as the explicit scope
+    /// terminator for the statement.
+    fn parse_math_op(
+        &mut self,
+        sep: Token,
+        sep_optional: bool,
+        end: Token,
+    ) -> Result<BasicMathOpData<'src>> {
         let mut sources: Vec<Value<'src>> = Vec::new();
-        let mut dests: Vec<&'src str> = Vec::new();
 
         // Grab all source values (there must be at least one).
         loop {
@@ -59,65 +135,236 @@ impl<'src> Parser<'src> {
                 break;
             }
         }
+
+        // If the separator is omitted, all values so far are sources of a "GIVING" clause.
+        if sep_optional && self.peek() == tok![giving] {
+            self.next()?;
+            return Ok(BasicMathOpData {
+                sources,
+                dests: self.arith_dests()?,
+                overwrite_dests: true,
+                corresponding: false,
+                size_error: self.parse_size_error(end)?,
+            });
+        }
         self.consume(sep)?;
 
         // Get a single destination value.
         // We have to get one first, since there might be a "GIVING" clause, which would
         // actually make this "destination" a source value for the add... thanks COBOL.
-        let first_dest_tok = self.consume(tok![ident])?;
-        let first_dest = self.text(first_dest_tok);
+        let first_dest = self.value()?;
+        let first_rounding = self.rounding()?;
 
         // If there is a "GIVING" clause, get that here.
         let mut overwrite_dests = false;
-        if self.peek() == tok![giving] {
+        let dests = if self.peek() == tok![giving] {
+            if first_rounding.is_some() {
+                parser_bail!(
+                    self,
+                    "Only the receiving items of a 'GIVING' clause may be rounded."
+                );
+            }
             self.next()?;
             overwrite_dests = true;
 
             // The first "destination" is actually a source.
-            sources.push(Value::Variable(first_dest));
+            sources.push(first_dest);
 
-            // Fetch the single *actual* destination.
-            let dest_tok = self.consume(tok![ident])?;
-            dests.push(self.text(dest_tok));
+            // Fetch the *actual* destinations.
+            self.arith_dests()?
         } else {
             // No "GIVING" clause, simply iterate destinations until the end.
-            dests.push(first_dest);
+            let Value::Variable(sym) = first_dest else {
+                parser_bail!(
+                    self,
+                    "Arithmetic statements without a 'GIVING' clause must store their result in variables."
+                );
+            };
+            let mut dests = vec![ArithDest {
+                sym,
+                rounding: first_rounding,
+            }];
             while self.peek() == tok![ident] {
-                let dest_tok = self.consume(tok![ident])?;
-                dests.push(self.text(dest_tok));
+                dests.push(self.arith_dest()?);
             }
-        }
+            dests
+        };
 
         Ok(BasicMathOpData {
             sources,
             dests,
             overwrite_dests,
+            corresponding: false,
+            size_error: self.parse_size_error(end)?,
+        })
+    }
+
+    /// Parses data for a single corresponding mathematical operation (ADD, SUB) from the current
+    /// location, following the "CORRESPONDING" phrase. Takes the separator between the source and
+    /// destination groups, as well as the explicit scope terminator for the statement.
+    fn parse_corresponding_op(&mut self, sep: Token, end: Token) -> Result<BasicMathOpData<'src>> {
+        let source = Value::Variable(self.data_ref()?);
+        self.consume(sep)?;
+        Ok(BasicMathOpData {
+            sources: vec![source],
+            dests: vec![self.arith_dest()?],
+            overwrite_dests: false,
+            corresponding: true,
+            size_error: self.parse_size_error(end)?,
         })
     }
+
+    /// Parses a list of one or more receiving items of an arithmetic statement.
+    fn arith_dests(&mut self) -> Result<Vec<ArithDest<'src>>> {
+        let mut dests: Vec<ArithDest<'src>> = Vec::new();
+        loop {
+            dests.push(self.arith_dest()?);
+            if self.peek() != tok![ident] {
+                break;
+            }
+        }
+        Ok(dests)
+    }
+
+    /// Parses a single receiving item of an arithmetic statement, in the form:
+    /// `data-ref [ROUNDED [MODE [IS] mode]]`.
+    fn arith_dest(&mut self) -> Result<ArithDest<'src>> {
+        Ok(ArithDest {
+            sym: self.data_ref()?,
+            rounding: self.rounding()?,
+        })
+    }
+
+    /// Parses an optional "ROUNDED" phrase, in the form: `[ROUNDED [MODE [IS] mode]]`.
+    fn rounding(&mut self) -> Result<Option<RoundingMode>> {
+        if self.peek() != tok![rounded] {
+            return Ok(None);
+        }
+        self.next()?;
+
+        // Without an explicit mode, results are rounded to the nearest value, away from zero.
+        let mut rounding = RoundingMode::NearestAwayFromZero;
+        if self.peek() == tok![mode] {
+            self.next()?;
+            if self.peek() == tok![is] {
+                self.next()?;
+            }
+            rounding = match self.next()?.0 {
+                tok![nearest_away_from_zero] => RoundingMode::NearestAwayFromZero,
+                tok![nearest_even] => RoundingMode::NearestEven,
+                tok![away_from_zero] => RoundingMode::AwayFromZero,
+                tok![truncation] => RoundingMode::Truncation,
+                err_tok => {
+                    parser_bail!(self, "Expected a rounding mode, instead found '{err_tok}'.")
+                }
+            };
+        }
+        Ok(Some(rounding))
+    }
+
+    /// Parses the optional size error phrases of an arithmetic statement, in the form:
+    /// `[[ON] SIZE ERROR stats] [NOT [ON] SIZE ERROR stats] [end]`.
+    /// The phrases and terminator may begin on the line following the statement.
+    fn parse_size_error(&mut self, end: Token) -> Result<SizeErrorPhrases<'src>> {
+        let mut phrases = SizeErrorPhrases::default();
+        let offset = (self.peek() == tok![eol]) as usize;
+        if !self.is_size_error_phrase(offset) && self.peek_nth(offset) != end {
+            return Ok(phrases);
+        }
+        if offset > 0 {
+            self.next()?;
+        }
+
+        if self.peek() != tok![not] && self.is_size_error_phrase(0) {
+            self.parse_size_error_phrase()?;
+            phrases.on_error = Some(self.stats()?);
+        }
+        if self.peek() == tok![not] {
+            self.next()?;
+            self.parse_size_error_phrase()?;
+            phrases.not_on_error = Some(self.stats()?);
+        }
+        self.end_scope(end)?;
+        Ok(phrases)
+    }
+
+    /// Returns whether a size error phrase begins at the given token offset from the current position.
+    fn is_size_error_phrase(&mut self, offset: usize) -> bool {
+        let offset = offset + (self.peek_nth(offset) == tok![not]) as usize;
+        let offset = offset + (self.peek_nth(offset) == tok![on]) as usize;
+        self.peek_nth(offset) == tok![size_error]
+    }
+
+    /// Parses the `[ON] SIZE ERROR` keywords of a single size error phrase.
+    fn parse_size_error_phrase(&mut self) -> Result<()> {
+        if self.peek() == tok![on] {
+            self.next()?;
+        }
+        self.consume(tok![size_error])?;
+        Ok(())
+    }
+}
+
+/// Data for a single "COMPUTE" instruction, storing the result of an expression in a set of destinations.
+#[derive(Debug)]
+pub(crate) struct ComputeData<'src> {
+    /// The destinations of the computed result.
+    pub dests: Vec<ArithDest<'src>>,
+
+    /// The expression to compute.
+    pub expr: Expr<'src>,
+
+    /// The size error phrases of the computation.
+    pub size_error: SizeErrorPhrases<'src>,
+}
+
+impl<'src> Parser<'src> {
+    /// Parses a single "COMPUTE" statement from the current position.
+    pub(super) fn parse_compute(&mut self) -> Result<Stat<'src>> {
+        self.consume(tok![compute])?;
+
+        // Fetch all destinations (there must be at least one), then the expression.
+        let dests = self.arith_dests()?;
+        self.consume(tok![=])?;
+        let expr = self.expr()?;
+
+        Ok(Stat::Compute(ComputeData {
+            dests,
+            expr,
+            size_error: self.parse_size_error(tok![end_compute])?,
+        }))
+    }
 }
 
 /// Operand data for a single DIVIDE instruction.
 #[derive(Debug)]
 pub(crate) struct DivideData<'src> {
-    /// The variable being divided.
-    pub dividend: &'src str,
+    /// The value being divided.
+    /// When not present, each destination is divided by the divisor in place.
+    pub dividend: Option<Value<'src>>,
 
-    /// The variable used as the divisor.
-    pub divisor: &'src str,
+    /// The value used as the divisor.
+    pub divisor: Value<'src>,
 
-    /// The variable that output is placed in.
-    /// May be the same as the divisor.
-    pub out_var: &'src str,
+    /// The variables that the quotient is placed in.
+    pub dests: Vec<ArithDest<'src>>,
+
+    /// The variable that the remainder is placed in, if any.
+    /// Only valid with a single destination.
+    pub remainder: Option<DataRef<'src>>,
+
+    /// The size error phrases of the division.
+    pub size_error: SizeErrorPhrases<'src>,
 }
 
 impl<'src> Parser<'src> {
     /// Parses a single "DIVIDE" statement from the current position.
     pub(super) fn parse_divide(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![divide])?;
-        let first_tok = self.consume(tok![ident])?;
+        let first = self.value()?;
 
-        // Determine whether we're dividing the first variable by the second, or
-        // the second variable by the first.
+        // Determine whether we're dividing the first value by the second, or
+        // the second value by the first.
         let left_to_right = if self.peek() == tok![by] {
             self.next()?;
             true
@@ -132,15 +379,57 @@ impl<'src> Parser<'src> {
             );
         };
 
-        let second_tok = self.consume(tok![ident])?;
+        let second = self.value()?;
+        let second_rounding = self.rounding()?;
+
+        // Determine which value is the dividend, and which is the divisor.
+        let (dividend, divisor) = if left_to_right {
+            (first, second)
+        } else {
+            (second, first)
+        };
 
         // If we're using "BY", there must be a "GIVING" clause.
-        let out_var = if self.peek() == tok![giving] {
+        let mut dests: Vec<ArithDest<'src>> = Vec::new();
+        let mut remainder = None;
+        let dividend = if self.peek() == tok![giving] {
+            if second_rounding.is_some() {
+                parser_bail!(
+                    self,
+                    "Only the receiving items of a 'GIVING' clause may be rounded."
+                );
+            }
             self.next()?;
-            let output_tok = self.consume(tok![ident])?;
-            self.text(output_tok)
+            dests = self.arith_dests()?;
+
+            // A remainder may only be requested for a single quotient.
+            if self.peek() == tok![remainder] {
+                self.next()?;
+                if dests.len() > 1 {
+                    parser_bail!(
+                        self,
+                        "DIVIDE statements with a 'REMAINDER' phrase may only have a single 'GIVING' item."
+                    );
+                }
+                remainder = Some(self.data_ref()?);
+            }
+            Some(dividend)
         } else if !left_to_right {
-            self.text(second_tok)
+            // Without "GIVING", each of the values following "INTO" is divided in place.
+            let Value::Variable(sym) = dividend else {
+                parser_bail!(
+                    self,
+                    "DIVIDE statements without a 'GIVING' clause must divide into variables."
+                );
+            };
+            dests.push(ArithDest {
+                sym,
+                rounding: second_rounding,
+            });
+            while self.peek() == tok![ident] {
+                dests.push(self.arith_dest()?);
+            }
+            None
         } else {
             parser_bail!(
                 self,
@@ -148,17 +437,12 @@ impl<'src> Parser<'src> {
             );
         };
 
-        // Determine which token is the dividend, and which is the divisor.
-        let (dividend_tok, divisor_tok) = if left_to_right {
-            (first_tok, second_tok)
-        } else {
-            (second_tok, first_tok)
-        };
-
         Ok(Stat::Divide(DivideData {
-            dividend: self.text(dividend_tok),
-            divisor: self.text(divisor_tok),
-            out_var,
+            dividend,
+            divisor,
+            dests,
+            remainder,
+            size_error: self.parse_size_error(tok![end_divide])?,
         }))
     }
 }
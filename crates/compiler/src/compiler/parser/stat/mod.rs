@@ -1,49 +1,156 @@
-use super::{parser_bail, token::tok, Literal, Parser, Spanned, Value};
+use super::{
+    parser_bail,
+    token::{tok, Token},
+    DataRef, Literal, Parser, Spanned, Value,
+};
 use miette::Result;
+use std::fmt::Display;
 
 pub(crate) use cond::*;
 pub(crate) use control::*;
 pub(crate) use intrinsics::*;
 pub(crate) use math::*;
+pub(crate) use table::*;
 
 mod cond;
 mod control;
 mod intrinsics;
 mod math;
+mod table;
 
 /// Represents a single executable statement within a COBOL program.
 #[derive(Debug)]
 pub(crate) enum Stat<'src> {
     Display(Vec<Value<'src>>),
     Move(MoveData<'src>),
+    Initialize(InitializeData<'src>),
     Add(BasicMathOpData<'src>),
     Subtract(BasicMathOpData<'src>),
     Multiply(BasicMathOpData<'src>),
     Divide(DivideData<'src>),
+    Compute(ComputeData<'src>),
     If(IfData<'src>),
+    Evaluate(EvaluateData<'src>),
     Perform(PerformType<'src>),
-    Accept(&'src str),
+    Accept(DataRef<'src>),
     Exit(ExitType),
+    GoTo(GoToData<'src>),
+    StopRun(Option<Value<'src>>),
+    GoBack,
+    Continue,
+    NextSentence,
+    Search(SearchData<'src>),
+    SearchAll(SearchAllData<'src>),
+    Set(SetData<'src>),
+}
+
+impl<'src> Stat<'src> {
+    /// Returns whether the given token begins a statement.
+    pub(super) fn is_stat_start(tok: Token) -> bool {
+        matches!(
+            tok,
+            tok![display]
+                | tok![move]
+                | tok![initialize]
+                | tok![add]
+                | tok![subtract]
+                | tok![multiply]
+                | tok![divide]
+                | tok![compute]
+                | tok![if]
+                | tok![evaluate]
+                | tok![perform]
+                | tok![accept]
+                | tok![exit]
+                | tok![go]
+                | tok![stop_run]
+                | tok![goback]
+                | tok![continue]
+                | tok![next_sentence]
+                | tok![search]
+                | tok![set]
+        )
+    }
 }
 
 impl<'src> Parser<'src> {
+    /// Parses a set of statements from the current parser position, until a token which cannot
+    /// begin a statement is reached. Statements may be separated by any number of newlines,
+    /// including none at all.
+    pub(super) fn stats(&mut self) -> Result<Vec<Spanned<Stat<'src>>>> {
+        let mut stats: Vec<Spanned<Stat<'src>>> = Vec::new();
+        loop {
+            self.skip_eols()?;
+            if !Stat::is_stat_start(self.peek()) {
+                break;
+            }
+            stats.push(self.stat()?);
+        }
+        Ok(stats)
+    }
+
+    /// Consumes the given scope terminator of a conditional statement, if present.
+    /// Otherwise, the statement is ended by the period ending the sentence, or by the end of an
+    /// enclosing statement.
+    pub(super) fn end_scope(&mut self, end: Token) -> Result<()> {
+        if self.peek() == end {
+            self.next()?;
+        }
+        Ok(())
+    }
+
+    /// Consumes a single newline at the current parser position, if the token following it
+    /// satisfies the given predicate.
+    fn skip_eol_before(&mut self, pred: impl Fn(Token) -> bool) -> Result<()> {
+        if self.peek() == tok![eol] && pred(self.peek_nth(1)) {
+            self.next()?;
+        }
+        Ok(())
+    }
+
+    /// Consumes any newlines at the current parser position.
+    pub(super) fn skip_eols(&mut self) -> Result<()> {
+        while self.peek() == tok![eol] {
+            self.next()?;
+        }
+        Ok(())
+    }
+
     /// Parses a single statement from the current parser position.
-    /// Takes whether this statement should end with a "." in addition to a newline.
-    pub(super) fn stat(&mut self, parse_dot: bool) -> Result<Spanned<Stat<'src>>> {
+    pub(super) fn stat(&mut self) -> Result<Spanned<Stat<'src>>> {
         let start_idx = self.peek_idx();
 
         // Parse the statement body.
         let stat = match self.peek() {
             tok![display] => self.parse_display()?,
             tok![move] => self.parse_move()?,
+            tok![initialize] => self.parse_initialize()?,
             tok![add] => self.parse_add()?,
             tok![subtract] => self.parse_subtract()?,
             tok![multiply] => self.parse_multiply()?,
             tok![divide] => self.parse_divide()?,
+            tok![compute] => self.parse_compute()?,
             tok![if] => self.parse_if()?,
+            tok![evaluate] => self.parse_evaluate()?,
             tok![perform] => self.parse_perform()?,
             tok![accept] => self.parse_accept()?,
             tok![exit] => self.parse_exit()?,
+            tok![go] => self.parse_go_to()?,
+            tok![stop_run] => self.parse_stop_run()?,
+            tok![goback] => {
+                self.next()?;
+                Stat::GoBack
+            }
+            tok![continue] => {
+                self.next()?;
+                Stat::Continue
+            }
+            tok![next_sentence] => {
+                self.next()?;
+                Stat::NextSentence
+            }
+            tok![search] => self.parse_search()?,
+            tok![set] => self.parse_set()?,
 
             // Unknown token.
             tok => {
@@ -56,12 +163,6 @@ impl<'src> Parser<'src> {
             }
         };
 
-        // Parse the dot (optionally) and newline out.
-        if parse_dot {
-            self.consume(tok![.])?;
-        }
-        self.consume(tok![eol])?;
-
         Ok((stat, (start_idx, self.cur_idx()).into()))
     }
 
@@ -84,8 +185,7 @@ impl<'src> Parser<'src> {
     /// Parses a single "ACCEPT" statement from the current position.
     fn parse_accept(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![accept])?;
-        let ident_tok = self.consume(tok![ident])?;
-        Ok(Stat::Accept(self.text(ident_tok)))
+        Ok(Stat::Accept(self.data_ref()?))
     }
 }
 
@@ -94,6 +194,10 @@ impl<'src> Parser<'src> {
 pub(crate) struct MoveData<'src> {
     pub source: MoveSource<'src>,
     pub dest: MoveRef<'src>,
+
+    /// Whether the move is between the corresponding subordinate items of two groups,
+    /// rather than between the items themselves.
+    pub corresponding: bool,
 }
 
 /// All available sources for a MOVE instruction.
@@ -109,7 +213,7 @@ pub(crate) enum MoveSource<'src> {
 #[derive(Debug)]
 pub(crate) struct MoveRef<'src> {
     /// The underyling variable for this move reference.
-    pub sym: &'src str,
+    pub sym: DataRef<'src>,
 
     /// The span of this variable that is targeted, if specified.
     /// This is only valid on variables of type PIC X(N), and is verified at code generation.
@@ -123,11 +227,74 @@ pub(crate) struct MoveSpan<'src> {
     pub len: Option<Value<'src>>,
 }
 
+/// Data for a single "INITIALIZE" statement.
+#[derive(Debug)]
+pub(crate) struct InitializeData<'src> {
+    /// The items to initialize, each of which may be elementary or a group.
+    pub targets: Vec<DataRef<'src>>,
+
+    /// The literal to initialize each category of elementary item to, from the REPLACING phrase.
+    /// When empty, every elementary item is initialized to its default value instead.
+    pub replacing: Vec<(InitCategory, Literal)>,
+}
+
+/// A category of elementary item which may be named in the REPLACING phrase of an
+/// "INITIALIZE" statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InitCategory {
+    Alphabetic,
+    Alphanumeric,
+    Numeric,
+    NumericEdited,
+}
+
+impl InitCategory {
+    /// Returns the category named by the given token, if any.
+    fn from_token(tok: Token) -> Option<Self> {
+        match tok {
+            tok![alphabetic] => Some(Self::Alphabetic),
+            tok![alphanumeric] => Some(Self::Alphanumeric),
+            tok![numeric] => Some(Self::Numeric),
+            tok![numeric_edited] => Some(Self::NumericEdited),
+            _ => None,
+        }
+    }
+}
+
+impl Display for InitCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Alphabetic => f.write_str("ALPHABETIC"),
+            Self::Alphanumeric => f.write_str("ALPHANUMERIC"),
+            Self::Numeric => f.write_str("NUMERIC"),
+            Self::NumericEdited => f.write_str("NUMERIC-EDITED"),
+        }
+    }
+}
+
 impl<'src> Parser<'src> {
     /// Parses a single "MOVE" statement from the current position.
     fn parse_move(&mut self) -> Result<Stat<'src>> {
         self.consume(tok![move])?;
 
+        // Corresponding moves are only between two group references.
+        if self.parse_corresponding()? {
+            let source = MoveSource::MoveRef(MoveRef {
+                sym: self.data_ref()?,
+                span: None,
+            });
+            self.consume(tok![to])?;
+            let dest = MoveRef {
+                sym: self.data_ref()?,
+                span: None,
+            };
+            return Ok(Stat::Move(MoveData {
+                source,
+                dest,
+                corresponding: true,
+            }));
+        }
+
         // Parse the source.
         let source = if self.peek() == tok![function] {
             self.next()?;
@@ -148,14 +315,74 @@ impl<'src> Parser<'src> {
 
         // Parse the destination.
         self.consume(tok![to])?;
-        let dest_tok = self.consume(tok![ident])?;
-        let sym = &self.text(dest_tok);
+        let sym = self.data_ref()?;
         let span = (self.peek() == tok![open_par])
             .then(|| self.parse_span())
             .transpose()?;
         let dest = MoveRef { sym, span };
 
-        Ok(Stat::Move(MoveData { source, dest }))
+        Ok(Stat::Move(MoveData {
+            source,
+            dest,
+            corresponding: false,
+        }))
+    }
+
+    /// Parses a single "INITIALIZE" statement from the current position, in the form:
+    /// `INITIALIZE x... [REPLACING {ALPHABETIC|ALPHANUMERIC|NUMERIC|NUMERIC-EDITED} [DATA] BY lit...]`.
+    fn parse_initialize(&mut self) -> Result<Stat<'src>> {
+        self.consume(tok![initialize])?;
+        let mut targets = vec![self.data_ref()?];
+        while self.peek() == tok![ident] {
+            targets.push(self.data_ref()?);
+        }
+
+        // Parse out the replacement literal for each category, if present.
+        // The phrase may continue onto the following lines.
+        let mut replacing: Vec<(InitCategory, Literal)> = Vec::new();
+        self.skip_eol_before(|tok| tok == tok![replacing])?;
+        if self.peek() == tok![replacing] {
+            self.next()?;
+            loop {
+                self.skip_eol_before(|tok| InitCategory::from_token(tok).is_some())?;
+                let Some(category) = InitCategory::from_token(self.peek()) else {
+                    break;
+                };
+                self.next()?;
+                if replacing.iter().any(|(existing, _)| *existing == category) {
+                    parser_bail!(
+                        self,
+                        "The category '{}' cannot be replaced more than once.",
+                        category
+                    );
+                }
+                if self.peek() == tok![data_by] {
+                    self.next()?;
+                } else {
+                    self.consume(tok![by])?;
+                }
+                replacing.push((category, self.literal()?));
+            }
+            if replacing.is_empty() {
+                let next = self.next()?;
+                parser_bail!(
+                    self,
+                    "Expected a category of data item to replace, instead found '{}'.",
+                    next.0
+                );
+            }
+        }
+
+        Ok(Stat::Initialize(InitializeData { targets, replacing }))
+    }
+
+    /// Parses an optional "CORRESPONDING" (or "CORR") phrase, returning whether it was present.
+    fn parse_corresponding(&mut self) -> Result<bool> {
+        if self.peek() != tok![corresponding] && self.peek() != tok![corr] {
+            return Ok(false);
+        }
+        self.next()?;
+        Ok(true)
     }
 
     /// Parses a single [`MoveSpan`] from the current position.
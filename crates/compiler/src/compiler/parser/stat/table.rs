@@ -0,0 +1,174 @@
+use miette::Result;
+
+use crate::compiler::parser::{parser_bail, token::tok, Cond, DataRef, Parser, Spanned, Value};
+
+use super::Stat;
+
+/// Data for a single "SEARCH" statement, performing a linear search of a table.
+#[derive(Debug)]
+pub(crate) struct SearchData<'src> {
+    /// The name of the table being searched.
+    pub table: &'src str,
+
+    /// An additional item incremented alongside the table's index, if specified.
+    pub varying: Option<DataRef<'src>>,
+
+    /// Statements to execute if no occurrence matches any condition.
+    pub at_end: Option<Vec<Spanned<Stat<'src>>>>,
+
+    /// Conditions to check against each occurrence, in order.
+    pub whens: Vec<SearchWhen<'src>>,
+}
+
+/// Data for a single "SEARCH ALL" statement, performing a binary search of a keyed table.
+#[derive(Debug)]
+pub(crate) struct SearchAllData<'src> {
+    /// The name of the table being searched.
+    pub table: &'src str,
+
+    /// Statements to execute if no occurrence matches the condition.
+    pub at_end: Option<Vec<Spanned<Stat<'src>>>>,
+
+    /// The condition to search for, made up of equality checks against the table's keys.
+    pub when: SearchWhen<'src>,
+}
+
+/// A single "WHEN" phrase within a SEARCH statement.
+#[derive(Debug)]
+pub(crate) struct SearchWhen<'src> {
+    /// The condition to check.
+    pub cond: Cond<'src>,
+
+    /// Statements to execute once the condition matches.
+    pub stats: Vec<Spanned<Stat<'src>>>,
+}
+
+impl<'src> Parser<'src> {
+    /// Parses a single "SEARCH" or "SEARCH ALL" statement from the current position.
+    pub(super) fn parse_search(&mut self) -> Result<Stat<'src>> {
+        self.consume(tok![search])?;
+        let is_all = self.peek() == tok![all];
+        if is_all {
+            self.next()?;
+        }
+        let table_tok = self.consume(tok![ident])?;
+        let table = self.text(table_tok);
+
+        // Linear searches may increment an additional item.
+        let varying = if !is_all && self.peek() == tok![varying] {
+            self.next()?;
+            Some(self.data_ref()?)
+        } else {
+            None
+        };
+        self.skip_eols()?;
+
+        // Parse the "AT END" phrase, if present.
+        let at_end = if self.peek() == tok![at_end] {
+            self.next()?;
+            Some(self.stats()?)
+        } else {
+            None
+        };
+
+        // Parse out all "WHEN" phrases. There must be at least one.
+        let mut whens: Vec<SearchWhen<'src>> = Vec::new();
+        while self.peek() == tok![when] {
+            self.next()?;
+            let cond = self.parse_cond()?;
+            let stats = self.stats()?;
+            whens.push(SearchWhen { cond, stats });
+        }
+        if whens.is_empty() {
+            let err_tok = self.next()?.0;
+            parser_bail!(
+                self,
+                "Expected at least one WHEN phrase for SEARCH statement, instead found '{}'.",
+                err_tok
+            );
+        }
+        self.end_scope(tok![end_search])?;
+
+        // Binary searches may only contain a single condition.
+        if is_all {
+            if whens.len() > 1 {
+                parser_bail!(
+                    self,
+                    "SEARCH ALL statements may only contain a single WHEN phrase."
+                );
+            }
+            return Ok(Stat::SearchAll(SearchAllData {
+                table,
+                at_end,
+                when: whens.pop().unwrap(),
+            }));
+        }
+
+        Ok(Stat::Search(SearchData {
+            table,
+            varying,
+            at_end,
+            whens,
+        }))
+    }
+}
+
+/// Data for a single "SET" statement, used for manipulating table indexes and condition names.
+#[derive(Debug)]
+pub(crate) struct SetData<'src> {
+    /// The items being set.
+    pub targets: Vec<DataRef<'src>>,
+
+    /// The operation applied to each target.
+    pub action: SetAction<'src>,
+}
+
+/// Available operations for a single "SET" statement.
+#[derive(Debug)]
+pub(crate) enum SetAction<'src> {
+    To(Value<'src>),
+    UpBy(Value<'src>),
+    DownBy(Value<'src>),
+
+    /// Sets each target condition name to true, by moving its first value into its item.
+    ToTrue,
+}
+
+impl<'src> Parser<'src> {
+    /// Parses a single "SET" statement from the current position.
+    pub(super) fn parse_set(&mut self) -> Result<Stat<'src>> {
+        self.consume(tok![set])?;
+
+        // Parse all targets (there must be at least one).
+        let mut targets: Vec<DataRef<'src>> = Vec::new();
+        loop {
+            targets.push(self.data_ref()?);
+            if self.peek() != tok![ident] {
+                break;
+            }
+        }
+
+        let action = match self.next()?.0 {
+            tok![to] if self.peek() == tok![true] => {
+                self.next()?;
+                SetAction::ToTrue
+            }
+            tok![to] => SetAction::To(self.value()?),
+            tok![up] => {
+                self.consume(tok![by])?;
+                SetAction::UpBy(self.value()?)
+            }
+            tok![down] => {
+                self.consume(tok![by])?;
+                SetAction::DownBy(self.value()?)
+            }
+            tok => parser_bail!(
+                self,
+                "Expected one of TO, UP BY or DOWN BY for SET statement, instead found '{}'.",
+                tok
+            ),
+        };
+
+        Ok(Stat::Set(SetData { targets, action }))
+    }
+}
@@ -42,6 +42,80 @@ macro_rules! tok {
     [function] => { $crate::compiler::parser::Token::Function };
     [exit] => { $crate::compiler::parser::Token::Exit };
     [paragraph] => { $crate::compiler::parser::Token::Paragraph };
+    [occurs] => { $crate::compiler::parser::Token::Occurs };
+    [depending] => { $crate::compiler::parser::Token::Depending };
+    [on] => { $crate::compiler::parser::Token::On };
+    [ascending] => { $crate::compiler::parser::Token::Ascending };
+    [descending] => { $crate::compiler::parser::Token::Descending };
+    [key] => { $crate::compiler::parser::Token::Key };
+    [is] => { $crate::compiler::parser::Token::Is };
+    [indexed] => { $crate::compiler::parser::Token::Indexed };
+    [search] => { $crate::compiler::parser::Token::Search };
+    [all] => { $crate::compiler::parser::Token::All };
+    [at_end] => { $crate::compiler::parser::Token::AtEnd };
+    [when] => { $crate::compiler::parser::Token::When };
+    [end_search] => { $crate::compiler::parser::Token::EndSearch };
+    [set] => { $crate::compiler::parser::Token::Set };
+    [up] => { $crate::compiler::parser::Token::Up };
+    [down] => { $crate::compiler::parser::Token::Down };
+    [varying] => { $crate::compiler::parser::Token::Varying };
+    [redefines] => { $crate::compiler::parser::Token::Redefines };
+    [true] => { $crate::compiler::parser::Token::True };
+    [usage] => { $crate::compiler::parser::Token::Usage };
+    [sign] => { $crate::compiler::parser::Token::Sign };
+    [leading] => { $crate::compiler::parser::Token::Leading };
+    [trailing] => { $crate::compiler::parser::Token::Trailing };
+    [separate] => { $crate::compiler::parser::Token::Separate };
+    [character] => { $crate::compiler::parser::Token::Character };
+    [comp_3] => { $crate::compiler::parser::Token::Comp3 };
+    [packed_decimal] => { $crate::compiler::parser::Token::PackedDecimal };
+    [size_error] => { $crate::compiler::parser::Token::SizeError };
+    [end_add] => { $crate::compiler::parser::Token::EndAdd };
+    [end_subtract] => { $crate::compiler::parser::Token::EndSubtract };
+    [end_multiply] => { $crate::compiler::parser::Token::EndMultiply };
+    [end_divide] => { $crate::compiler::parser::Token::EndDivide };
+    [rounded] => { $crate::compiler::parser::Token::Rounded };
+    [mode] => { $crate::compiler::parser::Token::Mode };
+    [nearest_even] => { $crate::compiler::parser::Token::NearestEven };
+    [nearest_away_from_zero] => { $crate::compiler::parser::Token::NearestAwayFromZero };
+    [away_from_zero] => { $crate::compiler::parser::Token::AwayFromZero };
+    [truncation] => { $crate::compiler::parser::Token::Truncation };
+    [compute] => { $crate::compiler::parser::Token::Compute };
+    [end_compute] => { $crate::compiler::parser::Token::EndCompute };
+    [remainder] => { $crate::compiler::parser::Token::Remainder };
+    [of] => { $crate::compiler::parser::Token::Of };
+    [in] => { $crate::compiler::parser::Token::In };
+    [corresponding] => { $crate::compiler::parser::Token::Corresponding };
+    [corr] => { $crate::compiler::parser::Token::Corr };
+    [evaluate] => { $crate::compiler::parser::Token::Evaluate };
+    [end_evaluate] => { $crate::compiler::parser::Token::EndEvaluate };
+    [also] => { $crate::compiler::parser::Token::Also };
+    [other] => { $crate::compiler::parser::Token::Other };
+    [any] => { $crate::compiler::parser::Token::Any };
+    [false] => { $crate::compiler::parser::Token::False };
+    [after] => { $crate::compiler::parser::Token::After };
+    [end_perform] => { $crate::compiler::parser::Token::EndPerform };
+    [cycle] => { $crate::compiler::parser::Token::Cycle };
+    [go] => { $crate::compiler::parser::Token::Go };
+    [section] => { $crate::compiler::parser::Token::Section };
+    [returning] => { $crate::compiler::parser::Token::Returning };
+    [goback] => { $crate::compiler::parser::Token::Goback };
+    [program] => { $crate::compiler::parser::Token::Program };
+    [with_error_status] => { $crate::compiler::parser::Token::WithErrorStatus };
+    [continue] => { $crate::compiler::parser::Token::Continue };
+    [next_sentence] => { $crate::compiler::parser::Token::NextSentence };
+    [space] => { $crate::compiler::parser::Token::Space };
+    [zero] => { $crate::compiler::parser::Token::Zero };
+    [high_value] => { $crate::compiler::parser::Token::HighValue };
+    [low_value] => { $crate::compiler::parser::Token::LowValue };
+    [quote] => { $crate::compiler::parser::Token::Quote };
+    [initialize] => { $crate::compiler::parser::Token::Initialize };
+    [replacing] => { $crate::compiler::parser::Token::Replacing };
+    [alphabetic] => { $crate::compiler::parser::Token::Alphabetic };
+    [alphanumeric] => { $crate::compiler::parser::Token::Alphanumeric };
+    [numeric] => { $crate::compiler::parser::Token::Numeric };
+    [numeric_edited] => { $crate::compiler::parser::Token::NumericEdited };
+    [data_by] => { $crate::compiler::parser::Token::DataBy };
     [=] => { $crate::compiler::parser::Token::Equals };
     [<=] => { $crate::compiler::parser::Token::LessThanEqual };
     [>=] => { $crate::compiler::parser::Token::GreaterThanEqual };
@@ -52,6 +126,11 @@ macro_rules! tok {
     [close_par] => { $crate::compiler::parser::Token::CloseParentheses };
     [,] => { $crate::compiler::parser::Token::Comma };
     [:] => { $crate::compiler::parser::Token::Colon };
+    [+] => { $crate::compiler::parser::Token::Plus };
+    [-] => { $crate::compiler::parser::Token::Minus };
+    [**] => { $crate::compiler::parser::Token::Power };
+    [*] => { $crate::compiler::parser::Token::Asterisk };
+    [/] => { $crate::compiler::parser::Token::Slash };
     [float_lit] => { $crate::compiler::parser::Token::FloatLiteral };
     [int_lit] => { $crate::compiler::parser::Token::IntLiteral };
     [str_literal] => { $crate::compiler::parser::Token::StringLiteral };
@@ -63,10 +142,12 @@ macro_rules! tok {
 pub(crate) use tok;
 
 /// A lexer for COBOL tokens.
+#[derive(Clone)]
 pub(crate) struct Lexer<'src> {
     input: &'src str,
     generated: logos::SpannedIter<'src, Token>,
     eof_reached: bool,
+    pic_next: bool,
 }
 
 impl<'src> Lexer<'src> {
@@ -75,8 +156,37 @@ impl<'src> Lexer<'src> {
             input,
             generated: Token::lexer(input).spanned(),
             eof_reached: false,
+            pic_next: false,
         }
     }
+
+    /// Lexes a single PIC layout string from the current position, if one is present.
+    /// PIC strings are lexed manually, as their contents overlap with most other tokens.
+    /// The string ends at the first whitespace, or a "." which is followed by whitespace.
+    fn lex_pic_str(&mut self) -> Option<Spanned<Token>> {
+        let rem = self.generated.remainder();
+        let skip = rem.len() - rem.trim_start_matches([' ', '\t', '\x0c']).len();
+        let pic_str = &rem[skip..];
+        let len = pic_str
+            .char_indices()
+            .find(|(idx, c)| {
+                c.is_whitespace()
+                    || (*c == '.'
+                        && pic_str[idx + 1..]
+                            .chars()
+                            .next()
+                            .is_none_or(|n| n.is_whitespace()))
+            })
+            .map(|(idx, _)| idx)
+            .unwrap_or(pic_str.len());
+        if len == 0 {
+            return None;
+        }
+
+        let start = self.generated.span().end + skip;
+        self.generated.bump(skip + len);
+        Some((Token::PicClause, (start..start + len).into()))
+    }
 }
 
 /// Allows for iterating over the lexer's tokens.
@@ -85,6 +195,14 @@ impl<'src> Iterator for Lexer<'src> {
 
     /// Returns the next token as a spanned object.
     fn next(&mut self) -> Option<Self::Item> {
+        // If the previous token was "PIC", we expect a layout string.
+        if self.pic_next {
+            self.pic_next = false;
+            if let Some(pic_tok) = self.lex_pic_str() {
+                return Some(pic_tok);
+            }
+        }
+
         loop {
             match self.generated.next() {
                 Some((kind, span)) => {
@@ -96,6 +214,7 @@ impl<'src> Iterator for Lexer<'src> {
                     }
 
                     //Not a comment, we can safely return the token.
+                    self.pic_next = kind == Token::Pic;
                     return Some((kind, span.into()));
                 }
 
@@ -176,10 +295,13 @@ pub(crate) enum Token {
     #[token("UNTIL")]
     Until,
     #[token("WITH TEST BEFORE")]
+    #[token("TEST BEFORE")]
     WithTestBefore,
     #[token("WITH TEST AFTER")]
+    #[token("TEST AFTER")]
     WithTestAfter,
     #[token("THRU")]
+    #[token("THROUGH")]
     Thru,
     #[token("TIMES")]
     Times,
@@ -191,6 +313,160 @@ pub(crate) enum Token {
     Exit,
     #[token("PARAGRAPH")]
     Paragraph,
+    #[token("OCCURS")]
+    Occurs,
+    #[token("DEPENDING")]
+    Depending,
+    #[token("ON", priority = 5)]
+    On,
+    #[token("ASCENDING")]
+    Ascending,
+    #[token("DESCENDING")]
+    Descending,
+    #[token("KEY")]
+    Key,
+    #[token("IS", priority = 5)]
+    Is,
+    #[token("INDEXED")]
+    Indexed,
+    #[token("SEARCH")]
+    Search,
+    #[token("ALL")]
+    All,
+    #[token("AT END")]
+    AtEnd,
+    #[token("WHEN")]
+    When,
+    #[token("END-SEARCH")]
+    EndSearch,
+    #[token("SET")]
+    Set,
+    #[token("UP", priority = 5)]
+    Up,
+    #[token("DOWN")]
+    Down,
+    #[token("VARYING")]
+    Varying,
+    #[token("REDEFINES")]
+    Redefines,
+    #[token("TRUE")]
+    True,
+    #[token("USAGE")]
+    Usage,
+    #[token("SIGN")]
+    Sign,
+    #[token("LEADING")]
+    Leading,
+    #[token("TRAILING")]
+    Trailing,
+    #[token("SEPARATE")]
+    Separate,
+    #[token("CHARACTER")]
+    Character,
+    #[token("COMP-3")]
+    Comp3,
+    #[token("PACKED-DECIMAL")]
+    PackedDecimal,
+    #[token("SIZE ERROR")]
+    SizeError,
+    #[token("END-ADD")]
+    EndAdd,
+    #[token("END-SUBTRACT")]
+    EndSubtract,
+    #[token("END-MULTIPLY")]
+    EndMultiply,
+    #[token("END-DIVIDE")]
+    EndDivide,
+    #[token("ROUNDED")]
+    Rounded,
+    #[token("MODE")]
+    Mode,
+    #[token("NEAREST-EVEN")]
+    NearestEven,
+    #[token("NEAREST-AWAY-FROM-ZERO")]
+    NearestAwayFromZero,
+    #[token("AWAY-FROM-ZERO")]
+    AwayFromZero,
+    #[token("TRUNCATION")]
+    Truncation,
+    #[token("COMPUTE")]
+    Compute,
+    #[token("END-COMPUTE")]
+    EndCompute,
+    #[token("REMAINDER")]
+    Remainder,
+    #[token("OF", priority = 5)]
+    Of,
+    #[token("IN", priority = 5)]
+    In,
+    #[token("CORRESPONDING")]
+    Corresponding,
+    #[token("CORR")]
+    Corr,
+    #[token("EVALUATE")]
+    Evaluate,
+    #[token("END-EVALUATE")]
+    EndEvaluate,
+    #[token("ALSO")]
+    Also,
+    #[token("OTHER")]
+    Other,
+    #[token("ANY")]
+    Any,
+    #[token("FALSE")]
+    False,
+    #[token("AFTER")]
+    After,
+    #[token("END-PERFORM")]
+    EndPerform,
+    #[token("CYCLE")]
+    Cycle,
+    #[token("GO", priority = 5)]
+    Go,
+    #[token("SECTION")]
+    Section,
+    #[token("RETURNING")]
+    Returning,
+    #[token("GOBACK")]
+    Goback,
+    #[token("PROGRAM")]
+    Program,
+    #[token("WITH ERROR STATUS")]
+    WithErrorStatus,
+    #[token("CONTINUE")]
+    Continue,
+    #[token("NEXT SENTENCE")]
+    NextSentence,
+    #[token("SPACE")]
+    #[token("SPACES")]
+    Space,
+    #[token("ZERO")]
+    #[token("ZEROS")]
+    #[token("ZEROES")]
+    Zero,
+    #[token("HIGH-VALUE")]
+    #[token("HIGH-VALUES")]
+    HighValue,
+    #[token("LOW-VALUE")]
+    #[token("LOW-VALUES")]
+    LowValue,
+    #[token("QUOTE")]
+    #[token("QUOTES")]
+    Quote,
+    #[token("INITIALIZE")]
+    Initialize,
+    #[token("REPLACING")]
+    Replacing,
+    #[token("ALPHABETIC")]
+    Alphabetic,
+    #[token("ALPHANUMERIC")]
+    Alphanumeric,
+    #[token("NUMERIC")]
+    Numeric,
+    #[token("NUMERIC-EDITED")]
+    NumericEdited,
+    #[token("DATA BY")]
+    DataBy,
 
     // Symbols & regex tokens.
     #[token("=")]
@@ -213,6 +489,16 @@ pub(crate) enum Token {
     Comma,
     #[token(":")]
     Colon,
+    #[token("+")]
+    Plus,
+    #[token("-")]
+    Minus,
+    #[token("**")]
+    Power,
+    #[token("*")]
+    Asterisk,
+    #[token("/")]
+    Slash,
     #[regex(r#"(-)?[0-9]+\.[0-9]+"#)]
     FloatLiteral,
     #[regex(r#"(-)?[0-9]+"#, priority = 5)]
@@ -220,11 +506,11 @@ pub(crate) enum Token {
     #[regex(r#""((\[.])|[^\"])*""#)]
     #[regex(r#"'((\[.])|[^\'])*'"#)]
     StringLiteral,
-    #[regex(r#"([9AXVSP](\([0-9]+\))?)+"#, priority = 3)]
+    // Lexed manually following a "PIC" token, see [`Lexer::lex_pic_str()`].
     PicClause,
     // This is technically incorrect for now, but Logos doesn't like the correct regex of:
     // [A-Z0-9][A-Z0-9-]*[A-Z0-9]|[A-Z0-9]+
-    #[regex(r#"[A-Z0-9][A-Z0-9-]+"#)]
+    #[regex(r#"[A-Z0-9][A-Z0-9-]*"#)]
     Identifier,
     #[regex(r"\*>[^\n]*[\n]*")]
     SingleLineComment,
@@ -277,6 +563,80 @@ impl Display for Token {
             Token::Function => write!(f, "FUNCTION"),
             Token::Exit => write!(f, "EXIT"),
             Token::Paragraph => write!(f, "PARAGRAPH"),
+            Token::Occurs => write!(f, "OCCURS"),
+            Token::Depending => write!(f, "DEPENDING"),
+            Token::On => write!(f, "ON"),
+            Token::Ascending => write!(f, "ASCENDING"),
+            Token::Descending => write!(f, "DESCENDING"),
+            Token::Key => write!(f, "KEY"),
+            Token::Is => write!(f, "IS"),
+            Token::Indexed => write!(f, "INDEXED"),
+            Token::Search => write!(f, "SEARCH"),
+            Token::All => write!(f, "ALL"),
+            Token::AtEnd => write!(f, "AT END"),
+            Token::When => write!(f, "WHEN"),
+            Token::EndSearch => write!(f, "END-SEARCH"),
+            Token::Set => write!(f, "SET"),
+            Token::Up => write!(f, "UP"),
+            Token::Down => write!(f, "DOWN"),
+            Token::Varying => write!(f, "VARYING"),
+            Token::Redefines => write!(f, "REDEFINES"),
+            Token::True => write!(f, "TRUE"),
+            Token::Usage => write!(f, "USAGE"),
+            Token::Sign => write!(f, "SIGN"),
+            Token::Leading => write!(f, "LEADING"),
+            Token::Trailing => write!(f, "TRAILING"),
+            Token::Separate => write!(f, "SEPARATE"),
+            Token::Character => write!(f, "CHARACTER"),
+            Token::Comp3 => write!(f, "COMP-3"),
+            Token::PackedDecimal => write!(f, "PACKED-DECIMAL"),
+            Token::SizeError => write!(f, "SIZE ERROR"),
+            Token::EndAdd => write!(f, "END-ADD"),
+            Token::EndSubtract => write!(f, "END-SUBTRACT"),
+            Token::EndMultiply => write!(f, "END-MULTIPLY"),
+            Token::EndDivide => write!(f, "END-DIVIDE"),
+            Token::Rounded => write!(f, "ROUNDED"),
+            Token::Mode => write!(f, "MODE"),
+            Token::NearestEven => write!(f, "NEAREST-EVEN"),
+            Token::NearestAwayFromZero => write!(f, "NEAREST-AWAY-FROM-ZERO"),
+            Token::AwayFromZero => write!(f, "AWAY-FROM-ZERO"),
+            Token::Truncation => write!(f, "TRUNCATION"),
+            Token::Compute => write!(f, "COMPUTE"),
+            Token::EndCompute => write!(f, "END-COMPUTE"),
+            Token::Remainder => write!(f, "REMAINDER"),
+            Token::Of => write!(f, "OF"),
+            Token::In => write!(f, "IN"),
+            Token::Corresponding => write!(f, "CORRESPONDING"),
+            Token::Corr => write!(f, "CORR"),
+            Token::Evaluate => write!(f, "EVALUATE"),
+            Token::EndEvaluate => write!(f, "END-EVALUATE"),
+            Token::Also => write!(f, "ALSO"),
+            Token::Other => write!(f, "OTHER"),
+            Token::Any => write!(f, "ANY"),
+            Token::False => write!(f, "FALSE"),
+            Token::After => write!(f, "AFTER"),
+            Token::EndPerform => write!(f, "END-PERFORM"),
+            Token::Cycle => write!(f, "CYCLE"),
+            Token::Go => write!(f, "GO"),
+            Token::Section => write!(f, "SECTION"),
+            Token::Returning => write!(f, "RETURNING"),
+            Token::Goback => write!(f, "GOBACK"),
+            Token::Program => write!(f, "PROGRAM"),
+            Token::WithErrorStatus => write!(f, "WITH ERROR STATUS"),
+            Token::Continue => write!(f, "CONTINUE"),
+            Token::NextSentence => write!(f, "NEXT SENTENCE"),
+            Token::Space => write!(f, "SPACE"),
+            Token::Zero => write!(f, "ZERO"),
+            Token::HighValue => write!(f, "HIGH-VALUE"),
+            Token::LowValue => write!(f, "LOW-VALUE"),
+            Token::Quote => write!(f, "QUOTE"),
+            Token::Initialize => write!(f, "INITIALIZE"),
+            Token::Replacing => write!(f, "REPLACING"),
+            Token::Alphabetic => write!(f, "ALPHABETIC"),
+            Token::Alphanumeric => write!(f, "ALPHANUMERIC"),
+            Token::Numeric => write!(f, "NUMERIC"),
+            Token::NumericEdited => write!(f, "NUMERIC-EDITED"),
+            Token::DataBy => write!(f, "DATA BY"),
             Token::Equals => write!(f, "="),
             Token::LessThanEqual => write!(f, "<="),
             Token::GreaterThanEqual => write!(f, ">="),
@@ -287,6 +647,11 @@ impl Display for Token {
             Token::CloseParentheses => write!(f, ")"),
             Token::Comma => write!(f, ","),
             Token::Colon => write!(f, ":"),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Power => write!(f, "**"),
+            Token::Asterisk => write!(f, "*"),
+            Token::Slash => write!(f, "/"),
             Token::FloatLiteral => write!(f, "float-literal"),
             Token::IntLiteral => write!(f, "int-literal"),
             Token::StringLiteral => write!(f, "string-literal"),
@@ -18,6 +18,9 @@ pub struct CommonTestRunner {
 
     /// The expected output type.
     expected: ExpectedOutput,
+
+    /// The expected exit status of the output program, if tested.
+    exit_code: Option<i32>,
 }
 
 /// Represents a single expected output from a common compiler
@@ -51,6 +54,7 @@ impl CommonTestRunner {
             name,
             input: "",
             expected: ExpectedOutput::None,
+            exit_code: None,
         }
     }
 
@@ -93,6 +97,13 @@ impl CommonTestRunner {
         self
     }
 
+    /// Modifies the current test runner to additionally expect the output program to exit
+    /// with the given status.
+    pub fn expect_exit_code(mut self, code: i32) -> Self {
+        self.exit_code = Some(code);
+        self
+    }
+
     /// Executes this test runner. Panics on test failure.
     pub fn run(self) {
         // Create a build configuration based on our inputs.
@@ -159,7 +170,7 @@ impl CommonTestRunner {
                         self.name, e
                     );
                 }
-                Self::test_output(&self.name, input, expected);
+                Self::test_output(&self.name, input, expected, self.exit_code);
             }
         }
 
@@ -176,12 +187,17 @@ impl CommonTestRunner {
 
     /// Tests the output of a single common test runner, assuming that output is placed at
     /// `./target/{test_name}.out`. Panics on failure.
-    fn test_output(test_name: &str, input: Option<&str>, expected: &str) {
+    fn test_output(
+        test_name: &str,
+        input: Option<&str>,
+        expected: &str,
+        expected_exit_code: Option<i32>,
+    ) {
         let mut out_bin = PathBuf::from_str("target").unwrap();
         out_bin.push(format!("{}.out", test_name));
 
         // Execute with/without `stdin` and get output.
-        let output = if let Some(input) = input {
+        let (output, exit_code) = if let Some(input) = input {
             run_bin_stdin(&out_bin, input)
         } else {
             run_bin_nostdin(&out_bin)
@@ -191,27 +207,32 @@ impl CommonTestRunner {
         if output != expected {
             panic!("Failure for test '{}' output conformancy:\n=== Expected ===\n{}\n=== Found ===\n{}", test_name, expected, output);
         }
+
+        // Check if the exit status matches expected, if required.
+        if expected_exit_code.is_some_and(|code| exit_code != Some(code)) {
+            panic!(
+                "Failure for test '{}' exit status: expected {:?}, found {:?}.",
+                test_name, expected_exit_code, exit_code
+            );
+        }
     }
 }
 
-/// Executes the given binary, returning the output that the command created with no input.
-/// Panics on failure to execute.
-fn run_bin_nostdin(bin: &PathBuf) -> String {
+/// Executes the given binary, returning the output that the command created with no input,
+/// along with its exit status. Panics on failure to execute.
+fn run_bin_nostdin(bin: &PathBuf) -> (String, Option<i32>) {
     let mut cmd = Command::new(bin.to_str().unwrap());
-    String::from_utf8(
-        cmd.output()
-            .expect(&format!(
-                "Failed to execute test binary: {}",
-                bin.to_str().unwrap()
-            ))
-            .stdout,
-    )
-    .unwrap()
+    let output = cmd.output().expect(&format!(
+        "Failed to execute test binary: {}",
+        bin.to_str().unwrap()
+    ));
+    (String::from_utf8(output.stdout).unwrap(), output.status.code())
 }
 
 /// Executes the given binary, passing the provided input via. `stdin`.
-/// Returns the output generated by the given program on `stdout`. Panics on failure.
-fn run_bin_stdin(bin: &PathBuf, input: &str) -> String {
+/// Returns the output generated by the given program on `stdout`, along with its exit status.
+/// Panics on failure.
+fn run_bin_stdin(bin: &PathBuf, input: &str) -> (String, Option<i32>) {
     let mut cmd = Command::new(bin.to_str().unwrap());
     cmd.stdin(Stdio::piped());
     cmd.stdout(Stdio::piped());
@@ -224,12 +245,9 @@ fn run_bin_stdin(bin: &PathBuf, input: &str) -> String {
     if let Some(mut child_stdin) = child.stdin.take() {
         child_stdin.write_all(input_bytes).unwrap();
     }
-    let stdout = child
-        .wait_with_output()
-        .expect(&format!(
-            "Failed to execute child test process: {}",
-            bin.to_str().unwrap()
-        ))
-        .stdout;
-    String::from_utf8(stdout).unwrap()
+    let output = child.wait_with_output().expect(&format!(
+        "Failed to execute child test process: {}",
+        bin.to_str().unwrap()
+    ));
+    (String::from_utf8(output.stdout).unwrap(), output.status.code())
 }
@@ -0,0 +1,242 @@
+use super::common::CommonTestRunner;
+
+/// Tests that expressions are evaluated with standard operator precedence.
+#[test]
+fn compute_precedence() {
+    CommonTestRunner::new("compute_precedence")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMPUTE-PRECEDENCE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 99 VALUE 3.
+    01 B-VAL PIC 99 VALUE 5.
+    01 C-VAL PIC 99 VALUE 4.
+    01 N-VAL PIC 9 VALUE 3.
+    01 X-VAL PIC S9(4).
+    01 Y-VAL PIC S9(4).
+
+PROCEDURE DIVISION.
+    COMPUTE X-VAL = (A-VAL + B-VAL) * C-VAL / 2 ** N-VAL.
+    COMPUTE Y-VAL = A-VAL + B-VAL * C-VAL - 10 / 2.
+    DISPLAY X-VAL " " Y-VAL.
+STOP RUN.
+        "#)
+        .expect_output("+0004 +0018\n")
+        .run();
+}
+
+/// Tests unary signs, and that exponentiation is evaluated from left to right.
+#[test]
+fn compute_unary_power() {
+    CommonTestRunner::new("compute_unary_power")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMPUTE-UNARY-POWER-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC S99 COMP VALUE 2.
+    01 X-VAL PIC S9(4) COMP.
+    01 Y-VAL PIC S9(4) COMP.
+    01 Z-VAL PIC S9V999 COMP.
+
+PROCEDURE DIVISION.
+    COMPUTE X-VAL = -A-VAL ** 2 + 2 ** 3 ** 2.
+    COMPUTE Y-VAL = - (A-VAL - 7) * +3.
+    COMPUTE Z-VAL = A-VAL ** -3.
+    DISPLAY X-VAL " " Y-VAL " " Z-VAL.
+STOP RUN.
+        "#)
+        .expect_output("68 15 0.125\n")
+        .run();
+}
+
+/// Tests that expressions with mixed integer and decimal operands are calculated exactly.
+#[test]
+fn compute_mixed_decimals() {
+    CommonTestRunner::new("compute_mixed_decimals")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMPUTE-MIXED-DECIMALS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-PRINCIPAL PIC 9(6)V99 COMP VALUE 1000.
+    01 WS-RATE PIC V9999 COMP VALUE 0.0525.
+    01 WS-YEARS PIC 99 COMP VALUE 2.
+    01 WS-TOTAL PIC 9(6)V99 COMP.
+    01 WS-SPLIT PIC 9(4)V99 COMP.
+
+PROCEDURE DIVISION.
+    COMPUTE WS-TOTAL ROUNDED = WS-PRINCIPAL * (1 + WS-RATE) ** WS-YEARS.
+    COMPUTE WS-SPLIT WS-TOTAL = WS-PRINCIPAL / 3 + 0.5.
+    DISPLAY WS-SPLIT " " WS-TOTAL.
+    COMPUTE WS-TOTAL ROUNDED MODE IS NEAREST-EVEN = WS-PRINCIPAL * (1 + WS-RATE) ** WS-YEARS.
+    DISPLAY WS-TOTAL.
+STOP RUN.
+        "#)
+        .expect_output("333.83 333.83\n1107.76\n")
+        .run();
+}
+
+/// Tests that expressions can span multiple lines, and that the result is checked for size errors.
+#[test]
+fn compute_multiline_size_error() {
+    CommonTestRunner::new("compute_multiline_size_error")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMPUTE-MULTILINE-SIZE-ERROR-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 999 COMP VALUE 500.
+    01 X-VAL PIC 999 COMP VALUE 1.
+
+PROCEDURE DIVISION.
+    COMPUTE X-VAL = A-VAL * 2
+        + 1
+        ON SIZE ERROR DISPLAY "OVERFLOW"
+        NOT ON SIZE ERROR DISPLAY "OK"
+    END-COMPUTE.
+    COMPUTE X-VAL = A-VAL +
+        1
+    END-COMPUTE.
+    DISPLAY X-VAL.
+STOP RUN.
+        "#)
+        .expect_output("OVERFLOW\n501\n")
+        .run();
+}
+
+/// Tests that dividing by zero within an expression is a size error, leaving the destination unchanged.
+#[test]
+fn compute_divide_by_zero() {
+    CommonTestRunner::new("compute_divide_by_zero")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMPUTE-DIVIDE-BY-ZERO-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 C-VAL PIC 999 COMP VALUE 7.
+    01 D-VAL PIC 999 COMP VALUE 0.
+
+PROCEDURE DIVISION.
+    COMPUTE C-VAL = 10 / D-VAL
+        ON SIZE ERROR DISPLAY "C0"
+        NOT ON SIZE ERROR DISPLAY "OK"
+    END-COMPUTE.
+    COMPUTE C-VAL = 1 + 10 / D-VAL.
+    DISPLAY C-VAL.
+    MOVE 4 TO D-VAL.
+    COMPUTE C-VAL = 10 / D-VAL
+        ON SIZE ERROR DISPLAY "C0"
+        NOT ON SIZE ERROR DISPLAY "OK"
+    END-COMPUTE.
+    DISPLAY C-VAL.
+STOP RUN.
+        "#)
+        .expect_output("C0\n7\nOK\n2\n")
+        .run();
+}
+
+/// Tests that intrinsic function calls can be used within an expression.
+#[test]
+fn compute_intrinsic() {
+    CommonTestRunner::new("compute_intrinsic")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMPUTE-INTRINSIC-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 99 COMP VALUE 17.
+    01 X-VAL PIC 99 COMP.
+
+PROCEDURE DIVISION.
+    COMPUTE X-VAL = FUNCTION MOD(A-VAL, 5) * 10 + 1.
+    DISPLAY X-VAL.
+STOP RUN.
+        "#)
+        .expect_output("21\n")
+        .run();
+}
+
+/// Tests that the result of a COMPUTE statement cannot be stored in a string.
+#[test]
+fn compute_string_dest() {
+    CommonTestRunner::new("compute_string_dest")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMPUTE-STRING-DEST-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 X-VAL PIC X(4).
+
+PROCEDURE DIVISION.
+    COMPUTE X-VAL = 1 + 2.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Cannot save the result of a COMPUTE operation in a string-typed variable."))
+        .run();
+}
+
+/// Tests that arithmetic expressions can be used on either side of a condition.
+#[test]
+fn cond_expressions() {
+    CommonTestRunner::new("cond_expressions")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COND-EXPRESSIONS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 99 VALUE 7.
+    01 B-VAL PIC 99 VALUE 4.
+    01 C-VAL PIC 9V9 VALUE 5.5.
+
+PROCEDURE DIVISION.
+    IF A-VAL + B-VAL > C-VAL * 1.5 THEN
+        DISPLAY "GREATER"
+    END-IF.
+    IF (A-VAL - B-VAL) * 2 = 6 AND A-VAL / 2 = 3.5 THEN
+        DISPLAY "EQUAL"
+    END-IF.
+    IF A-VAL / 3 = 2.33 THEN
+        DISPLAY "INEXACT"
+    ELSE
+        DISPLAY "EXACT"
+    END-IF.
+STOP RUN.
+        "#)
+        .expect_output("GREATER\nEQUAL\nEXACT\n")
+        .run();
+}
+
+/// Tests that an arithmetic expression can be used within the condition of a PERFORM UNTIL.
+#[test]
+fn perform_until_expression() {
+    CommonTestRunner::new("perform_until_expression")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-UNTIL-EXPRESSION-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 I-VAL PIC 99 COMP VALUE 1.
+    01 N-VAL PIC 99 COMP VALUE 50.
+
+PROCEDURE DIVISION.
+    PERFORM STEP-I UNTIL I-VAL * I-VAL > N-VAL.
+    DISPLAY I-VAL.
+    STOP RUN.
+
+    STEP-I.
+        ADD 1 TO I-VAL.
+        "#)
+        .expect_output("8\n")
+        .run();
+}
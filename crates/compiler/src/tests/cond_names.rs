@@ -0,0 +1,125 @@
+use super::common::CommonTestRunner;
+
+/// Tests that condition names can be checked directly within conditions, and set with "SET ... TO TRUE".
+#[test]
+fn string_cond_names() {
+    CommonTestRunner::new("string_cond_names")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. STRING-COND-NAMES-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-STATUS PIC X(1) VALUE "A".
+        88 IS-ACTIVE VALUE "A".
+        88 IS-CLOSED VALUE "C" "X".
+
+PROCEDURE DIVISION.
+    IF IS-ACTIVE THEN
+        DISPLAY "Active"
+    END-IF.
+    SET IS-CLOSED TO TRUE.
+    DISPLAY WS-STATUS.
+    IF NOT IS-ACTIVE AND IS-CLOSED THEN
+        DISPLAY "Closed"
+    END-IF.
+    MOVE "X" TO WS-STATUS.
+    IF IS-CLOSED THEN
+        DISPLAY "Still closed"
+    END-IF.
+STOP RUN.
+        "#)
+        .expect_output("Active\nC\nClosed\nStill closed\n")
+        .run();
+}
+
+/// Tests that condition names can hold for ranges of numeric values.
+#[test]
+fn numeric_cond_name_ranges() {
+    CommonTestRunner::new("numeric_cond_name_ranges")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. NUMERIC-COND-NAME-RANGES-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-NUM PIC 9(2) COMP VALUE 0.
+        88 IS-VALID VALUE 1 THRU 9, 20 THRU 29.
+        88 IS-ZERO VALUE 0.
+
+PROCEDURE DIVISION.
+    PERFORM CHECK-PARA.
+    MOVE 5 TO WS-NUM.
+    PERFORM CHECK-PARA.
+    MOVE 15 TO WS-NUM.
+    PERFORM CHECK-PARA.
+    MOVE 29 TO WS-NUM.
+    PERFORM CHECK-PARA.
+    SET IS-VALID TO TRUE.
+    DISPLAY WS-NUM.
+STOP RUN.
+
+    CHECK-PARA.
+    IF IS-VALID THEN
+        DISPLAY "Valid"
+    ELSE
+        IF IS-ZERO THEN
+            DISPLAY "Zero"
+        ELSE
+            DISPLAY "Invalid"
+        END-IF
+    END-IF.
+        "#)
+        .expect_output("Zero\nValid\nInvalid\nValid\n1\n")
+        .run();
+}
+
+/// Tests that condition names declared for table elements are subscripted like their item.
+#[test]
+fn table_cond_names() {
+    CommonTestRunner::new("table_cond_names")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. TABLE-COND-NAMES-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-FLAG PIC X(1) VALUE "N" OCCURS 3 TIMES.
+            88 FLAG-SET VALUE "Y".
+
+PROCEDURE DIVISION.
+    SET FLAG-SET(2) TO TRUE.
+    DISPLAY WS-TABLE.
+    IF FLAG-SET(2) THEN
+        DISPLAY "Set"
+    END-IF.
+    IF FLAG-SET(3) THEN
+        DISPLAY "Wrong"
+    END-IF.
+STOP RUN.
+        "#)
+        .expect_output("NYN\nSet\n")
+        .run();
+}
+
+/// Tests that condition name values must fit the layout of their item.
+#[test]
+fn invalid_cond_name_value() {
+    CommonTestRunner::new("invalid_cond_name_value")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INVALID-COND-NAME-VALUE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-STATUS PIC X(1).
+        88 IS-ACTIVE VALUE "ACTIVE".
+
+PROCEDURE DIVISION.
+    DISPLAY WS-STATUS.
+STOP RUN.
+        "#)
+        .expect_fail(Some("does not fit the data layout"))
+        .run();
+}
@@ -40,7 +40,7 @@ PROCEDURE DIVISION.
     C-PARA.
     DISPLAY "World".
         "#)
-        .expect_output("HelloCOBOLWorld\n\n\n")
+        .expect_output("Hello\nCOBOL\nWorld\n")
         .run();
 }
 
@@ -64,7 +64,7 @@ PROCEDURE DIVISION.
     DISPLAY "Hello".
     ADD 1 TO LIMIT-VAL.
         "#)
-        .expect_output("HelloHelloHello\n\n\n")
+        .expect_output("Hello\nHello\nHello\n")
         .run();
 }
 
@@ -83,6 +83,390 @@ PROCEDURE DIVISION.
     TEST-PARA.
     DISPLAY "Hello".
         "#)
-        .expect_output("HelloHelloHello\n\n\n")
+        .expect_output("Hello\nHello\nHello\n")
         .run();
-}
\ No newline at end of file
+}
+/// Tests performing a paragraph while varying an item until a condition is met.
+#[test]
+fn perform_varying() {
+    CommonTestRunner::new("perform_varying")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-VARYING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-IDX PIC 9(2) COMP.
+    01 WS-LIMIT PIC 9(2) COMP VALUE 7.
+    01 WS-STEP PIC 9(2) COMP VALUE 3.
+
+PROCEDURE DIVISION.
+    PERFORM SHOW-IDX VARYING WS-IDX FROM 1 BY WS-STEP UNTIL WS-IDX > WS-LIMIT.
+    DISPLAY "End " WS-IDX.
+    PERFORM SHOW-IDX VARYING WS-IDX FROM 9 BY 1 UNTIL WS-IDX > WS-LIMIT.
+    STOP RUN.
+
+    SHOW-IDX.
+    DISPLAY WS-IDX.
+        "#)
+        .expect_output("1\n4\n7\nEnd 10\n")
+        .run();
+}
+
+/// Tests that testing after the body performs a varying paragraph at least once.
+#[test]
+fn perform_varying_test_after() {
+    CommonTestRunner::new("perform_varying_test_after")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-VARYING-TEST-AFTER-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-IDX PIC S9(2)V9.
+
+PROCEDURE DIVISION.
+    PERFORM SHOW-IDX WITH TEST AFTER VARYING WS-IDX FROM 5 BY 1 UNTIL WS-IDX > 0.
+    PERFORM SHOW-IDX TEST AFTER VARYING WS-IDX FROM 1 BY -0.5 UNTIL WS-IDX < 0.
+    STOP RUN.
+
+    SHOW-IDX.
+    DISPLAY WS-IDX.
+        "#)
+        .expect_output("+05.0\n+01.0\n+00.5\n+00.0\n-00.5\n")
+        .run();
+}
+
+/// Tests that nested "AFTER" loops are run to completion for each step of the outer loop.
+#[test]
+fn perform_varying_after() {
+    CommonTestRunner::new("perform_varying_after")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-VARYING-AFTER-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-I PIC 9 COMP.
+    01 WS-J PIC 9 COMP.
+    01 WS-K PIC 9 COMP.
+
+PROCEDURE DIVISION.
+    PERFORM SHOW-IJ VARYING WS-I FROM 1 BY 1 UNTIL WS-I > 2
+        AFTER WS-J FROM 1 BY 1 UNTIL WS-J > 3.
+    PERFORM SHOW-IJK WITH TEST AFTER VARYING WS-I FROM 1 BY 1 UNTIL WS-I = 2
+        AFTER WS-J FROM 1 BY 1 UNTIL WS-J = 2 AFTER WS-K FROM 5 BY 2 UNTIL WS-K > 6.
+    STOP RUN.
+
+    SHOW-IJ.
+    DISPLAY WS-I "," WS-J.
+
+    SHOW-IJK.
+    DISPLAY WS-I "," WS-J "," WS-K.
+        "#)
+        .expect_output("1,1\n1,2\n1,3\n2,1\n2,2\n2,3\n1,1,5\n1,1,7\n1,2,5\n1,2,7\n2,1,5\n2,1,7\n2,2,5\n2,2,7\n")
+        .run();
+}
+
+/// Tests that a table can be processed by varying an index.
+#[test]
+fn perform_varying_table() {
+    CommonTestRunner::new("perform_varying_table")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-VARYING-TABLE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TBL.
+        05 WS-ENTRY PIC 9(2) OCCURS 4 TIMES INDEXED BY WS-TBL-IDX.
+    01 WS-TOTAL PIC 9(3) VALUE 0.
+
+PROCEDURE DIVISION.
+    PERFORM FILL-ENTRY VARYING WS-TBL-IDX FROM 1 BY 1 UNTIL WS-TBL-IDX > 4.
+    DISPLAY WS-TBL " " WS-TOTAL.
+    STOP RUN.
+
+    FILL-ENTRY.
+    MOVE WS-TBL-IDX TO WS-ENTRY(WS-TBL-IDX).
+    COMPUTE WS-ENTRY(WS-TBL-IDX) = WS-ENTRY(WS-TBL-IDX) * 10.
+    ADD WS-ENTRY(WS-TBL-IDX) TO WS-TOTAL.
+        "#)
+        .expect_output("10203040 100\n")
+        .run();
+}
+
+/// Tests that the item varied by a PERFORM statement must be numeric.
+#[test]
+fn perform_varying_string() {
+    CommonTestRunner::new("perform_varying_string")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-VARYING-STRING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-STR PIC X(2).
+
+PROCEDURE DIVISION.
+    PERFORM SHOW-STR VARYING WS-STR FROM 1 BY 1 UNTIL WS-STR = "10".
+    STOP RUN.
+
+    SHOW-STR.
+    DISPLAY WS-STR.
+        "#)
+        .expect_fail(Some("must be numeric"))
+        .run();
+}
+
+/// Tests performing inline statements until a condition is met, and a set number of times.
+#[test]
+fn perform_inline() {
+    CommonTestRunner::new("perform_inline")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-INLINE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-COUNT PIC 9(2) VALUE 0.
+    01 WS-TIMES PIC 9 VALUE 2.
+
+PROCEDURE DIVISION.
+    PERFORM UNTIL WS-COUNT >= 3
+        ADD 1 TO WS-COUNT
+        DISPLAY "Count " WS-COUNT
+    END-PERFORM.
+    PERFORM WITH TEST AFTER UNTIL WS-COUNT > 0
+        DISPLAY "Once"
+    END-PERFORM.
+    PERFORM 2 TIMES
+        DISPLAY "Literal"
+    END-PERFORM.
+    PERFORM WS-TIMES TIMES
+        DISPLAY "Variable"
+    END-PERFORM.
+    PERFORM
+        DISPLAY "Single"
+    END-PERFORM.
+    STOP RUN.
+        "#)
+        .expect_output("Count 01\nCount 02\nCount 03\nOnce\nLiteral\nLiteral\nVariable\nVariable\nSingle\n")
+        .run();
+}
+
+/// Tests nested inline PERFORM VARYING statements.
+#[test]
+fn perform_inline_varying() {
+    CommonTestRunner::new("perform_inline_varying")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-INLINE-VARYING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-I PIC 9 COMP.
+    01 WS-J PIC 9 COMP.
+
+PROCEDURE DIVISION.
+    PERFORM VARYING WS-I FROM 1 BY 1 UNTIL WS-I > 2
+        PERFORM VARYING WS-J FROM 1 BY 1 UNTIL WS-J > WS-I
+            DISPLAY WS-I "," WS-J
+        END-PERFORM
+    END-PERFORM.
+    STOP RUN.
+        "#)
+        .expect_output("1,1\n2,1\n2,2\n")
+        .run();
+}
+
+/// Tests leaving an inline PERFORM early, and skipping to its next iteration.
+#[test]
+fn perform_inline_exit() {
+    CommonTestRunner::new("perform_inline_exit")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-INLINE-EXIT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-I PIC 9(2) COMP.
+    01 WS-J PIC 9(2) COMP.
+
+PROCEDURE DIVISION.
+    PERFORM VARYING WS-I FROM 1 BY 1 UNTIL WS-I > 10
+        IF WS-I = 2 THEN
+            EXIT PERFORM CYCLE
+        END-IF
+        IF WS-I = 4 THEN
+            EXIT PERFORM
+        END-IF
+        PERFORM 3 TIMES
+            EXIT PERFORM
+        END-PERFORM
+        DISPLAY WS-I
+    END-PERFORM.
+    DISPLAY "End " WS-I.
+    STOP RUN.
+        "#)
+        .expect_output("1\n3\nEnd 4\n")
+        .run();
+}
+
+/// Tests that EXIT PERFORM cannot be used outside of an inline PERFORM statement.
+#[test]
+fn perform_exit_outside_inline() {
+    CommonTestRunner::new("perform_exit_outside_inline")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-EXIT-OUTSIDE-INLINE-TEST.
+
+PROCEDURE DIVISION.
+    PERFORM TEST-PARA.
+    STOP RUN.
+
+    TEST-PARA.
+    EXIT PERFORM.
+        "#)
+        .expect_fail(Some("must be within an inline PERFORM"))
+        .run();
+}
+
+/// Tests transferring control to a later paragraph with GO TO.
+#[test]
+fn go_to() {
+    CommonTestRunner::new("go_to")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GO-TO-TEST.
+
+PROCEDURE DIVISION.
+    DISPLAY "Start".
+    GO TO C-PARA.
+
+    B-PARA.
+    DISPLAY "Skipped".
+
+    C-PARA.
+    DISPLAY "End".
+    STOP RUN.
+        "#)
+        .expect_output("Start\nEnd\n")
+        .run();
+}
+
+/// Tests selecting a paragraph with GO TO DEPENDING ON, continuing when the value is out of range.
+#[test]
+fn go_to_depending() {
+    CommonTestRunner::new("go_to_depending")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GO-TO-DEPENDING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-IDX PIC 9 VALUE 0.
+
+PROCEDURE DIVISION.
+    GO TO SELECT-PARA.
+
+    SELECT-PARA.
+    ADD 1 TO WS-IDX.
+    GO TO ONE-PARA TWO-PARA THREE-PARA DEPENDING ON WS-IDX.
+    DISPLAY "Other".
+    STOP RUN.
+
+    ONE-PARA.
+    DISPLAY "One".
+    GO TO END-PARA.
+
+    TWO-PARA.
+    DISPLAY "Two".
+    GO TO END-PARA.
+
+    THREE-PARA.
+    DISPLAY "Three".
+
+    END-PARA.
+    GO TO SELECT-PARA.
+        "#)
+        .expect_output("One\nTwo\nThree\nOther\n")
+        .run();
+}
+
+/// Tests that GO TO within a PERFORM THRU range returns once the end paragraph completes.
+#[test]
+fn go_to_perform_thru() {
+    CommonTestRunner::new("go_to_perform_thru")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GO-TO-PERFORM-THRU-TEST.
+
+PROCEDURE DIVISION.
+    PERFORM A-PARA THRU C-PARA.
+    DISPLAY "Done".
+    STOP RUN.
+
+    A-PARA.
+    DISPLAY "A".
+    GO TO C-PARA.
+
+    B-PARA.
+    DISPLAY "B".
+
+    C-PARA.
+    DISPLAY "C".
+        "#)
+        .expect_output("A\nC\nDone\n")
+        .run();
+}
+
+/// Tests that looping with GO TO does not grow the stack.
+#[test]
+fn go_to_loop() {
+    CommonTestRunner::new("go_to_loop")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GO-TO-LOOP-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-COUNT PIC 9(8) COMP VALUE 0.
+    01 WS-TOTAL PIC 9(8) COMP VALUE 0.
+
+PROCEDURE DIVISION.
+    GO TO LOOP-PARA.
+
+    INC-PARA.
+    ADD 2 TO WS-TOTAL.
+
+    LOOP-PARA.
+    ADD 1 TO WS-COUNT.
+    PERFORM INC-PARA.
+    IF WS-COUNT < 1000000 THEN
+        GO TO LOOP-PARA
+    END-IF.
+
+    END-PARA.
+    DISPLAY WS-COUNT " " WS-TOTAL.
+    STOP RUN.
+        "#)
+        .expect_output("1000000 2000000\n")
+        .run();
+}
+
+/// Tests that GO TO cannot transfer control to a paragraph which does not exist.
+#[test]
+fn go_to_unknown_para() {
+    CommonTestRunner::new("go_to_unknown_para")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GO-TO-UNKNOWN-PARA-TEST.
+
+PROCEDURE DIVISION.
+    GO TO MISSING-PARA.
+    STOP RUN.
+        "#)
+        .expect_fail(Some("No paragraph with the name 'MISSING-PARA' exists."))
+        .run();
+}
@@ -0,0 +1,172 @@
+use super::common::CommonTestRunner;
+
+/// Tests that a corresponding move copies only the items sharing a name, regardless of order.
+#[test]
+fn move_corresponding() {
+    CommonTestRunner::new("move_corresponding")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MOVE-CORRESPONDING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 IN-REC.
+        05 CUST-ID PIC 9(4) VALUE 42.
+        05 CUST-NAME PIC X(5) VALUE "Alice".
+        05 BALANCE PIC S9(4)V99 VALUE -12.5.
+        05 IN-ONLY PIC X(3) VALUE "XYZ".
+    01 OUT-REC.
+        05 BALANCE PIC -(4)9.99.
+        05 CUST-NAME PIC X(7) VALUE "???????".
+        05 OUT-ONLY PIC X(3) VALUE "ABC".
+        05 CUST-ID PIC 9(6).
+
+PROCEDURE DIVISION.
+    MOVE CORRESPONDING IN-REC TO OUT-REC.
+    DISPLAY "[" BALANCE OF OUT-REC "][" CUST-NAME OF OUT-REC "][" OUT-ONLY "][" CUST-ID OF OUT-REC "]".
+STOP RUN.
+        "#)
+        .expect_output("[  -12.50][Alice  ][ABC][000042]\n")
+        .run();
+}
+
+/// Tests that corresponding moves descend into subordinate groups sharing a name, and that a
+/// group may correspond to an elementary item.
+#[test]
+fn move_corresponding_nested() {
+    CommonTestRunner::new("move_corresponding_nested")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MOVE-CORRESPONDING-NESTED-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 SRC-REC.
+        05 ADDR.
+            10 CITY PIC X(6) VALUE "London".
+            10 STREET PIC X(4) VALUE "Main".
+        05 PHONE.
+            10 AREA-CODE PIC 9(3) VALUE 20.
+            10 LOCAL-NUM PIC 9(4) VALUE 1234.
+    01 DEST-REC.
+        05 ADDR.
+            10 STREET PIC X(4).
+            10 CITY PIC X(6).
+        05 PHONE PIC X(7).
+
+PROCEDURE DIVISION.
+    MOVE CORR SRC-REC TO DEST-REC.
+    DISPLAY CITY OF DEST-REC " " STREET OF DEST-REC " " PHONE OF DEST-REC.
+STOP RUN.
+        "#)
+        .expect_output("London Main 0201234\n")
+        .run();
+}
+
+/// Tests that items with a REDEFINES or OCCURS clause are excluded from corresponding moves.
+#[test]
+fn move_corresponding_excluded() {
+    CommonTestRunner::new("move_corresponding_excluded")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MOVE-CORRESPONDING-EXCLUDED-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 SRC-REC.
+        05 CODE-A PIC X(2) VALUE "AB".
+        05 CODE-B REDEFINES CODE-A PIC X(2).
+        05 CODE-C PIC X(1) OCCURS 2 TIMES VALUE "C".
+    01 DEST-REC.
+        05 CODE-A PIC X(2) VALUE "..".
+        05 CODE-B PIC X(2) VALUE "..".
+        05 CODE-C PIC X(1) OCCURS 2 TIMES VALUE ".".
+
+PROCEDURE DIVISION.
+    MOVE CORRESPONDING SRC-REC TO DEST-REC.
+    DISPLAY DEST-REC.
+STOP RUN.
+        "#)
+        .expect_output("AB....\n")
+        .run();
+}
+
+/// Tests that corresponding additions and subtractions apply to each pair of numeric items.
+#[test]
+fn add_subtract_corresponding() {
+    CommonTestRunner::new("add_subtract_corresponding")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ADD-SUBTRACT-CORRESPONDING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 DAY-TOTALS.
+        05 SALES PIC 9(4)V99 VALUE 10.25.
+        05 REFUNDS PIC 9(3) VALUE 3.
+        05 STORE-NAME PIC X(4) VALUE "Main".
+    01 RUNNING-TOTALS.
+        05 REFUNDS PIC 9(3) VALUE 7.
+        05 SALES PIC 9(4)V9 VALUE 100.
+        05 STORE-NAME PIC X(4) VALUE "None".
+
+PROCEDURE DIVISION.
+    ADD CORRESPONDING DAY-TOTALS TO RUNNING-TOTALS ROUNDED.
+    DISPLAY SALES OF RUNNING-TOTALS " " REFUNDS OF RUNNING-TOTALS " " STORE-NAME OF RUNNING-TOTALS.
+    SUBTRACT CORR DAY-TOTALS FROM RUNNING-TOTALS.
+    DISPLAY SALES OF RUNNING-TOTALS " " REFUNDS OF RUNNING-TOTALS.
+STOP RUN.
+        "#)
+        .expect_output("0110.3 010 None\n0100.0 007\n")
+        .run();
+}
+
+/// Tests that size error phrases apply across all corresponding items of an addition.
+#[test]
+fn add_corresponding_size_error() {
+    CommonTestRunner::new("add_corresponding_size_error")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ADD-CORRESPONDING-SIZE-ERROR-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 SRC-REC.
+        05 FIELD-A PIC 9(2) VALUE 50.
+        05 FIELD-B PIC 9(2) VALUE 1.
+    01 DEST-REC.
+        05 FIELD-A PIC 9(2) VALUE 60.
+        05 FIELD-B PIC 9(2) VALUE 1.
+
+PROCEDURE DIVISION.
+    ADD CORRESPONDING SRC-REC TO DEST-REC
+        ON SIZE ERROR DISPLAY "Overflow"
+    END-ADD.
+    DISPLAY FIELD-A OF DEST-REC " " FIELD-B OF DEST-REC.
+STOP RUN.
+        "#)
+        .expect_output("Overflow\n60 02\n")
+        .run();
+}
+
+/// Tests that the operands of a corresponding move must be group items.
+#[test]
+fn move_corresponding_elementary() {
+    CommonTestRunner::new("move_corresponding_elementary")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MOVE-CORRESPONDING-ELEMENTARY-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 SRC-VAL PIC X(2).
+    01 DEST-REC.
+        05 SRC-VAL PIC X(2).
+
+PROCEDURE DIVISION.
+    MOVE CORRESPONDING SRC-VAL TO DEST-REC.
+STOP RUN.
+        "#)
+        .expect_fail(Some("must be group items"))
+        .run();
+}
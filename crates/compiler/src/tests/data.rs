@@ -90,7 +90,7 @@ PROGRAM-ID. FLT-INITIAL-VALUE-TEST.
 
 DATA DIVISION.
     WORKING-STORAGE SECTION.
-    01 TEST-VAL PIC S9(4)P9(4) COMP VALUE -9004.2.
+    01 TEST-VAL PIC S9(4)V9(4) COMP VALUE -9004.2.
 
 PROCEDURE DIVISION.
     DISPLAY TEST-VAL.
@@ -0,0 +1,142 @@
+use super::common::CommonTestRunner;
+
+/// Tests that numeric items are stored as DISPLAY by default, with one digit per byte.
+#[test]
+fn display_default_usage() {
+    CommonTestRunner::new("display_default_usage")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DISPLAY-DEFAULT-USAGE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-DATE PIC 9(8) VALUE 20240131.
+        05 WS-DATE-X REDEFINES WS-DATE PIC X(8).
+    01 WS-COUNT PIC 9(4) USAGE IS DISPLAY.
+    01 WS-PRICE PIC 9(3)V99 VALUE 12.5.
+
+PROCEDURE DIVISION.
+    DISPLAY WS-DATE-X.
+    MOVE "19991231" TO WS-DATE-X.
+    DISPLAY WS-DATE.
+    DISPLAY WS-COUNT.
+    DISPLAY WS-PRICE.
+STOP RUN.
+        "#)
+        .expect_output("20240131\n19991231\n0000\n012.50\n")
+        .run();
+}
+
+/// Tests the storage of signs within DISPLAY numeric items.
+#[test]
+fn display_sign_positions() {
+    CommonTestRunner::new("display_sign_positions")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DISPLAY-SIGN-POSITIONS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TRAILING PIC S9(3) VALUE -123.
+    01 WS-TRAILING-X REDEFINES WS-TRAILING PIC X(3).
+    01 WS-LEADING PIC S9(3) SIGN IS LEADING VALUE 456.
+    01 WS-LEADING-X REDEFINES WS-LEADING PIC X(3).
+    01 WS-SEP PIC S9(3) SIGN TRAILING SEPARATE CHARACTER VALUE -7.
+    01 WS-SEP-X REDEFINES WS-SEP PIC X(4).
+    01 WS-LEAD-SEP PIC S9(3) LEADING SEPARATE VALUE 89.
+    01 WS-LEAD-SEP-X REDEFINES WS-LEAD-SEP PIC X(4).
+
+PROCEDURE DIVISION.
+    DISPLAY WS-TRAILING-X " " WS-TRAILING.
+    DISPLAY WS-LEADING-X " " WS-LEADING.
+    DISPLAY WS-SEP-X " " WS-SEP.
+    DISPLAY WS-LEAD-SEP-X " " WS-LEAD-SEP.
+    MOVE "12}" TO WS-TRAILING-X.
+    DISPLAY WS-TRAILING.
+STOP RUN.
+        "#)
+        .expect_output("12L -123\nD56 +456\n007- -007\n+089 +089\n-120\n")
+        .run();
+}
+
+/// Tests arithmetic and moves between DISPLAY and COMP numeric items.
+#[test]
+fn display_arithmetic() {
+    CommonTestRunner::new("display_arithmetic")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DISPLAY-ARITHMETIC-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-A PIC S9(4) VALUE 25.
+    01 WS-B PIC 9(4) COMP VALUE 30.
+    01 WS-C PIC S9(4).
+    01 WS-D PIC S9(3)V99 VALUE 1.25.
+    01 WS-E PIC 9(2).
+
+PROCEDURE DIVISION.
+    ADD WS-A TO WS-B.
+    DISPLAY WS-B.
+    SUBTRACT WS-B FROM WS-A GIVING WS-C.
+    DISPLAY WS-C.
+    MULTIPLY 3 BY WS-C.
+    DISPLAY WS-C.
+    ADD 2.5 TO WS-D.
+    DISPLAY WS-D.
+    MOVE WS-C TO WS-D.
+    DISPLAY WS-D.
+    MOVE 1234 TO WS-B.
+    MOVE WS-B TO WS-E.
+    DISPLAY WS-E.
+STOP RUN.
+        "#)
+        .expect_output("55\n-0030\n-0090\n+003.75\n-090.00\n34\n")
+        .run();
+}
+
+/// Tests that a SIGN clause cannot be used on an unsigned item.
+#[test]
+fn display_sign_unsigned() {
+    CommonTestRunner::new("display_sign_unsigned")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DISPLAY-SIGN-UNSIGNED-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-VAL PIC 9(3) SIGN IS LEADING SEPARATE.
+
+PROCEDURE DIVISION.
+    DISPLAY WS-VAL.
+STOP RUN.
+        "#)
+        .expect_fail(Some("cannot have a SIGN clause"))
+        .run();
+}
+
+/// Tests that DISPLAY numeric items are moved into alphanumeric items as their unsigned digits.
+#[test]
+fn display_move_to_alphanumeric() {
+    CommonTestRunner::new("display_move_to_alphanumeric")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DISPLAY-MOVE-TO-ALPHANUMERIC-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-NUM PIC S9(5)V99 VALUE -123.45.
+    01 WS-SEP PIC S9(3) SIGN IS LEADING SEPARATE VALUE -42.
+    01 WS-STR PIC X(8).
+
+PROCEDURE DIVISION.
+    MOVE WS-NUM TO WS-STR.
+    DISPLAY "[" WS-STR "]".
+    MOVE WS-SEP TO WS-STR.
+    DISPLAY "[" WS-STR "]".
+STOP RUN.
+        "#)
+        .expect_output("[0012345 ]\n[042     ]\n")
+        .run();
+}
@@ -0,0 +1,175 @@
+use super::common::CommonTestRunner;
+
+/// Tests that an EVALUATE on an integer subject selects the first matching branch, falling back
+/// to "WHEN OTHER" when no branch matches.
+#[test]
+fn evaluate_int_subject() {
+    CommonTestRunner::new("evaluate_int_subject")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EVALUATE-INT-SUBJECT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-CODE PIC S9(3) COMP VALUE -1.
+
+PROCEDURE DIVISION.
+    PERFORM CHECK-CODE.
+    MOVE 1 TO WS-CODE.
+    PERFORM CHECK-CODE.
+    MOVE 3 TO WS-CODE.
+    PERFORM CHECK-CODE.
+    MOVE 5 TO WS-CODE.
+    PERFORM CHECK-CODE.
+    MOVE 7 TO WS-CODE.
+    PERFORM CHECK-CODE.
+    MOVE 99 TO WS-CODE.
+    PERFORM CHECK-CODE.
+    STOP RUN.
+
+CHECK-CODE.
+    EVALUATE WS-CODE
+        WHEN -1
+            DISPLAY "Negative"
+        WHEN 1
+        WHEN 2
+            DISPLAY "Low"
+        WHEN 3 THRU 6
+            DISPLAY "Mid"
+        WHEN 5
+            DISPLAY "Unreachable"
+        WHEN 7 THROUGH 7
+            DISPLAY "Seven"
+        WHEN OTHER
+            DISPLAY "Other"
+    END-EVALUATE.
+        "#)
+        .expect_output("Negative\nLow\nMid\nMid\nSeven\nOther\n")
+        .run();
+}
+
+/// Tests that an EVALUATE with a "TRUE" subject selects the first branch whose condition holds.
+#[test]
+fn evaluate_true() {
+    CommonTestRunner::new("evaluate_true")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EVALUATE-TRUE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TEMP PIC 9(3) VALUE 15.
+    01 WS-STATUS PIC X(1) VALUE "A".
+        88 IS-ACTIVE VALUE "A".
+
+PROCEDURE DIVISION.
+    EVALUATE TRUE
+        WHEN WS-TEMP > 30
+            DISPLAY "Hot"
+        WHEN WS-TEMP > 10 AND IS-ACTIVE
+            DISPLAY "Warm and active"
+        WHEN OTHER
+            DISPLAY "Cold"
+    END-EVALUATE.
+    EVALUATE FALSE
+        WHEN IS-ACTIVE
+            DISPLAY "Inactive"
+        WHEN WS-TEMP * 2 = 30
+            DISPLAY "Not double"
+        WHEN OTHER
+            DISPLAY "Neither"
+    END-EVALUATE.
+STOP RUN.
+        "#)
+        .expect_output("Warm and active\nNeither\n")
+        .run();
+}
+
+/// Tests that multiple subjects are matched using "ALSO", "ANY", "NOT" and ranges.
+#[test]
+fn evaluate_also() {
+    CommonTestRunner::new("evaluate_also")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EVALUATE-ALSO-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-REGION PIC X(2) VALUE "EU".
+    01 WS-AMOUNT PIC 9(4)V99 VALUE 150.5.
+    01 WS-IDX PIC 9 VALUE 0.
+
+PROCEDURE DIVISION.
+    PERFORM CHECK-ORDER.
+    MOVE 2000 TO WS-AMOUNT.
+    PERFORM CHECK-ORDER.
+    MOVE "US" TO WS-REGION.
+    PERFORM CHECK-ORDER.
+    MOVE 10 TO WS-AMOUNT.
+    PERFORM CHECK-ORDER.
+    STOP RUN.
+
+CHECK-ORDER.
+    EVALUATE WS-REGION ALSO WS-AMOUNT + 0.5 ALSO TRUE
+        WHEN "EU" ALSO 0 THRU 1000 ALSO ANY
+            DISPLAY "Small EU order"
+        WHEN "EU" ALSO ANY ALSO WS-AMOUNT > 1000
+            DISPLAY "Large EU order"
+        WHEN NOT "EU" ALSO NOT 10.5 ALSO ANY
+            DISPLAY "Foreign order"
+        WHEN ANY ALSO ANY ALSO ANY
+            DISPLAY "Small foreign order"
+    END-EVALUATE.
+        "#)
+        .expect_output("Small EU order\nLarge EU order\nForeign order\nSmall foreign order\n")
+        .run();
+}
+
+/// Tests that an EVALUATE with no branches selected and no "WHEN OTHER" does nothing.
+#[test]
+fn evaluate_no_match() {
+    CommonTestRunner::new("evaluate_no_match")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EVALUATE-NO-MATCH-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-CODE PIC 9(2) VALUE 40.
+
+PROCEDURE DIVISION.
+    EVALUATE WS-CODE
+        WHEN 1 THRU 10
+            DISPLAY "Low"
+        WHEN 20
+            DISPLAY "Twenty"
+    END-EVALUATE.
+    DISPLAY "Done".
+STOP RUN.
+        "#)
+        .expect_output("Done\n")
+        .run();
+}
+
+/// Tests that each "WHEN" phrase must have one object for each subject.
+#[test]
+fn evaluate_object_count() {
+    CommonTestRunner::new("evaluate_object_count")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EVALUATE-OBJECT-COUNT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-CODE PIC 9(2) VALUE 40.
+
+PROCEDURE DIVISION.
+    EVALUATE WS-CODE
+        WHEN 1 ALSO 2
+            DISPLAY "Low"
+    END-EVALUATE.
+STOP RUN.
+        "#)
+        .expect_fail(Some("exactly one object for each subject"))
+        .run();
+}
@@ -0,0 +1,225 @@
+use super::common::CommonTestRunner;
+
+/// Tests that moving a figurative constant fills every byte of the destination.
+#[test]
+fn figurative_move_fill() {
+    CommonTestRunner::new("figurative_move_fill")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-MOVE-FILL-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-NAME PIC X(30) VALUE "JOHN SMITH".
+    01 WS-CODE PIC X(5).
+
+PROCEDURE DIVISION.
+    MOVE SPACES TO WS-NAME.
+    DISPLAY "[" WS-NAME "]".
+    MOVE ALL "AB" TO WS-CODE.
+    DISPLAY "[" WS-CODE "]".
+    MOVE ZEROS TO WS-CODE.
+    DISPLAY "[" WS-CODE "]".
+    MOVE QUOTES TO WS-CODE.
+    DISPLAY "[" WS-CODE "]".
+    MOVE ALL SPACE TO WS-CODE.
+    DISPLAY "[" WS-CODE "]".
+STOP RUN.
+        "#)
+        .expect_output("[                              ]\n[ABABA]\n[00000]\n[\"\"\"\"\"]\n[     ]\n")
+        .run();
+}
+
+/// Tests that "ZERO" is moved as the number zero into numeric and numeric-edited items.
+#[test]
+fn figurative_move_zero_numeric() {
+    CommonTestRunner::new("figurative_move_zero_numeric")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-MOVE-ZERO-NUMERIC-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-DISPLAY PIC S9(3)V9 VALUE -12.5.
+    01 WS-COMP PIC 9(4) COMP VALUE 1234.
+    01 WS-PACKED PIC 9(3) COMP-3 VALUE 999.
+    01 WS-EDITED PIC ZZ9.99.
+
+PROCEDURE DIVISION.
+    MOVE ZERO TO WS-DISPLAY.
+    MOVE ZEROES TO WS-COMP.
+    MOVE ZEROS TO WS-PACKED.
+    MOVE ZERO TO WS-EDITED.
+    DISPLAY WS-DISPLAY " " WS-COMP " " WS-PACKED " [" WS-EDITED "]".
+STOP RUN.
+        "#)
+        .expect_output("+000.0 0 000 [  0.00]\n")
+        .run();
+}
+
+/// Tests that figurative constants can be used as the initial value of an item.
+#[test]
+fn figurative_value_clause() {
+    CommonTestRunner::new("figurative_value_clause")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-VALUE-CLAUSE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-BLANK PIC X(4) VALUE SPACES.
+    01 WS-STARS PIC X(6) VALUE ALL "*-".
+    01 WS-ZEROES PIC X(3) VALUE ZEROES.
+    01 WS-COUNT PIC 9(3) VALUE ZERO.
+    01 WS-TOTAL PIC 9(3) COMP VALUE ZERO.
+
+PROCEDURE DIVISION.
+    DISPLAY "[" WS-BLANK "][" WS-STARS "][" WS-ZEROES "]".
+    DISPLAY WS-COUNT " " WS-TOTAL.
+STOP RUN.
+        "#)
+        .expect_output("[    ][*-*-*-][000]\n000 0\n")
+        .run();
+}
+
+/// Tests that displaying a figurative constant displays a single repetition of it.
+#[test]
+fn figurative_display() {
+    CommonTestRunner::new("figurative_display")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-DISPLAY-TEST.
+
+PROCEDURE DIVISION.
+    DISPLAY "[" SPACE "][" ZERO "][" QUOTE "][" ALL "XY" "]".
+STOP RUN.
+        "#)
+        .expect_output("[ ][0][\"][XY]\n")
+        .run();
+}
+
+/// Tests that figurative constants are compared as if filled to the length of the other operand.
+#[test]
+fn figurative_conditions() {
+    CommonTestRunner::new("figurative_conditions")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-CONDITIONS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-NAME PIC X(10) VALUE SPACES.
+    01 WS-FLAGS PIC X(4) VALUE ALL "YN".
+    01 WS-COUNT PIC 9(3) VALUE 0.
+    01 WS-MARK PIC X(2) VALUE HIGH-VALUES.
+    01 WS-EMPTY PIC X(2) VALUE LOW-VALUES.
+
+PROCEDURE DIVISION.
+    IF WS-NAME = SPACES
+        DISPLAY "Name is blank"
+    END-IF.
+    IF ALL "YN" = WS-FLAGS
+        DISPLAY "Flags alternate"
+    END-IF.
+    IF WS-COUNT = ZERO
+        DISPLAY "Count is zero"
+    END-IF.
+    IF WS-MARK = HIGH-VALUES AND WS-EMPTY = LOW-VALUES
+        DISPLAY "Marked"
+    END-IF.
+    MOVE "X" TO WS-NAME.
+    ADD 1 TO WS-COUNT.
+    IF NOT WS-NAME = SPACES AND WS-COUNT > ZERO
+        DISPLAY "Both changed"
+    END-IF.
+STOP RUN.
+        "#)
+        .expect_output("Name is blank\nFlags alternate\nCount is zero\nMarked\nBoth changed\n")
+        .run();
+}
+
+/// Tests that a figurative constant fills only the span of the destination it is moved into.
+#[test]
+fn figurative_move_span() {
+    CommonTestRunner::new("figurative_move_span")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-MOVE-SPAN-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TEXT PIC X(8) VALUE "ABCDEFGH".
+
+PROCEDURE DIVISION.
+    MOVE ALL "*" TO WS-TEXT(3:4).
+    DISPLAY WS-TEXT.
+STOP RUN.
+        "#)
+        .expect_output("AB****GH\n")
+        .run();
+}
+
+/// Tests that moving a figurative constant into a group fills the entire group.
+#[test]
+fn figurative_move_group() {
+    CommonTestRunner::new("figurative_move_group")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-MOVE-GROUP-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-FIRST PIC X(3) VALUE "ABC".
+        05 WS-SECOND PIC X(2) VALUE "DE".
+
+PROCEDURE DIVISION.
+    MOVE ALL "-" TO WS-RECORD.
+    DISPLAY "[" WS-FIRST "][" WS-SECOND "]".
+STOP RUN.
+        "#)
+        .expect_output("[---][--]\n")
+        .run();
+}
+
+/// Tests that figurative constants other than "ZERO" cannot be moved into numeric items.
+#[test]
+fn figurative_move_invalid() {
+    CommonTestRunner::new("figurative_move_invalid")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-MOVE-INVALID-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-COUNT PIC 9(3).
+
+PROCEDURE DIVISION.
+    MOVE SPACES TO WS-COUNT.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Attempted to move incompatible literal 'SPACES'"))
+        .run();
+}
+
+/// Tests that figurative constants other than "ZERO" cannot be compared to numeric items.
+#[test]
+fn figurative_compare_invalid() {
+    CommonTestRunner::new("figurative_compare_invalid")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. FIGURATIVE-COMPARE-INVALID-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-COUNT PIC 9(3).
+
+PROCEDURE DIVISION.
+    IF WS-COUNT = QUOTES
+        DISPLAY "Quoted"
+    END-IF.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Cannot compare a numeric value to the figurative constant 'QUOTES'."))
+        .run();
+}
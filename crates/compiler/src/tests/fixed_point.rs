@@ -0,0 +1,253 @@
+use super::common::CommonTestRunner;
+
+/// Tests that adding decimal values does not accumulate binary floating point errors.
+#[test]
+fn add_exact_decimals() {
+    CommonTestRunner::new("add_exact_decimals")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ADD-EXACT-DECIMALS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9V9 COMP VALUE 0.1.
+    01 B-VAL PIC 9V9 COMP VALUE 0.2.
+    01 C-VAL PIC 9V9 COMP.
+
+PROCEDURE DIVISION.
+    ADD A-VAL TO B-VAL GIVING C-VAL.
+    DISPLAY C-VAL.
+    IF C-VAL = 0.3 THEN
+        DISPLAY "EXACT"
+    END-IF.
+STOP RUN.
+        "#)
+        .expect_output("0.3\nEXACT\n")
+        .run();
+}
+
+/// Tests that a running monetary total remains exact over many additions.
+#[test]
+fn money_total() {
+    CommonTestRunner::new("money_total")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MONEY-TOTAL-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-PRICE PIC 9(4)V99 COMP VALUE 19.99.
+    01 WS-TOTAL PIC 9(8)V99 COMP VALUE 0.
+    01 WS-COUNT PIC 9(4) COMP VALUE 0.
+
+PROCEDURE DIVISION.
+    PERFORM ADD-PRICE UNTIL WS-COUNT = 1000.
+    DISPLAY WS-TOTAL.
+    STOP RUN.
+
+    ADD-PRICE.
+        ADD WS-PRICE TO WS-TOTAL.
+        ADD 1 TO WS-COUNT.
+        "#)
+        .expect_output("19990\n")
+        .run();
+}
+
+/// Tests that results are truncated to the decimal places of their destination.
+#[test]
+fn truncate_to_dest_scale() {
+    CommonTestRunner::new("truncate_to_dest_scale")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. TRUNCATE-DEST-SCALE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4)V99 COMP VALUE 12.34.
+    01 B-VAL PIC 9(4)V99 COMP VALUE 1.5.
+    01 C-VAL PIC 9(4)V9 COMP.
+    01 D-VAL PIC 9(4) COMP.
+
+PROCEDURE DIVISION.
+    MULTIPLY A-VAL BY B-VAL GIVING C-VAL.
+    MOVE A-VAL TO D-VAL.
+    DISPLAY C-VAL " " D-VAL.
+STOP RUN.
+        "#)
+        .expect_output("18.5 12\n")
+        .run();
+}
+
+/// Tests that dividing decimal values produces an exact result to the output's decimal places.
+#[test]
+fn divide_decimals() {
+    CommonTestRunner::new("divide_decimals")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DIVIDE-DECIMALS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4)V99 COMP VALUE 10.
+    01 B-VAL PIC 9V9 COMP VALUE 0.3.
+    01 C-VAL PIC 9(4)V9(4) COMP.
+
+PROCEDURE DIVISION.
+    DIVIDE A-VAL BY B-VAL GIVING C-VAL.
+    DISPLAY C-VAL.
+STOP RUN.
+        "#)
+        .expect_output("33.3333\n")
+        .run();
+}
+
+/// Tests that "ROUNDED" results are rounded to the nearest value, away from zero.
+#[test]
+fn rounded_default() {
+    CommonTestRunner::new("rounded_default")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ROUNDED-DEFAULT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC S9V999 COMP VALUE 2.345.
+    01 B-VAL PIC S9V999 COMP VALUE -2.345.
+    01 ONE-VAL PIC 9 COMP VALUE 1.
+    01 C-VAL PIC S9V99 COMP.
+    01 D-VAL PIC S9V99 COMP.
+    01 E-VAL PIC S9V99 COMP.
+
+PROCEDURE DIVISION.
+    MULTIPLY A-VAL BY ONE-VAL GIVING C-VAL ROUNDED.
+    MULTIPLY B-VAL BY ONE-VAL GIVING D-VAL ROUNDED.
+    MULTIPLY A-VAL BY ONE-VAL GIVING E-VAL.
+    DISPLAY C-VAL " " D-VAL " " E-VAL.
+STOP RUN.
+        "#)
+        .expect_output("2.35 -2.35 2.34\n")
+        .run();
+}
+
+/// Tests that banker's rounding rounds halfway values to the nearest even value.
+#[test]
+fn rounded_nearest_even() {
+    CommonTestRunner::new("rounded_nearest_even")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ROUNDED-NEAREST-EVEN-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC S9V999 COMP VALUE 2.345.
+    01 B-VAL PIC S9V999 COMP VALUE 2.355.
+    01 C-VAL PIC S9V999 COMP VALUE -2.345.
+    01 D-VAL PIC S9V999 COMP VALUE 2.346.
+    01 ZERO-VAL PIC 9 COMP VALUE 0.
+    01 W-VAL PIC S9V99 COMP.
+    01 X-VAL PIC S9V99 COMP.
+    01 Y-VAL PIC S9V99 COMP.
+    01 Z-VAL PIC S9V99 COMP.
+
+PROCEDURE DIVISION.
+    ADD A-VAL TO ZERO-VAL GIVING W-VAL ROUNDED MODE IS NEAREST-EVEN.
+    ADD B-VAL TO ZERO-VAL GIVING X-VAL ROUNDED MODE NEAREST-EVEN.
+    ADD C-VAL TO ZERO-VAL GIVING Y-VAL ROUNDED MODE IS NEAREST-EVEN.
+    ADD D-VAL TO ZERO-VAL GIVING Z-VAL ROUNDED MODE IS NEAREST-EVEN.
+    DISPLAY W-VAL " " X-VAL " " Y-VAL " " Z-VAL.
+STOP RUN.
+        "#)
+        .expect_output("2.34 2.36 -2.34 2.35\n")
+        .run();
+}
+
+/// Tests the truncation and away from zero rounding modes, applied to individual destinations.
+#[test]
+fn rounded_modes() {
+    CommonTestRunner::new("rounded_modes")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ROUNDED-MODES-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9V999 COMP VALUE 0.001.
+    01 B-VAL PIC 9V99 COMP VALUE 2.34.
+    01 C-VAL PIC 9V99 COMP VALUE 2.34.
+    01 D-VAL PIC 9V99 COMP VALUE 2.34.
+
+PROCEDURE DIVISION.
+    ADD A-VAL TO B-VAL ROUNDED MODE IS AWAY-FROM-ZERO C-VAL ROUNDED MODE IS TRUNCATION D-VAL ROUNDED.
+    DISPLAY B-VAL " " C-VAL " " D-VAL.
+STOP RUN.
+        "#)
+        .expect_output("2.35 2.34 2.34\n")
+        .run();
+}
+
+/// Tests that the quotient of a division can be rounded.
+#[test]
+fn divide_rounded() {
+    CommonTestRunner::new("divide_rounded")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DIVIDE-ROUNDED-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC S99 COMP VALUE 20.
+    01 B-VAL PIC S9 COMP VALUE -3.
+    01 C-VAL PIC S9V99 COMP.
+    01 D-VAL PIC S9(4) COMP VALUE 25.
+    01 E-VAL PIC 9 COMP VALUE 2.
+
+PROCEDURE DIVISION.
+    DIVIDE A-VAL BY B-VAL GIVING C-VAL ROUNDED.
+    DIVIDE E-VAL INTO D-VAL ROUNDED MODE IS NEAREST-EVEN.
+    DISPLAY C-VAL " " D-VAL.
+STOP RUN.
+        "#)
+        .expect_output("-6.67 12\n")
+        .run();
+}
+
+/// Tests that sources of a "GIVING" clause cannot be rounded.
+#[test]
+fn rounded_giving_source() {
+    CommonTestRunner::new("rounded_giving_source")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ROUNDED-GIVING-SOURCE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9V99 COMP VALUE 1.
+    01 B-VAL PIC 9V99 COMP VALUE 2.
+
+PROCEDURE DIVISION.
+    ADD A-VAL TO B-VAL ROUNDED GIVING A-VAL.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Only the receiving items of a 'GIVING' clause may be rounded."))
+        .run();
+}
+
+/// Tests that scaling positions ('P') within a PIC clause are rejected.
+#[test]
+fn scaling_position_unsupported() {
+    CommonTestRunner::new("scaling_position_unsupported")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SCALING-POSITION-UNSUPPORTED-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 99PP COMP.
+
+PROCEDURE DIVISION.
+    DISPLAY A-VAL.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Scaling positions ('P') are not supported within PIC clauses."))
+        .run();
+}
@@ -0,0 +1,201 @@
+use super::common::CommonTestRunner;
+
+/// Tests that elementary items within a group can be accessed individually.
+#[test]
+fn group_field_access() {
+    CommonTestRunner::new("group_field_access")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GROUP-FIELD-ACCESS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 CUSTOMER.
+        05 CUST-NAME PIC X(5) VALUE "Alice".
+        05 CUST-ADDR.
+            10 CUST-CITY PIC X(6) VALUE "London".
+            10 CUST-AGE PIC 9(2) COMP VALUE 42.
+
+PROCEDURE DIVISION.
+    DISPLAY CUST-NAME.
+    DISPLAY CUST-CITY.
+    ADD 1 TO CUST-AGE.
+    DISPLAY CUST-AGE.
+STOP RUN.
+        "#)
+        .expect_output("Alice\nLondon\n43\n")
+        .run();
+}
+
+/// Tests that displaying a group displays each of its elementary items in order.
+#[test]
+fn group_display() {
+    CommonTestRunner::new("group_display")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GROUP-DISPLAY-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 FULL-NAME.
+        05 FIRST-NAME PIC X(3) VALUE "Bob".
+        05 LAST-NAME PIC X(5) VALUE "Smith".
+
+PROCEDURE DIVISION.
+    DISPLAY FULL-NAME.
+STOP RUN.
+        "#)
+        .expect_output("BobSmith\n")
+        .run();
+}
+
+/// Tests that a group can be moved into another group as a single alphanumeric item.
+#[test]
+fn group_move() {
+    CommonTestRunner::new("group_move")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GROUP-MOVE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 SRC-GRP.
+        05 SRC-A PIC X(2) VALUE "AB".
+        05 SRC-B PIC X(2) VALUE "CD".
+    01 DEST-GRP.
+        05 DEST-A PIC X(3).
+        05 DEST-B PIC X(1).
+
+PROCEDURE DIVISION.
+    MOVE SRC-GRP TO DEST-GRP.
+    DISPLAY DEST-A.
+    DISPLAY DEST-B.
+STOP RUN.
+        "#)
+        .expect_output("ABC\nD\n")
+        .run();
+}
+
+/// Tests that alphanumeric items shorter than their value or source are padded with spaces.
+#[test]
+fn group_space_padding() {
+    CommonTestRunner::new("group_space_padding")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GROUP-SPACE-PADDING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 REC.
+        05 REC-TEXT PIC X(3) VALUE "XY".
+        05 REC-NUM PIC 9(2) VALUE 7.
+    01 STR-VAL PIC X(3) VALUE "AB".
+
+PROCEDURE DIVISION.
+    DISPLAY REC.
+    IF STR-VAL = "AB " THEN
+        DISPLAY "pass"
+    END-IF.
+    MOVE "Z" TO REC-TEXT.
+    DISPLAY REC.
+STOP RUN.
+        "#)
+        .expect_output("XY 07\npass\nZ  07\n")
+        .run();
+}
+
+/// Tests that subordinate items with mismatched level numbers are rejected.
+#[test]
+fn invalid_group_levels() {
+    CommonTestRunner::new("invalid_group_levels")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INV-GROUP-LEVELS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 TEST-GRP.
+        10 TEST-A PIC X(2).
+        05 TEST-B PIC X(2).
+
+PROCEDURE DIVISION.
+    DISPLAY TEST-GRP.
+STOP RUN.
+        "#)
+        .expect_fail(Some("does not match any enclosing level"))
+        .run();
+}
+
+/// Tests that elementary items cannot contain subordinate items.
+#[test]
+fn invalid_elementary_children() {
+    CommonTestRunner::new("invalid_elementary_children")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INV-ELEM-CHILDREN-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 TEST-GRP.
+        05 TEST-A PIC X(2).
+            10 TEST-B PIC X(2).
+
+PROCEDURE DIVISION.
+    DISPLAY TEST-GRP.
+STOP RUN.
+        "#)
+        .expect_fail(Some("cannot contain subordinate items"))
+        .run();
+}
+
+/// Tests that items sharing a name can be referenced by qualifying them with their groups.
+#[test]
+fn group_qualified_refs() {
+    CommonTestRunner::new("group_qualified_refs")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GROUP-QUALIFIED-REFS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 HOME-ADDR.
+        05 CITY PIC X(6) VALUE "London".
+        05 POST-CODE.
+            10 AREA-CODE PIC 9(2) VALUE 12.
+    01 WORK-ADDR.
+        05 CITY PIC X(5) VALUE "Paris".
+        05 POST-CODE.
+            10 AREA-CODE PIC 9(2) VALUE 34.
+
+PROCEDURE DIVISION.
+    DISPLAY CITY OF HOME-ADDR " " CITY IN WORK-ADDR.
+    ADD AREA-CODE OF POST-CODE OF HOME-ADDR TO AREA-CODE IN WORK-ADDR.
+    DISPLAY AREA-CODE OF WORK-ADDR.
+STOP RUN.
+        "#)
+        .expect_output("London Paris\n46\n")
+        .run();
+}
+
+/// Tests that an unqualified reference to a name shared by multiple items is rejected.
+#[test]
+fn group_ambiguous_ref() {
+    CommonTestRunner::new("group_ambiguous_ref")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GROUP-AMBIGUOUS-REF-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 HOME-ADDR.
+        05 CITY PIC X(6) VALUE "London".
+    01 WORK-ADDR.
+        05 CITY PIC X(5) VALUE "Paris".
+
+PROCEDURE DIVISION.
+    DISPLAY CITY.
+STOP RUN.
+        "#)
+        .expect_fail(Some("is ambiguous"))
+        .run();
+}
@@ -0,0 +1,182 @@
+use super::common::CommonTestRunner;
+
+/// Tests that INITIALIZE resets elementary alphanumeric items to spaces and numeric items to zero.
+#[test]
+fn initialize_elementary() {
+    CommonTestRunner::new("initialize_elementary")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INITIALIZE-ELEMENTARY-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-NAME PIC X(5) VALUE "HELLO".
+    01 WS-COUNT PIC 9(3) VALUE 42.
+    01 WS-TOTAL PIC S9(4)V99 COMP VALUE -12.5.
+
+PROCEDURE DIVISION.
+    INITIALIZE WS-NAME.
+    INITIALIZE WS-COUNT WS-TOTAL.
+    DISPLAY "[" WS-NAME "] " WS-COUNT " " WS-TOTAL.
+STOP RUN.
+        "#)
+        .expect_output("[     ] 000 0\n")
+        .run();
+}
+
+/// Tests that INITIALIZE on a record initializes each elementary item according to its category.
+#[test]
+fn initialize_record() {
+    CommonTestRunner::new("initialize_record")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INITIALIZE-RECORD-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-CUSTOMER.
+        05 WS-ID PIC 9(4) VALUE 1234.
+        05 WS-NAME PIC X(6) VALUE "ALICE".
+        05 WS-INITIALS PIC A(2) VALUE "AB".
+        05 WS-DETAILS.
+            10 WS-BALANCE PIC S9(5)V99 COMP-3 VALUE -99.5.
+            10 WS-SHOWN PIC $$,$$9.99.
+
+PROCEDURE DIVISION.
+    MOVE 42.5 TO WS-SHOWN.
+    INITIALIZE WS-CUSTOMER.
+    DISPLAY WS-ID " [" WS-NAME "][" WS-INITIALS "] " WS-BALANCE " [" WS-SHOWN "]".
+STOP RUN.
+        "#)
+        .expect_output("0000 [      ][  ] +00000.00 [    $0.00]\n")
+        .run();
+}
+
+/// Tests that the REPLACING phrase initializes only the items of the categories named.
+#[test]
+fn initialize_replacing() {
+    CommonTestRunner::new("initialize_replacing")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INITIALIZE-REPLACING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-CODE PIC X(5) VALUE "ABCDE".
+        05 WS-LETTERS PIC A(3) VALUE "XYZ".
+        05 WS-QTY PIC 9(3) VALUE 5.
+        05 WS-PRICE PIC 9(3)V9 COMP VALUE 12.5.
+
+PROCEDURE DIVISION.
+    INITIALIZE WS-RECORD REPLACING ALPHANUMERIC BY "N/A" NUMERIC BY 7.
+    DISPLAY "[" WS-CODE "][" WS-LETTERS "] " WS-QTY " " WS-PRICE.
+    INITIALIZE WS-RECORD
+        REPLACING ALPHABETIC DATA BY ALL "-"
+        ALPHANUMERIC BY SPACES.
+    DISPLAY "[" WS-CODE "][" WS-LETTERS "] " WS-QTY " " WS-PRICE.
+STOP RUN.
+        "#)
+        .expect_output("[N/A  ][XYZ] 007 7\n[     ][---] 007 7\n")
+        .run();
+}
+
+/// Tests that every occurrence of a table is initialized, and that items redefining others are not.
+#[test]
+fn initialize_table_redefines() {
+    CommonTestRunner::new("initialize_table_redefines")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INITIALIZE-TABLE-REDEFINES-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-ENTRY OCCURS 3 TIMES.
+            10 WS-KEY PIC X(2) VALUE "KK".
+            10 WS-VAL PIC 99 VALUE 11.
+    01 WS-OVERLAY.
+        05 WS-TEXT PIC X(4) VALUE "TEXT".
+        05 WS-DIGITS REDEFINES WS-TEXT PIC 9(4).
+
+PROCEDURE DIVISION.
+    INITIALIZE WS-TABLE WS-OVERLAY.
+    DISPLAY "[" WS-KEY(1) "]" WS-VAL(1) "[" WS-KEY(2) "]" WS-VAL(2) "[" WS-KEY(3) "]" WS-VAL(3).
+    DISPLAY "[" WS-TEXT "]".
+STOP RUN.
+        "#)
+        .expect_output("[  ]00[  ]00[  ]00\n[    ]\n")
+        .run();
+}
+
+/// Tests that large tables are initialized in full, and that variable length tables only have
+/// their current occurrences initialized.
+#[test]
+fn initialize_large_table() {
+    CommonTestRunner::new("initialize_large_table")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INITIALIZE-LARGE-TABLE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-ENTRY OCCURS 10000 TIMES.
+            10 WS-KEY PIC X(2) VALUE "KK".
+            10 WS-VAL PIC 99 VALUE 11.
+    01 WS-CNT PIC 9 VALUE 2.
+    01 WS-VAR-TABLE.
+        05 WS-ITEM PIC 9 VALUE 7 OCCURS 1 TO 3 DEPENDING ON WS-CNT.
+
+PROCEDURE DIVISION.
+    INITIALIZE WS-TABLE WS-VAR-TABLE.
+    DISPLAY "[" WS-KEY(1) "]" WS-VAL(1) "[" WS-KEY(10000) "]" WS-VAL(10000).
+    MOVE 3 TO WS-CNT.
+    DISPLAY WS-VAR-TABLE.
+STOP RUN.
+        "#)
+        .expect_output("[  ]00[  ]00\n007\n")
+        .run();
+}
+
+/// Tests that a replacement literal must fit the items of the category it replaces.
+#[test]
+fn initialize_incompatible_literal() {
+    CommonTestRunner::new("initialize_incompatible_literal")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INITIALIZE-INCOMPATIBLE-LITERAL-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-CODE PIC X(2).
+        05 WS-QTY PIC 9(3).
+
+PROCEDURE DIVISION.
+    INITIALIZE WS-RECORD REPLACING NUMERIC BY "ABC".
+STOP RUN.
+        "#)
+        .expect_fail(Some("Cannot initialize NUMERIC item 'WS-QTY' (3 bytes) with incompatible literal 'ABC'."))
+        .run();
+}
+
+/// Tests that a category cannot be replaced more than once in a single INITIALIZE statement.
+#[test]
+fn initialize_duplicate_category() {
+    CommonTestRunner::new("initialize_duplicate_category")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INITIALIZE-DUPLICATE-CATEGORY-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-QTY PIC 9(3).
+
+PROCEDURE DIVISION.
+    INITIALIZE WS-QTY REPLACING NUMERIC BY 1 NUMERIC BY 2.
+STOP RUN.
+        "#)
+        .expect_fail(Some("The category 'NUMERIC' cannot be replaced more than once."))
+        .run();
+}
@@ -97,8 +97,8 @@ PROGRAM-ID. RANDOM-DIFFERS-TEST.
 
 DATA DIVISION.
     WORKING-STORAGE SECTION.
-    01 A-VAL PIC 9(4)P9(4) COMP.
-    01 B-VAL PIC 9(4)P9(4) COMP.
+    01 A-VAL PIC 9(4)V9(4) COMP.
+    01 B-VAL PIC 9(4)V9(4) COMP.
 
 PROCEDURE DIVISION.
     MOVE FUNCTION RANDOM() TO A-VAL.
@@ -124,7 +124,7 @@ PROGRAM-ID. INT-CONV-FLOAT-TEST.
 
 DATA DIVISION.
     WORKING-STORAGE SECTION.
-    01 FLT-VAL PIC 9(4)P9(4) COMP VALUE 20.43.
+    01 FLT-VAL PIC 9(4)V9(4) COMP VALUE 20.43.
     01 INT-VAL PIC 9(10) COMP.
 
 PROCEDURE DIVISION.
@@ -146,7 +146,7 @@ PROGRAM-ID. INT-CONV-INT-TEST.
 
 DATA DIVISION.
     WORKING-STORAGE SECTION.
-    01 FLT-VAL PIC 9(4)P9(4) COMP VALUE 220.0.
+    01 FLT-VAL PIC 9(4)V9(4) COMP VALUE 220.0.
     01 INT-VAL PIC 9(10) COMP.
 
 PROCEDURE DIVISION.
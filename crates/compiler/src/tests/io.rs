@@ -46,7 +46,7 @@ PROGRAM-ID. DISPLAY-FLT-DATA-TEST.
 
 DATA DIVISION.
     WORKING-STORAGE SECTION.
-    01 FLT-VAL PIC S9(4)P9(4) COMP VALUE -1234.5678.
+    01 FLT-VAL PIC S9(4)V9(4) COMP VALUE -1234.5678.
 
 PROCEDURE DIVISION.
     DISPLAY FLT-VAL.
@@ -129,7 +129,7 @@ PROGRAM-ID. ACCEPT-FLT-TEST.
 
 DATA DIVISION.
     WORKING-STORAGE SECTION.
-    01 FLT-VAL PIC 9(6)P9(4) COMP.
+    01 FLT-VAL PIC 9(6)V9(4) COMP.
 
 PROCEDURE DIVISION.
     ACCEPT FLT-VAL.
@@ -11,7 +11,7 @@ PROGRAM-ID. ADD-INT-TEST.
 DATA DIVISION.
     WORKING-STORAGE SECTION.
     01 A-VAL PIC 9(6) COMP VALUE 6.
-    01 B-VAL PIC 9(6) COMP VALUE -4.
+    01 B-VAL PIC S9(6) COMP VALUE -4.
 
 PROCEDURE DIVISION.
     ADD A-VAL TO B-VAL.
@@ -34,7 +34,7 @@ DATA DIVISION.
     WORKING-STORAGE SECTION.
     01 A-VAL PIC 9(6) COMP VALUE 6.
     01 B-VAL PIC 9(6) COMP VALUE 3.
-    01 C-VAL PIC 9(6) COMP VALUE -4.
+    01 C-VAL PIC S9(6) COMP VALUE -4.
 
 PROCEDURE DIVISION.
     ADD A-VAL TO B-VAL C-VAL.
@@ -102,8 +102,8 @@ PROGRAM-ID. SUB-INT-MULT-OUT-TEST.
 DATA DIVISION.
     WORKING-STORAGE SECTION.
     01 A-VAL PIC 9(6) COMP VALUE 6.
-    01 B-VAL PIC 9(6) COMP VALUE 3.
-    01 C-VAL PIC 9(6) COMP VALUE 6.
+    01 B-VAL PIC S9(6) COMP VALUE 3.
+    01 C-VAL PIC S9(6) COMP VALUE 6.
 
 PROCEDURE DIVISION.
     SUBTRACT A-VAL FROM B-VAL C-VAL.
@@ -171,8 +171,8 @@ PROGRAM-ID. MUL-INT-MULT-OUT-TEST.
 DATA DIVISION.
     WORKING-STORAGE SECTION.
     01 A-VAL PIC 9(6) COMP VALUE 6.
-    01 B-VAL PIC 9(6) COMP VALUE 3.
-    01 C-VAL PIC 9(6) COMP VALUE -4.
+    01 B-VAL PIC S9(6) COMP VALUE 3.
+    01 C-VAL PIC S9(6) COMP VALUE -4.
 
 PROCEDURE DIVISION.
     MULTIPLY A-VAL BY B-VAL C-VAL.
@@ -199,11 +199,11 @@ DATA DIVISION.
     01 OUT-VAL PIC 9(6) COMP.
 
 PROCEDURE DIVISION.
-    MULTIPLY A-VAL B-VAL BY C-VAL GIVING OUT-VAL.
+    MULTIPLY A-VAL BY C-VAL GIVING OUT-VAL.
     DISPLAY OUT-VAL.
 STOP RUN.
         "#)
-        .expect_output("48\n")
+        .expect_output("24\n")
         .run();
 }
 
@@ -241,7 +241,7 @@ DATA DIVISION.
     WORKING-STORAGE SECTION.
     01 A-VAL PIC 9(6) COMP VALUE 4.
     01 B-VAL PIC 9(6) COMP VALUE 6.
-    01 OUT-VAL PIC 9(6)P9(4) COMP.
+    01 OUT-VAL PIC 9(6)V9(4) COMP.
 
 PROCEDURE DIVISION.
     DIVIDE B-VAL BY A-VAL GIVING OUT-VAL.
@@ -250,4 +250,382 @@ STOP RUN.
         "#)
         .expect_output("1.5\n")
         .run();
-}
\ No newline at end of file
+}
+
+/// Tests that results are truncated to the declared digits of their destination.
+#[test]
+fn truncate_to_digits() {
+    CommonTestRunner::new("truncate_to_digits")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. TRUNCATE-DIGITS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4) COMP VALUE 9999.
+    01 B-VAL PIC 9(4) COMP.
+    01 C-VAL PIC 9(2)V9 COMP VALUE 99.9.
+
+PROCEDURE DIVISION.
+    ADD 1 TO A-VAL.
+    COMPUTE B-VAL = 123456.
+    ADD 0.2 TO C-VAL.
+    DISPLAY A-VAL " " B-VAL " " C-VAL.
+STOP RUN.
+        "#)
+        .expect_output("0 3456 0.1\n")
+        .run();
+}
+
+/// Tests that initial values of binary items must fit within their declared digits and sign.
+#[test]
+fn comp_value_exceeds_digits() {
+    CommonTestRunner::new("comp_value_exceeds_digits")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMP-VALUE-EXCEEDS-DIGITS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(2) COMP VALUE 12345.
+
+PROCEDURE DIVISION.
+    DISPLAY A-VAL.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Initial value for variable 'A-VAL' does not fit data layout."))
+        .run();
+}
+
+/// Tests that unsigned binary items cannot be given a negative initial value.
+#[test]
+fn comp_unsigned_negative_value() {
+    CommonTestRunner::new("comp_unsigned_negative_value")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. COMP-UNSIGNED-NEGATIVE-VALUE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 B-VAL PIC 9(2) COMP VALUE -5.
+
+PROCEDURE DIVISION.
+    DISPLAY B-VAL.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Initial value for variable 'B-VAL' does not fit data layout."))
+        .run();
+}
+
+/// Tests that unsigned binary items store only the absolute value of negative results.
+#[test]
+fn unsigned_comp_below_zero() {
+    CommonTestRunner::new("unsigned_comp_below_zero")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. UNSIGNED-COMP-BELOW-ZERO-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(2) COMP VALUE 5.
+    01 B-VAL PIC 9(4) COMP.
+    01 C-VAL PIC S9(4) COMP.
+
+PROCEDURE DIVISION.
+    SUBTRACT 10 FROM A-VAL.
+    COMPUTE B-VAL = 0 - 3.
+    MOVE -3 TO C-VAL.
+    DISPLAY A-VAL " " B-VAL " " C-VAL.
+STOP RUN.
+        "#)
+        .expect_output("5 3 -3\n")
+        .run();
+}
+
+/// Tests that the ON SIZE ERROR and NOT ON SIZE ERROR phrases are executed as expected.
+#[test]
+fn add_size_error() {
+    CommonTestRunner::new("add_size_error")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ADD-SIZE-ERROR-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4) COMP VALUE 9998.
+
+PROCEDURE DIVISION.
+    PERFORM ADD-ONE 2 TIMES.
+    DISPLAY A-VAL.
+    STOP RUN.
+
+    ADD-ONE.
+        ADD 1 TO A-VAL
+            ON SIZE ERROR
+                DISPLAY "SIZE ERROR"
+            NOT ON SIZE ERROR
+                DISPLAY "OK"
+        END-ADD.
+        "#)
+        .expect_output("OK\nSIZE ERROR\n9999\n")
+        .run();
+}
+
+/// Tests that size error phrases can begin on the same line as their statement.
+#[test]
+fn multiply_size_error_inline() {
+    CommonTestRunner::new("multiply_size_error_inline")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MULTIPLY-SIZE-ERROR-INLINE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(2) COMP VALUE 60.
+    01 B-VAL PIC 9(3) COMP VALUE 60.
+
+PROCEDURE DIVISION.
+    MULTIPLY 2 BY A-VAL B-VAL ON SIZE ERROR DISPLAY "SIZE ERROR"
+    END-MULTIPLY.
+    DISPLAY A-VAL " " B-VAL.
+STOP RUN.
+        "#)
+        .expect_output("SIZE ERROR\n60 120\n")
+        .run();
+}
+
+/// Tests that a DIVIDE statement can contain size error phrases.
+#[test]
+fn divide_size_error() {
+    CommonTestRunner::new("divide_size_error")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DIVIDE-SIZE-ERROR-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4) COMP VALUE 1000.
+    01 B-VAL PIC 9(4) COMP VALUE 3.
+    01 OUT-VAL PIC 9(2)V99 COMP.
+
+PROCEDURE DIVISION.
+    DIVIDE A-VAL BY B-VAL GIVING OUT-VAL
+        ON SIZE ERROR
+            DISPLAY "SIZE ERROR"
+    END-DIVIDE.
+    DIVIDE B-VAL INTO A-VAL
+        NOT ON SIZE ERROR
+            DISPLAY "OK"
+    END-DIVIDE.
+    DISPLAY A-VAL " " OUT-VAL.
+STOP RUN.
+        "#)
+        .expect_output("SIZE ERROR\nOK\n333 0\n")
+        .run();
+}
+
+/// Tests that the remainder of a division can be stored.
+#[test]
+fn divide_remainder() {
+    CommonTestRunner::new("divide_remainder")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DIVIDE-REMAINDER-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-YEAR PIC 9(4) COMP VALUE 2023.
+    01 WS-QUOT PIC S9(4) COMP.
+    01 WS-REM PIC S9(4) COMP.
+    01 WS-DEC-QUOT PIC 9V9 COMP.
+    01 WS-DEC-REM PIC 9V99 COMP.
+
+PROCEDURE DIVISION.
+    DIVIDE WS-YEAR BY 4 GIVING WS-QUOT REMAINDER WS-REM.
+    DISPLAY WS-QUOT " " WS-REM.
+    DIVIDE 2 INTO -7 GIVING WS-QUOT REMAINDER WS-REM.
+    DISPLAY WS-QUOT " " WS-REM.
+    DIVIDE 10 BY 3 GIVING WS-DEC-QUOT ROUNDED REMAINDER WS-DEC-REM.
+    DISPLAY WS-DEC-QUOT " " WS-DEC-REM.
+STOP RUN.
+        "#)
+        .expect_output("505 3\n-3 -1\n3.3 0.1\n")
+        .run();
+}
+
+/// Tests that DIVIDE statements accept literal operands and multiple receiving items.
+#[test]
+fn divide_literals_multiple_dests() {
+    CommonTestRunner::new("divide_literals_multiple_dests")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DIVIDE-LITERALS-MULTIPLE-DESTS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 99 COMP VALUE 21.
+    01 B-VAL PIC 99 COMP VALUE 15.
+    01 C-VAL PIC 9 COMP.
+    01 D-VAL PIC 9V9 COMP.
+
+PROCEDURE DIVISION.
+    DIVIDE 7 INTO A-VAL.
+    DIVIDE 2 INTO A-VAL B-VAL ROUNDED.
+    DIVIDE 10 BY 4 GIVING C-VAL ROUNDED D-VAL.
+    DISPLAY A-VAL " " B-VAL " " C-VAL " " D-VAL.
+STOP RUN.
+        "#)
+        .expect_output("1 8 3 2.5\n")
+        .run();
+}
+
+/// Tests that division by zero is a size error, and leaves the receiving items unchanged.
+#[test]
+fn divide_by_zero() {
+    CommonTestRunner::new("divide_by_zero")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DIVIDE-BY-ZERO-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 99 COMP VALUE 12.
+    01 ZERO-VAL PIC 99 COMP VALUE 0.
+    01 OUT-VAL PIC 99 COMP VALUE 5.
+
+PROCEDURE DIVISION.
+    DIVIDE A-VAL BY ZERO-VAL GIVING OUT-VAL
+        ON SIZE ERROR DISPLAY "DIVIDE BY ZERO"
+        NOT ON SIZE ERROR DISPLAY "OK"
+    END-DIVIDE.
+    DIVIDE ZERO-VAL INTO A-VAL.
+    DISPLAY A-VAL " " OUT-VAL.
+STOP RUN.
+        "#)
+        .expect_output("DIVIDE BY ZERO\n12 5\n")
+        .run();
+}
+
+/// Tests that a remainder may only be stored for a single quotient.
+#[test]
+fn divide_remainder_multiple_dests() {
+    CommonTestRunner::new("divide_remainder_multiple_dests")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. DIVIDE-REMAINDER-MULTIPLE-DESTS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 99 COMP.
+    01 B-VAL PIC 99 COMP.
+    01 C-VAL PIC 99 COMP.
+
+PROCEDURE DIVISION.
+    DIVIDE 3 INTO 10 GIVING A-VAL B-VAL REMAINDER C-VAL.
+STOP RUN.
+        "#)
+        .expect_fail(Some("DIVIDE statements with a 'REMAINDER' phrase may only have a single 'GIVING' item."))
+        .run();
+}
+
+/// Tests that the result of an ADD statement can be stored in a list of "GIVING" items.
+#[test]
+fn add_giving_list() {
+    CommonTestRunner::new("add_giving_list")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ADD-GIVING-LIST-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4) COMP VALUE 6.
+    01 B-VAL PIC 9(4) COMP VALUE 2.
+    01 C-VAL PIC 9(4) COMP.
+    01 D-VAL PIC 9(4) COMP.
+    01 E-VAL PIC 9 COMP.
+
+PROCEDURE DIVISION.
+    ADD A-VAL B-VAL GIVING C-VAL D-VAL.
+    DISPLAY C-VAL " " D-VAL.
+    ADD A-VAL B-VAL TO 100 GIVING C-VAL E-VAL.
+    DISPLAY C-VAL " " D-VAL " " E-VAL.
+STOP RUN.
+        "#)
+        .expect_output("8 8\n108 8 8\n")
+        .run();
+}
+
+/// Tests that the result of a SUBTRACT statement can be stored in a list of "GIVING" items.
+#[test]
+fn subtract_giving_list() {
+    CommonTestRunner::new("subtract_giving_list")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SUBTRACT-GIVING-LIST-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4) COMP VALUE 6.
+    01 B-VAL PIC 9(4) COMP VALUE 2.
+    01 C-VAL PIC S9(4) COMP.
+    01 D-VAL PIC S9(4) COMP.
+
+PROCEDURE DIVISION.
+    SUBTRACT A-VAL B-VAL FROM 100 GIVING C-VAL D-VAL.
+    DISPLAY C-VAL " " D-VAL.
+    SUBTRACT 10 FROM A-VAL GIVING D-VAL.
+    DISPLAY C-VAL " " D-VAL.
+STOP RUN.
+        "#)
+        .expect_output("92 92\n92 -4\n")
+        .run();
+}
+
+/// Tests that MULTIPLY statements multiply each receiving item independently.
+#[test]
+fn multiply_each_dest() {
+    CommonTestRunner::new("multiply_each_dest")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MULTIPLY-EACH-DEST-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4) COMP VALUE 3.
+    01 B-VAL PIC 9(4) COMP VALUE 4.
+    01 C-VAL PIC 9(4) COMP VALUE 5.
+    01 D-VAL PIC 9 COMP.
+    01 E-VAL PIC 9V9 COMP.
+
+PROCEDURE DIVISION.
+    MULTIPLY A-VAL BY B-VAL C-VAL.
+    DISPLAY B-VAL " " C-VAL.
+    MULTIPLY 1.5 BY A-VAL GIVING D-VAL ROUNDED E-VAL.
+    DISPLAY D-VAL " " E-VAL.
+STOP RUN.
+        "#)
+        .expect_output("12 15\n5 4.5\n")
+        .run();
+}
+
+/// Tests that MULTIPLY statements cannot multiply more than one value before "BY".
+#[test]
+fn multiply_multiple_sources() {
+    CommonTestRunner::new("multiply_multiple_sources")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MULTIPLY-MULTIPLE-SOURCES-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 A-VAL PIC 9(4) COMP VALUE 3.
+    01 B-VAL PIC 9(4) COMP VALUE 4.
+    01 C-VAL PIC 9(4) COMP VALUE 5.
+
+PROCEDURE DIVISION.
+    MULTIPLY A-VAL B-VAL BY C-VAL.
+STOP RUN.
+        "#)
+        .expect_fail(Some("MULTIPLY statements may only have a single value before 'BY'."))
+        .run();
+}
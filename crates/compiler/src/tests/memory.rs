@@ -30,7 +30,7 @@ PROGRAM-ID. FLT-MOVE-TEST.
 
 DATA DIVISION.
     WORKING-STORAGE SECTION.
-    01 FLT-VAL PIC 9(4)P9(4) COMP.
+    01 FLT-VAL PIC 9(4)V9(4) COMP.
 
 PROCEDURE DIVISION.
     MOVE 20.04 TO FLT-VAL.
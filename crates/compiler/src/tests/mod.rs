@@ -2,9 +2,25 @@
 mod common;
 
 // Individual conformance test modules.
+mod compute;
+mod cond_names;
 mod control;
+mod corresponding;
 mod data;
+mod display_numerics;
+mod evaluate;
+mod figurative;
+mod fixed_point;
+mod groups;
+mod initialize;
 mod intrinsics;
 mod io;
 mod maths;
-mod memory;
\ No newline at end of file
+mod memory;
+mod numeric_edited;
+mod packed_decimal;
+mod redefines;
+mod sections;
+mod sentences;
+mod tables;
+mod termination;
\ No newline at end of file
@@ -0,0 +1,214 @@
+use super::common::CommonTestRunner;
+
+/// Tests that a floating currency symbol, comma insertion and a credit symbol are edited correctly.
+#[test]
+fn edit_currency_credit() {
+    CommonTestRunner::new("edit_currency_credit")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-CURRENCY-CREDIT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-AMOUNT PIC S9(4)V99 COMP VALUE -1234.5.
+    01 WS-EDITED PIC $$,$$9.99CR.
+
+PROCEDURE DIVISION.
+    MOVE WS-AMOUNT TO WS-EDITED.
+    DISPLAY "[" WS-EDITED "]".
+    MOVE 7.25 TO WS-EDITED.
+    DISPLAY "[" WS-EDITED "]".
+STOP RUN.
+        "#)
+        .expect_output("[$1,234.50CR]\n[    $7.25  ]\n")
+        .run();
+}
+
+/// Tests that zero suppression replaces leading zeroes with spaces.
+#[test]
+fn edit_zero_suppress() {
+    CommonTestRunner::new("edit_zero_suppress")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-ZERO-SUPPRESS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-COUNT PIC 9(4) VALUE 42.
+    01 WS-EDITED PIC ZZZ9.
+    01 WS-BLANK PIC ZZZZ.
+    01 WS-POINT PIC ZZZ.ZZ.
+
+PROCEDURE DIVISION.
+    MOVE WS-COUNT TO WS-EDITED.
+    MOVE 0 TO WS-BLANK.
+    MOVE 0.05 TO WS-POINT.
+    DISPLAY "[" WS-EDITED "][" WS-BLANK "][" WS-POINT "]".
+STOP RUN.
+        "#)
+        .expect_output("[  42][    ][   .05]\n")
+        .run();
+}
+
+/// Tests that check protection fills suppressed positions with asterisks.
+#[test]
+fn edit_check_protect() {
+    CommonTestRunner::new("edit_check_protect")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-CHECK-PROTECT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-EDITED PIC ***,**9.99.
+
+PROCEDURE DIVISION.
+    MOVE 1234.5 TO WS-EDITED.
+    DISPLAY WS-EDITED.
+STOP RUN.
+        "#)
+        .expect_output("**1,234.50\n")
+        .run();
+}
+
+/// Tests that fixed and floating sign symbols, as well as debit symbols, are edited correctly.
+#[test]
+fn edit_signs() {
+    CommonTestRunner::new("edit_signs")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-SIGNS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-PLUS PIC +ZZ9.
+    01 WS-TRAIL PIC ZZ9-.
+    01 WS-FLOAT PIC ----9.
+    01 WS-DEBIT PIC ZZ9DB.
+
+PROCEDURE DIVISION.
+    MOVE 5 TO WS-PLUS.
+    MOVE -5 TO WS-TRAIL.
+    MOVE -12 TO WS-FLOAT.
+    MOVE -5 TO WS-DEBIT.
+    DISPLAY "[" WS-PLUS "][" WS-TRAIL "][" WS-FLOAT "][" WS-DEBIT "]".
+STOP RUN.
+        "#)
+        .expect_output("[+  5][  5-][  -12][  5DB]\n")
+        .run();
+}
+
+/// Tests that simple insertion symbols are inserted between digits.
+#[test]
+fn edit_insertion() {
+    CommonTestRunner::new("edit_insertion")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-INSERTION-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-DATE PIC 99/99/99.
+    01 WS-SPACED PIC 999B999.
+    01 WS-ZEROES PIC 9900.
+
+PROCEDURE DIVISION.
+    MOVE 123199 TO WS-DATE.
+    MOVE 123456 TO WS-SPACED.
+    MOVE 12 TO WS-ZEROES.
+    DISPLAY WS-DATE " " WS-SPACED " " WS-ZEROES.
+STOP RUN.
+        "#)
+        .expect_output("12/31/99 123 456 1200\n")
+        .run();
+}
+
+/// Tests that the result of an arithmetic operation can be stored in a numeric-edited item.
+#[test]
+fn edit_arithmetic_result() {
+    CommonTestRunner::new("edit_arithmetic_result")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-ARITHMETIC-RESULT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-PRICE PIC 9(4)V99 COMP VALUE 1999.99.
+    01 WS-TAX PIC 9(4)V99 COMP VALUE 160.
+    01 WS-TOTAL PIC $Z,ZZ9.99.
+
+PROCEDURE DIVISION.
+    ADD WS-PRICE TO WS-TAX GIVING WS-TOTAL.
+    DISPLAY WS-TOTAL.
+STOP RUN.
+        "#)
+        .expect_output("$2,159.99\n")
+        .run();
+}
+
+/// Tests that a numeric-edited item is initialised with its edited text, without disturbing the
+/// items following it.
+#[test]
+fn edit_initial_value() {
+    CommonTestRunner::new("edit_initial_value")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-INITIAL-VALUE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-REC.
+        05 WS-EDITED PIC $$9.99 VALUE " $5.00".
+        05 WS-TEXT PIC X(3) VALUE "ABC".
+
+PROCEDURE DIVISION.
+    DISPLAY WS-REC.
+    MOVE 12.5 TO WS-EDITED.
+    DISPLAY WS-REC.
+STOP RUN.
+        "#)
+        .expect_output(" $5.00ABC\n$12.50ABC\n")
+        .run();
+}
+
+/// Tests that a numeric-edited item cannot be initialised with a numeric literal.
+#[test]
+fn edit_numeric_initial_value() {
+    CommonTestRunner::new("edit_numeric_initial_value")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-NUMERIC-INITIAL-VALUE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-REC.
+        05 WS-EDITED PIC $$9.99 VALUE 5.
+        05 WS-TEXT PIC X(3) VALUE "ABC".
+
+PROCEDURE DIVISION.
+    DISPLAY WS-REC.
+STOP RUN.
+        "#)
+        .expect_fail(Some("Initial value for numeric-edited item 'WS-EDITED' must be an alphanumeric literal."))
+        .run();
+}
+
+/// Tests that a numeric-edited PIC clause cannot contain an operational sign.
+#[test]
+fn edit_invalid_sign() {
+    CommonTestRunner::new("edit_invalid_sign")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EDIT-INVALID-SIGN-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-EDITED PIC SZZ9.
+
+PROCEDURE DIVISION.
+    DISPLAY WS-EDITED.
+STOP RUN.
+        "#)
+        .expect_fail(Some("cannot contain 'S' terms"))
+        .run();
+}
@@ -0,0 +1,88 @@
+use super::common::CommonTestRunner;
+
+/// Tests storing, displaying and performing arithmetic on COMP-3 items.
+#[test]
+fn packed_arithmetic() {
+    CommonTestRunner::new("packed_arithmetic")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PACKED-ARITHMETIC-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-AMOUNT PIC S9(7)V99 COMP-3 VALUE -1234.5.
+    01 WS-COUNT PIC 9(4) USAGE IS PACKED-DECIMAL VALUE 12.
+    01 WS-TOTAL PIC S9(5) COMP-3.
+    01 WS-BIN PIC 9(4) COMP.
+    01 WS-BIG PIC 9(6) COMP VALUE 123456.
+
+PROCEDURE DIVISION.
+    DISPLAY WS-AMOUNT.
+    DISPLAY WS-COUNT.
+    DISPLAY WS-TOTAL.
+    ADD 100.25 TO WS-AMOUNT.
+    DISPLAY WS-AMOUNT.
+    MULTIPLY 3 BY WS-COUNT GIVING WS-TOTAL.
+    SUBTRACT 50 FROM WS-TOTAL.
+    DISPLAY WS-TOTAL.
+    MOVE WS-TOTAL TO WS-BIN.
+    DISPLAY WS-BIN.
+    MOVE WS-BIG TO WS-COUNT.
+    DISPLAY WS-COUNT.
+STOP RUN.
+        "#)
+        .expect_output("-0001234.50\n0012\n+00000\n-0001134.25\n-00014\n14\n3456\n")
+        .run();
+}
+
+/// Tests that COMP-3 items are stored in exactly the number of bytes required for their digits,
+/// and that copying records containing them preserves their values.
+#[test]
+fn packed_storage_size() {
+    CommonTestRunner::new("packed_storage_size")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PACKED-STORAGE-SIZE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-ID PIC X(3) VALUE "A01".
+        05 WS-BALANCE PIC S9(7)V99 COMP-3 VALUE 250.75.
+        05 WS-BALANCE-X REDEFINES WS-BALANCE PIC X(5).
+        05 WS-QTY PIC 9(4) COMP-3 VALUE 42.
+    01 WS-COPY.
+        05 WS-COPY-ID PIC X(3).
+        05 WS-COPY-BALANCE PIC S9(7)V99 COMP-3.
+        05 WS-COPY-QTY PIC 9(4) COMP-3.
+
+PROCEDURE DIVISION.
+    MOVE WS-RECORD TO WS-COPY.
+    DISPLAY WS-COPY-ID " " WS-COPY-BALANCE " " WS-COPY-QTY.
+STOP RUN.
+        "#)
+        .expect_output("A01 +0000250.75 0042\n")
+        .run();
+}
+
+/// Tests that a COMP-3 item cannot be redefined by a larger item.
+#[test]
+fn packed_redefine_too_large() {
+    CommonTestRunner::new("packed_redefine_too_large")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PACKED-REDEFINE-TOO-LARGE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-BALANCE PIC S9(7)V99 COMP-3.
+        05 WS-BALANCE-X REDEFINES WS-BALANCE PIC X(6).
+
+PROCEDURE DIVISION.
+    DISPLAY WS-BALANCE.
+STOP RUN.
+        "#)
+        .expect_fail(Some("cannot be larger than the item"))
+        .run();
+}
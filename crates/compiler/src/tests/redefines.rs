@@ -0,0 +1,127 @@
+use super::common::CommonTestRunner;
+
+/// Tests that a redefining group shares storage with the item it redefines.
+#[test]
+fn redefine_elementary_as_group() {
+    CommonTestRunner::new("redefine_elementary_as_group")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. REDEFINE-ELEMENTARY-AS-GROUP-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-DATE-X PIC X(8) VALUE "20240131".
+        05 WS-DATE REDEFINES WS-DATE-X.
+            10 WS-YEAR PIC X(4).
+            10 WS-MONTH PIC X(2).
+            10 WS-DAY PIC X(2).
+        05 WS-TRAILER PIC X(3) VALUE "end".
+
+PROCEDURE DIVISION.
+    DISPLAY WS-YEAR.
+    DISPLAY WS-MONTH.
+    DISPLAY WS-DAY.
+    MOVE "12" TO WS-MONTH.
+    DISPLAY WS-DATE-X.
+    DISPLAY WS-RECORD.
+STOP RUN.
+        "#)
+        .expect_output("2024\n01\n31\n20241231\n20241231end\n")
+        .run();
+}
+
+/// Tests that level 01 items can redefine one another, and that an item may be redefined
+/// multiple times.
+#[test]
+fn redefine_top_level() {
+    CommonTestRunner::new("redefine_top_level")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. REDEFINE-TOP-LEVEL-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-CODE PIC X(6) VALUE "ABCDEF".
+    01 WS-PARTS REDEFINES WS-CODE.
+        05 WS-FIRST PIC X(3).
+        05 WS-SECOND PIC X(3).
+    01 WS-SHORT REDEFINES WS-CODE PIC X(2).
+
+PROCEDURE DIVISION.
+    DISPLAY WS-SECOND.
+    DISPLAY WS-SHORT.
+    MOVE "xyz" TO WS-FIRST.
+    DISPLAY WS-CODE.
+STOP RUN.
+        "#)
+        .expect_output("DEF\nAB\nxyzDEF\n")
+        .run();
+}
+
+/// Tests that a redefining item cannot be larger than the item it redefines.
+#[test]
+fn redefine_larger_fails() {
+    CommonTestRunner::new("redefine_larger_fails")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. REDEFINE-LARGER-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-SMALL PIC X(2).
+        05 WS-LARGE REDEFINES WS-SMALL PIC X(4).
+
+PROCEDURE DIVISION.
+    DISPLAY WS-LARGE.
+STOP RUN.
+        "#)
+        .expect_fail(Some("cannot be larger than the item"))
+        .run();
+}
+
+/// Tests that an item can only redefine the item immediately preceding it.
+#[test]
+fn redefine_non_preceding_fails() {
+    CommonTestRunner::new("redefine_non_preceding_fails")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. REDEFINE-NON-PRECEDING-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-A PIC X(2).
+        05 WS-B PIC X(2).
+        05 WS-C REDEFINES WS-A PIC X(2).
+
+PROCEDURE DIVISION.
+    DISPLAY WS-C.
+STOP RUN.
+        "#)
+        .expect_fail(Some("can only redefine the item immediately preceding it"))
+        .run();
+}
+
+/// Tests that redefining items cannot declare initial values.
+#[test]
+fn redefine_with_value_fails() {
+    CommonTestRunner::new("redefine_with_value_fails")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. REDEFINE-WITH-VALUE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-RECORD.
+        05 WS-A PIC X(2).
+        05 WS-B REDEFINES WS-A PIC X(2) VALUE "ab".
+
+PROCEDURE DIVISION.
+    DISPLAY WS-B.
+STOP RUN.
+        "#)
+        .expect_fail(Some("cannot have a VALUE clause"))
+        .run();
+}
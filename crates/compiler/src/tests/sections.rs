@@ -0,0 +1,141 @@
+use super::common::CommonTestRunner;
+
+/// Tests that performing a section executes all of its paragraphs in order.
+#[test]
+fn perform_section() {
+    CommonTestRunner::new("perform_section")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. PERFORM-SECTION-TEST.
+
+PROCEDURE DIVISION.
+    PERFORM INIT-SEC.
+    PERFORM INIT-SEC.
+    STOP RUN.
+
+INIT-SEC SECTION.
+    FIRST-PARA.
+    DISPLAY "First".
+
+    SECOND-PARA.
+    DISPLAY "Second".
+        "#)
+        .expect_output("First\nSecond\nFirst\nSecond\n")
+        .run();
+}
+
+/// Tests that EXIT SECTION skips the remaining paragraphs of the section.
+#[test]
+fn exit_section() {
+    CommonTestRunner::new("exit_section")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EXIT-SECTION-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-FLAG PIC X VALUE "N".
+
+PROCEDURE DIVISION.
+    PERFORM CHECK-SEC.
+    MOVE "Y" TO WS-FLAG.
+    PERFORM CHECK-SEC.
+    DISPLAY "Done".
+    STOP RUN.
+
+CHECK-SEC SECTION.
+    CHECK-PARA.
+    DISPLAY "Checking".
+    IF WS-FLAG = "Y" THEN
+        EXIT SECTION
+    END-IF.
+    DISPLAY "Not exited".
+
+    LAST-PARA.
+    DISPLAY "Last".
+        "#)
+        .expect_output("Checking\nNot exited\nLast\nChecking\nDone\n")
+        .run();
+}
+
+/// Tests that paragraph names are scoped to their section, and can be qualified with OF or IN.
+#[test]
+fn section_qualified_paras() {
+    CommonTestRunner::new("section_qualified_paras")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SECTION-QUALIFIED-PARAS-TEST.
+
+PROCEDURE DIVISION.
+    PERFORM SHOW-PARA OF SEC-1.
+    PERFORM SHOW-PARA IN SEC-2.
+    PERFORM SEC-2.
+    STOP RUN.
+
+SEC-1 SECTION.
+    SHOW-PARA.
+    DISPLAY "One".
+
+SEC-2 SECTION.
+    START-PARA.
+    PERFORM SHOW-PARA.
+    GO TO END-PARA.
+
+    SHOW-PARA.
+    DISPLAY "Two".
+
+    END-PARA.
+    DISPLAY "End".
+        "#)
+        .expect_output("One\nTwo\nTwo\nEnd\n")
+        .run();
+}
+
+/// Tests that GO TO a section falls through into the following sections.
+#[test]
+fn go_to_section() {
+    CommonTestRunner::new("go_to_section")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GO-TO-SECTION-TEST.
+
+PROCEDURE DIVISION.
+    GO TO SEC-2.
+
+SEC-1 SECTION.
+    DISPLAY "Skipped".
+
+SEC-2 SECTION.
+    DISPLAY "Two".
+
+SEC-3 SECTION.
+    DISPLAY "Three".
+    STOP RUN.
+        "#)
+        .expect_output("Two\nThree\n")
+        .run();
+}
+
+/// Tests that an unqualified paragraph name defined in multiple other sections is rejected.
+#[test]
+fn section_ambiguous_para() {
+    CommonTestRunner::new("section_ambiguous_para")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SECTION-AMBIGUOUS-PARA-TEST.
+
+PROCEDURE DIVISION.
+    PERFORM SHOW-PARA.
+    STOP RUN.
+
+SEC-1 SECTION.
+    SHOW-PARA.
+    DISPLAY "One".
+
+SEC-2 SECTION.
+    SHOW-PARA.
+    DISPLAY "Two".
+        "#)
+        .expect_fail(Some("is ambiguous"))
+        .run();
+}
@@ -0,0 +1,151 @@
+use super::common::CommonTestRunner;
+
+/// Tests that IF statements without "THEN" or "END-IF" are ended by the period ending the sentence.
+#[test]
+fn if_period_terminated() {
+    CommonTestRunner::new("if_period_terminated")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. IF-PERIOD-TERMINATED-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-A PIC 99 VALUE 7.
+
+PROCEDURE DIVISION.
+    IF WS-A > 5
+        DISPLAY "Big"
+        DISPLAY "Still big"
+    ELSE
+        DISPLAY "Small".
+    DISPLAY "After".
+    STOP RUN.
+        "#)
+        .expect_output("Big\nStill big\nAfter\n")
+        .run();
+}
+
+/// Tests that nested IF statements without "END-IF" pair each "ELSE" with the innermost IF.
+#[test]
+fn if_nested_period_terminated() {
+    CommonTestRunner::new("if_nested_period_terminated")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. IF-NESTED-PERIOD-TERMINATED-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-A PIC 9 VALUE 2.
+    01 WS-B PIC 9 VALUE 0.
+
+PROCEDURE DIVISION.
+    IF WS-A > 1
+        IF WS-B > 1
+            DISPLAY "Both"
+        ELSE
+            DISPLAY "Only A".
+    IF WS-B > 1 IF WS-A > 1 DISPLAY "Both" ELSE DISPLAY "Only B".
+    DISPLAY "Done".
+    STOP RUN.
+        "#)
+        .expect_output("Only A\nDone\n")
+        .run();
+}
+
+/// Tests that NEXT SENTENCE transfers control to the statement following the next period.
+#[test]
+fn next_sentence() {
+    CommonTestRunner::new("next_sentence")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. NEXT-SENTENCE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-A PIC 9 VALUE 1.
+
+PROCEDURE DIVISION.
+    PERFORM CHECK-PARA.
+    MOVE 2 TO WS-A.
+    PERFORM CHECK-PARA.
+    STOP RUN.
+
+    CHECK-PARA.
+    IF WS-A = 1
+        NEXT SENTENCE
+    END-IF
+    DISPLAY "Not one".
+    DISPLAY "Checked " WS-A.
+        "#)
+        .expect_output("Checked 1\nNot one\nChecked 2\n")
+        .run();
+}
+
+/// Tests that CONTINUE performs no operation.
+#[test]
+fn continue_stat() {
+    CommonTestRunner::new("continue_stat")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. CONTINUE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-A PIC 9 VALUE 3.
+
+PROCEDURE DIVISION.
+    IF WS-A = 3 THEN
+        CONTINUE
+    ELSE
+        DISPLAY "Not three"
+    END-IF.
+    CONTINUE.
+    DISPLAY "Done".
+    STOP RUN.
+        "#)
+        .expect_output("Done\n")
+        .run();
+}
+
+/// Tests that multiple statements and sentences may be placed on a single line.
+#[test]
+fn multiple_stats_per_line() {
+    CommonTestRunner::new("multiple_stats_per_line")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MULTIPLE-STATS-PER-LINE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-A PIC 99 VALUE 0.
+    01 WS-B PIC 99 VALUE 0.
+
+PROCEDURE DIVISION.
+    MOVE 1 TO WS-A ADD 2 TO WS-A DISPLAY WS-A.
+    MOVE 5 TO WS-B. DISPLAY WS-B.
+    MOVE 9 TO WS-A
+    DISPLAY WS-A
+    IF WS-A = 9 THEN DISPLAY "Nine" END-IF DISPLAY "Done".
+    STOP RUN.
+        "#)
+        .expect_output("03\n05\n09\nNine\nDone\n")
+        .run();
+}
+
+/// Tests that a sentence must be ended with a period.
+#[test]
+fn sentence_missing_period() {
+    CommonTestRunner::new("sentence_missing_period")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SENTENCE-MISSING-PERIOD-TEST.
+
+PROCEDURE DIVISION.
+    DISPLAY "Hello"
+
+    NEXT-PARA.
+    STOP RUN.
+        "#)
+        .expect_fail(Some("Expected token '.'"))
+        .run();
+}
@@ -0,0 +1,367 @@
+use super::common::CommonTestRunner;
+
+/// Tests that elementary tables can be accessed with literal and variable subscripts.
+#[test]
+fn elementary_table() {
+    CommonTestRunner::new("elementary_table")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. ELEMENTARY-TABLE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-NUM PIC 9(3) COMP OCCURS 3 TIMES.
+    01 I PIC 9(2) COMP VALUE 1.
+
+PROCEDURE DIVISION.
+    MOVE 10 TO WS-NUM(1).
+    MOVE 20 TO WS-NUM(2).
+    MOVE 30 TO WS-NUM(3).
+    PERFORM DISPLAY-PARA UNTIL I > 3.
+    ADD WS-NUM(1) WS-NUM(2) TO WS-NUM(3).
+    DISPLAY WS-NUM(3).
+    STOP RUN.
+
+    DISPLAY-PARA.
+    DISPLAY WS-NUM(I).
+    ADD 1 TO I.
+        "#)
+        .expect_output("10\n20\n30\n60\n")
+        .run();
+}
+
+/// Tests that initial values are repeated for each occurrence within a table, and that
+/// displaying the containing group displays every occurrence.
+#[test]
+fn table_initial_values() {
+    CommonTestRunner::new("table_initial_values")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. TABLE-INITIAL-VALUES-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-CODE PIC X(3) VALUE "abc" OCCURS 3 TIMES.
+
+PROCEDURE DIVISION.
+    MOVE "xyz" TO WS-CODE(2).
+    DISPLAY WS-TABLE.
+STOP RUN.
+        "#)
+        .expect_output("abcxyzabc\n")
+        .run();
+}
+
+/// Tests that multi-dimensional tables of group items can be accessed with multiple subscripts.
+#[test]
+fn multi_dimensional_table() {
+    CommonTestRunner::new("multi_dimensional_table")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MULTI-DIM-TABLE-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-GRID.
+        05 WS-ROW OCCURS 2 TIMES.
+            10 WS-LABEL PIC X(2).
+            10 WS-CELL PIC 9(2) COMP OCCURS 3 TIMES.
+    01 I PIC 9 COMP VALUE 2.
+    01 J PIC 9 COMP VALUE 3.
+
+PROCEDURE DIVISION.
+    MOVE "R2" TO WS-LABEL(I).
+    MOVE 42 TO WS-CELL(I, J).
+    MOVE 7 TO WS-CELL(1 2).
+    DISPLAY WS-LABEL(2) " " WS-CELL(2, 3) " " WS-CELL(1, 2).
+    IF WS-CELL(I, J) = 42 THEN
+        DISPLAY "Match"
+    END-IF.
+STOP RUN.
+        "#)
+        .expect_output("R2 42 7\nMatch\n")
+        .run();
+}
+
+/// Tests that referencing a table element without subscripts fails.
+#[test]
+fn missing_subscript() {
+    CommonTestRunner::new("missing_subscript")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. MISSING-SUBSCRIPT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-NUM PIC 9(3) COMP OCCURS 3 TIMES.
+
+PROCEDURE DIVISION.
+    DISPLAY WS-NUM.
+STOP RUN.
+        "#)
+        .expect_fail(Some("requires 1 subscript(s)"))
+        .run();
+}
+
+/// Tests that literal subscripts outside the bounds of a table fail.
+#[test]
+fn subscript_out_of_bounds() {
+    CommonTestRunner::new("subscript_out_of_bounds")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SUBSCRIPT-OOB-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-NUM PIC 9(3) COMP OCCURS 3 TIMES.
+
+PROCEDURE DIVISION.
+    DISPLAY WS-NUM(4).
+STOP RUN.
+        "#)
+        .expect_fail(Some("out of bounds"))
+        .run();
+}
+
+/// Tests that variable subscripts outside the bounds of a table terminate the program at runtime,
+/// rather than overwriting neighbouring data.
+#[test]
+fn variable_subscript_out_of_bounds() {
+    CommonTestRunner::new("variable_subscript_out_of_bounds")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. VARIABLE-SUBSCRIPT-OOB-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-IDX PIC 9 COMP VALUE 3.
+    01 WS-TABLE.
+        05 WS-NUM PIC 9(3) COMP OCCURS 3 TIMES.
+    01 WS-AFTER PIC 9(3) COMP VALUE 7.
+
+PROCEDURE DIVISION.
+    MOVE 10 TO WS-NUM(WS-IDX).
+    DISPLAY WS-NUM(WS-IDX) " " WS-AFTER.
+    MOVE 5 TO WS-IDX.
+    MOVE 10 TO WS-NUM(WS-IDX).
+    DISPLAY "unreachable".
+STOP RUN.
+        "#)
+        .expect_output("10 7\n")
+        .expect_exit_code(1)
+        .run();
+}
+
+/// Tests that level 01 items cannot be tables.
+#[test]
+fn invalid_top_level_occurs() {
+    CommonTestRunner::new("invalid_top_level_occurs")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. INV-TOP-LEVEL-OCCURS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-NUM PIC 9(3) COMP OCCURS 3 TIMES.
+
+PROCEDURE DIVISION.
+    DISPLAY WS-NUM(1).
+STOP RUN.
+        "#)
+        .expect_fail(Some("cannot use an OCCURS clause"))
+        .run();
+}
+
+/// Tests that a linear SEARCH finds the first matching occurrence, leaving the index pointing at it.
+#[test]
+fn linear_search() {
+    CommonTestRunner::new("linear_search")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. LINEAR-SEARCH-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-ITEM OCCURS 4 TIMES INDEXED BY IDX.
+            10 WS-CODE PIC X(3).
+            10 WS-QTY PIC 9(3) COMP.
+    01 WS-COUNT PIC 9(2) COMP VALUE 0.
+
+PROCEDURE DIVISION.
+    MOVE "abc" TO WS-CODE(1).
+    MOVE "def" TO WS-CODE(2).
+    MOVE "ghi" TO WS-CODE(3).
+    MOVE "def" TO WS-CODE(4).
+    MOVE 5 TO WS-QTY(2).
+    SET IDX TO 1.
+    SEARCH WS-ITEM VARYING WS-COUNT
+        AT END DISPLAY "NOT FOUND"
+        WHEN WS-CODE(IDX) = "xyz" DISPLAY "XYZ"
+        WHEN WS-CODE(IDX) = "def" DISPLAY "FOUND " IDX " " WS-QTY(IDX)
+    END-SEARCH.
+    DISPLAY WS-COUNT.
+    SET IDX UP BY 1.
+    SEARCH WS-ITEM
+        WHEN WS-CODE(IDX) = "def" DISPLAY "FOUND " IDX
+    END-SEARCH.
+    SET IDX TO 1.
+    SEARCH WS-ITEM
+        AT END DISPLAY "NOT FOUND"
+        WHEN WS-CODE(IDX) = "xyz" DISPLAY "XYZ"
+    END-SEARCH.
+    STOP RUN.
+        "#)
+        .expect_output("FOUND 2 5\n1\nFOUND 4\nNOT FOUND\n")
+        .run();
+}
+
+/// Tests that searching a variable length table only considers the current occurrences.
+#[test]
+fn search_occurs_depending_on() {
+    CommonTestRunner::new("search_occurs_depending_on")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SEARCH-OCCURS-DEPENDING-ON-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-LEN PIC 9(2) COMP VALUE 2.
+    01 WS-TABLE.
+        05 WS-NUM PIC 9(3) COMP OCCURS 1 TO 5 TIMES
+            DEPENDING ON WS-LEN INDEXED BY IDX.
+
+PROCEDURE DIVISION.
+    MOVE 1 TO WS-NUM(1).
+    MOVE 2 TO WS-NUM(2).
+    MOVE 3 TO WS-NUM(3).
+    SET IDX TO 1.
+    SEARCH WS-NUM
+        AT END DISPLAY "NOT FOUND"
+        WHEN WS-NUM(IDX) = 3 DISPLAY "FOUND " IDX
+    END-SEARCH.
+    MOVE 3 TO WS-LEN.
+    SET IDX TO 1.
+    SEARCH WS-NUM
+        AT END DISPLAY "NOT FOUND"
+        WHEN WS-NUM(IDX) = 3 DISPLAY "FOUND " IDX
+    END-SEARCH.
+    STOP RUN.
+        "#)
+        .expect_output("NOT FOUND\nFOUND 3\n")
+        .run();
+}
+
+/// Tests that displaying & moving a group ending in a variable length table only uses the
+/// current occurrences of the table.
+#[test]
+fn group_occurs_depending_on() {
+    CommonTestRunner::new("group_occurs_depending_on")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. GROUP-OCCURS-DEPENDING-ON-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-CNT PIC 9 VALUE 2.
+    01 WS-TABLE.
+        05 WS-HDR PIC X(2) VALUE "T:".
+        05 WS-ITEM PIC 9 OCCURS 1 TO 5 DEPENDING ON WS-CNT.
+    01 WS-GRID.
+        05 WS-ROW OCCURS 2 TIMES.
+            10 WS-CELL PIC 9 OCCURS 3 TIMES.
+            10 WS-SEP PIC X VALUE "/".
+    01 WS-DEST PIC X(8).
+
+PROCEDURE DIVISION.
+    MOVE 1 TO WS-ITEM(1).
+    MOVE 2 TO WS-ITEM(2).
+    MOVE 3 TO WS-ITEM(3).
+    DISPLAY WS-TABLE.
+    MOVE ALL "*" TO WS-DEST.
+    MOVE WS-TABLE TO WS-DEST.
+    DISPLAY "[" WS-DEST "]".
+    MOVE 3 TO WS-CNT.
+    DISPLAY WS-TABLE.
+    MOVE 0 TO WS-CNT.
+    DISPLAY WS-TABLE.
+    MOVE 5 TO WS-CELL(2, 3).
+    DISPLAY WS-GRID.
+    STOP RUN.
+        "#)
+        .expect_output("T:12\n[T:12    ]\nT:123\nT:\n000/005/\n")
+        .run();
+}
+
+/// Tests that SEARCH ALL performs a binary search over a table using its declared keys.
+#[test]
+fn search_all() {
+    CommonTestRunner::new("search_all")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SEARCH-ALL-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-ITEM OCCURS 5 TIMES
+            ASCENDING KEY IS WS-CODE
+            DESCENDING KEY IS WS-NUM
+            INDEXED BY IDX.
+            10 WS-CODE PIC X(2).
+            10 WS-NUM PIC 9(3) COMP.
+    01 WS-TARGET PIC X(2) VALUE "cc".
+
+PROCEDURE DIVISION.
+    MOVE "aa" TO WS-CODE(1).
+    MOVE "bb" TO WS-CODE(2).
+    MOVE "cc" TO WS-CODE(3).
+    MOVE "cc" TO WS-CODE(4).
+    MOVE "dd" TO WS-CODE(5).
+    MOVE 9 TO WS-NUM(3).
+    MOVE 4 TO WS-NUM(4).
+    SEARCH ALL WS-ITEM
+        AT END DISPLAY "NOT FOUND"
+        WHEN WS-CODE(IDX) = WS-TARGET AND WS-NUM(IDX) = 4
+            DISPLAY "FOUND " IDX
+    END-SEARCH.
+    SEARCH ALL WS-ITEM
+        AT END DISPLAY "NOT FOUND"
+        WHEN WS-CODE(IDX) = "aa" DISPLAY "FOUND " IDX
+    END-SEARCH.
+    SEARCH ALL WS-ITEM
+        AT END DISPLAY "NOT FOUND"
+        WHEN WS-CODE(IDX) = "ab" DISPLAY "FOUND " IDX
+    END-SEARCH.
+    STOP RUN.
+        "#)
+        .expect_output("FOUND 4\nFOUND 1\nNOT FOUND\n")
+        .run();
+}
+
+/// Tests that searching a table without an index fails to compile.
+#[test]
+fn search_without_index_fails() {
+    CommonTestRunner::new("search_without_index_fails")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. SEARCH-WITHOUT-INDEX-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-TABLE.
+        05 WS-NUM PIC 9(3) COMP OCCURS 3 TIMES.
+
+PROCEDURE DIVISION.
+    SEARCH WS-NUM
+        WHEN WS-NUM(1) = 3 DISPLAY "FOUND"
+    END-SEARCH.
+    STOP RUN.
+        "#)
+        .expect_fail(Some("does not declare an index with INDEXED BY"))
+        .run();
+}
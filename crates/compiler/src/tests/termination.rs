@@ -0,0 +1,115 @@
+use super::common::CommonTestRunner;
+
+/// Tests that STOP RUN RETURNING exits with the given status.
+#[test]
+fn stop_run_returning() {
+    CommonTestRunner::new("stop_run_returning")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. STOP-RUN-RETURNING-TEST.
+
+PROCEDURE DIVISION.
+    DISPLAY "Stopping".
+    STOP RUN RETURNING 3.
+        "#)
+        .expect_output("Stopping\n")
+        .expect_exit_code(3)
+        .run();
+}
+
+/// Tests that STOP RUN WITH ERROR STATUS exits with the given status, or 1 if none is given.
+#[test]
+fn stop_run_error_status() {
+    CommonTestRunner::new("stop_run_error_status")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. STOP-RUN-ERROR-STATUS-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 WS-STATUS PIC 99 VALUE 12.
+
+PROCEDURE DIVISION.
+    STOP RUN WITH ERROR STATUS WS-STATUS.
+        "#)
+        .expect_output("")
+        .expect_exit_code(12)
+        .run();
+
+    CommonTestRunner::new("stop_run_error_status_default")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. STOP-RUN-ERROR-STATUS-DEFAULT-TEST.
+
+PROCEDURE DIVISION.
+    STOP RUN WITH ERROR STATUS.
+        "#)
+        .expect_output("")
+        .expect_exit_code(1)
+        .run();
+}
+
+/// Tests that the RETURN-CODE special register can be used as a numeric item, and sets the exit
+/// status of STOP RUN and GOBACK.
+#[test]
+fn return_code_register() {
+    CommonTestRunner::new("return_code_register")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. RETURN-CODE-REGISTER-TEST.
+
+PROCEDURE DIVISION.
+    MOVE 8 TO RETURN-CODE.
+    ADD 1 TO RETURN-CODE.
+    DISPLAY RETURN-CODE.
+    PERFORM FINISH-PARA.
+    DISPLAY "Unreachable".
+    STOP RUN.
+
+    FINISH-PARA.
+    IF RETURN-CODE > 5 THEN
+        GOBACK
+    END-IF.
+        "#)
+        .expect_output("9\n")
+        .expect_exit_code(9)
+        .run();
+}
+
+/// Tests that EXIT PROGRAM continues with the next statement within the main program, and that
+/// completing the last paragraph returns with the current RETURN-CODE.
+#[test]
+fn exit_program() {
+    CommonTestRunner::new("exit_program")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. EXIT-PROGRAM-TEST.
+
+PROCEDURE DIVISION.
+    MOVE 4 TO RETURN-CODE.
+    EXIT PROGRAM.
+    DISPLAY "Continued".
+        "#)
+        .expect_output("Continued\n")
+        .expect_exit_code(4)
+        .run();
+}
+
+/// Tests that a data item cannot be declared with the name of a special register.
+#[test]
+fn return_code_conflict() {
+    CommonTestRunner::new("return_code_conflict")
+        .source(r#"
+IDENTIFICATION DIVISION.
+PROGRAM-ID. RETURN-CODE-CONFLICT-TEST.
+
+DATA DIVISION.
+    WORKING-STORAGE SECTION.
+    01 RETURN-CODE PIC 9.
+
+PROCEDURE DIVISION.
+    STOP RUN.
+        "#)
+        .expect_fail(Some("conflicts with the special register"))
+        .run();
+}
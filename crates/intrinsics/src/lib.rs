@@ -1,12 +1,9 @@
 #![no_std]
 extern crate alloc;
 use alloc::string::String;
-use core::{
-    ffi::{c_char, CStr},
-    ptr::null_mut,
-};
+use core::{ffi::c_char, ptr::null_mut};
 use libc_alloc::LibcAlloc;
-use libc_print::std_name::print;
+use libc_print::std_name::{eprintln, print, println};
 use once_cell::unsync::Lazy;
 use rand::{rngs::SmallRng, RngCore, SeedableRng};
 
@@ -36,12 +33,22 @@ fn panic(info: &core::panic::PanicInfo) -> ! {
     unsafe { libc::abort() }
 }
 
-/// Prints a single string to `stdout` without appending a newline.
+/// Returns a byte slice for the given fixed length string.
+/// Strings are stored at their maximum length with any unused trailing bytes zeroed, so the
+/// string ends at either the first null byte or the maximum length, whichever comes first.
+/// The bytes are not required to be valid UTF-8 (e.g. "HIGH-VALUES").
+unsafe fn cb_bounded_bytes<'a>(c_buf: *const c_char, max_len: i64) -> &'a [u8] {
+    let bytes = core::slice::from_raw_parts(c_buf.cast::<u8>(), max_len as usize);
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    &bytes[..len]
+}
+
+/// Prints a single string of the given maximum length to `stdout` without appending a newline.
+/// The bytes of the string are written out unchanged.
 #[no_mangle]
-pub unsafe extern "C" fn cb_print_str(c_buf: *const c_char) {
-    let c_str: &CStr = unsafe { CStr::from_ptr(c_buf) };
-    let str_slice: &str = c_str.to_str().unwrap();
-    print!("{str_slice}");
+pub unsafe extern "C" fn cb_print_str(c_buf: *const c_char, max_len: i64) {
+    let bytes = cb_bounded_bytes(c_buf, max_len);
+    libc::write(1, bytes.as_ptr().cast(), bytes.len());
 }
 
 /// Prints a single [`i64`] to `stdout` without appending a newline.
@@ -50,31 +57,402 @@ pub unsafe extern "C" fn cb_print_i64(i: i64) {
     print!("{i}");
 }
 
-/// Prints a single [`f64`] to `stdout` without appending a newline.
+/// Prints a single newline to `stdout`.
 #[no_mangle]
-pub unsafe extern "C" fn cb_print_f64(f: f64) {
-    print!("{f}");
+pub unsafe extern "C" fn cb_print_nl() {
+    println!();
 }
 
-/// Compares two given strings, returning the following as an [`i8`]:
-/// - If the two strings match, 1.
-/// - If the two strings do not match, 0.
+/// Prints a single decimal number to `stdout` without appending a newline.
+/// The value is given as an integer scaled by 10^`scale`. Trailing zeroes after the decimal point
+/// are not printed, and the decimal point is omitted entirely for whole values.
 #[no_mangle]
-pub unsafe extern "C" fn cb_strcmp(str_a: *const c_char, str_b: *const c_char) -> i8 {
-    // Convert both strings into slices.
-    let c_str: &CStr = unsafe { CStr::from_ptr(str_a) };
-    let slice_a: &str = c_str.to_str().unwrap();
-    let c_str: &CStr = unsafe { CStr::from_ptr(str_b) };
-    let slice_b: &str = c_str.to_str().unwrap();
+pub unsafe extern "C" fn cb_print_dec(val: i64, scale: i64) {
+    let divisor = 10u64.pow(scale as u32);
+    let (magnitude, sign) = (val.unsigned_abs(), if val < 0 { "-" } else { "" });
+    let (mut frac, mut width) = (magnitude % divisor, scale as usize);
+    if frac == 0 {
+        print!("{sign}{}", magnitude / divisor);
+        return;
+    }
+    while frac % 10 == 0 {
+        frac /= 10;
+        width -= 1;
+    }
+    print!("{sign}{}.{:0width$}", magnitude / divisor, frac);
+}
+
+/// Prints a single fixed point number to `stdout` without appending a newline.
+/// The value is given as an integer scaled by 10^`scale`, and is printed with exactly `digits`
+/// digits, `scale` of which follow the decimal point. Signed values are always printed with a
+/// leading sign character.
+#[no_mangle]
+pub unsafe extern "C" fn cb_print_fixed(val: i64, digits: i64, scale: i64, signed: i8) {
+    if signed != 0 {
+        print!("{}", if val < 0 { '-' } else { '+' });
+    }
+    let magnitude = val.unsigned_abs();
+    let int_digits = (digits - scale) as usize;
+    if scale > 0 {
+        let divisor = 10u64.pow(scale as u32);
+        print!(
+            "{:0int_width$}.{:0scale_width$}",
+            magnitude / divisor,
+            magnitude % divisor,
+            int_width = int_digits,
+            scale_width = scale as usize
+        );
+    } else {
+        print!("{:0int_digits$}", magnitude);
+    }
+}
+
+/// Sign storage modes for zoned (DISPLAY) numeric values.
+/// These must match the modes passed by the code generator, with 0 representing an unsigned value.
+const ZONED_TRAILING_OVERPUNCH: i64 = 1;
+const ZONED_LEADING_OVERPUNCH: i64 = 2;
+const ZONED_TRAILING_SEPARATE: i64 = 3;
+const ZONED_LEADING_SEPARATE: i64 = 4;
+
+/// Decodes a single zoned digit, returning the digit and whether it carries a negative overpunch.
+/// Invalid digits are treated as zero.
+fn cb_zoned_digit(byte: u8) -> (i64, bool) {
+    match byte {
+        b'0'..=b'9' => ((byte - b'0') as i64, false),
+        b'{' => (0, false),
+        b'A'..=b'I' => ((byte - b'A' + 1) as i64, false),
+        b'}' => (0, true),
+        b'J'..=b'R' => ((byte - b'J' + 1) as i64, true),
+        b'p'..=b'y' => ((byte - b'p') as i64, true),
+        _ => (0, false),
+    }
+}
+
+/// Encodes a single digit as a zoned byte, overpunching the given sign if requested.
+fn cb_zoned_punch(digit: u8, negative: Option<bool>) -> u8 {
+    match (negative, digit) {
+        (None, d) => b'0' + d,
+        (Some(false), 0) => b'{',
+        (Some(false), d) => b'A' + d - 1,
+        (Some(true), 0) => b'}',
+        (Some(true), d) => b'J' + d - 1,
+    }
+}
 
-    // Perform a bytewise comparison.
-    if slice_a.eq(slice_b) {
+/// Loads a zoned (DISPLAY) numeric value of the given number of digits from the given buffer.
+/// Returns the value as an integer, without applying any decimal scaling.
+#[no_mangle]
+pub unsafe extern "C" fn cb_zoned_load(buf: *const c_char, digits: i64, sign_mode: i64) -> i64 {
+    let (digit_start, sign_idx) = match sign_mode {
+        ZONED_LEADING_SEPARATE => (1, Some(0)),
+        ZONED_TRAILING_SEPARATE => (0, Some(digits as usize)),
+        _ => (0, None),
+    };
+    let bytes = core::slice::from_raw_parts(buf.cast::<u8>(), (digits as usize) + digit_start);
+    let overpunch_idx = match sign_mode {
+        ZONED_TRAILING_OVERPUNCH => Some(digits as usize - 1),
+        ZONED_LEADING_OVERPUNCH => Some(0),
+        _ => None,
+    };
+    let mut negative = false;
+    let mut val: i64 = 0;
+    for (idx, byte) in bytes[digit_start..].iter().enumerate() {
+        let (digit, punched) = cb_zoned_digit(*byte);
+        if Some(idx) == overpunch_idx {
+            negative = punched;
+        }
+        val = val * 10 + digit;
+    }
+    if let Some(sign_idx) = sign_idx {
+        negative = *buf.add(sign_idx) as u8 == b'-';
+    }
+    if negative {
+        -val
+    } else {
+        val
+    }
+}
+
+/// Stores the given integer as a zoned (DISPLAY) numeric value of the given number of digits
+/// into the given buffer. High order digits that do not fit are truncated.
+/// Unsigned destinations store the absolute value.
+#[no_mangle]
+pub unsafe extern "C" fn cb_zoned_store(val: i64, buf: *mut c_char, digits: i64, sign_mode: i64) {
+    let negative = val < 0;
+    let mut magnitude = val.unsigned_abs();
+    let digit_start = if sign_mode == ZONED_LEADING_SEPARATE {
         1
     } else {
         0
+    };
+    let bytes = core::slice::from_raw_parts_mut(buf.cast::<u8>(), (digits as usize) + digit_start);
+    for idx in (0..digits as usize).rev() {
+        let digit = (magnitude % 10) as u8;
+        magnitude /= 10;
+        let sign = match sign_mode {
+            ZONED_TRAILING_OVERPUNCH if idx == digits as usize - 1 => Some(negative),
+            ZONED_LEADING_OVERPUNCH if idx == 0 => Some(negative),
+            _ => None,
+        };
+        bytes[digit_start + idx] = cb_zoned_punch(digit, sign);
+    }
+    let sign_char = if negative { b'-' } else { b'+' };
+    match sign_mode {
+        ZONED_LEADING_SEPARATE => bytes[0] = sign_char,
+        ZONED_TRAILING_SEPARATE => *buf.add(digits as usize) = sign_char as _,
+        _ => {}
+    }
+}
+
+/// Loads a packed decimal (COMP-3) numeric value of the given number of digits from the given buffer.
+/// Returns the value as an integer, without applying any decimal scaling.
+/// Invalid digit nibbles are treated as zero.
+#[no_mangle]
+pub unsafe extern "C" fn cb_packed_load(buf: *const c_char, digits: i64) -> i64 {
+    let bytes = core::slice::from_raw_parts(buf.cast::<u8>(), (digits as usize) / 2 + 1);
+    let mut val: i64 = 0;
+    for (idx, byte) in bytes.iter().enumerate() {
+        let nibbles = if idx == bytes.len() - 1 {
+            &[byte >> 4][..]
+        } else {
+            &[byte >> 4, byte & 0xF][..]
+        };
+        for nibble in nibbles {
+            val = val * 10 + if *nibble > 9 { 0 } else { *nibble as i64 };
+        }
+    }
+
+    // The final nibble contains the sign, where 0xB and 0xD are negative.
+    match bytes[bytes.len() - 1] & 0xF {
+        0xB | 0xD => -val,
+        _ => val,
     }
 }
 
+/// Stores the given integer as a packed decimal (COMP-3) numeric value of the given number of
+/// digits into the given buffer. High order digits that do not fit are truncated.
+/// Unsigned destinations store the absolute value.
+#[no_mangle]
+pub unsafe extern "C" fn cb_packed_store(val: i64, buf: *mut c_char, digits: i64, signed: i8) {
+    let sign_nibble = match (signed != 0, val < 0) {
+        (false, _) => 0xF,
+        (true, false) => 0xC,
+        (true, true) => 0xD,
+    };
+    let mut magnitude = 10u64
+        .checked_pow(digits as u32)
+        .map_or(val.unsigned_abs(), |max| val.unsigned_abs() % max);
+    let bytes = core::slice::from_raw_parts_mut(buf.cast::<u8>(), (digits as usize) / 2 + 1);
+    let last = bytes.len() - 1;
+    bytes[last] = ((magnitude % 10) as u8) << 4 | sign_nibble;
+    magnitude /= 10;
+    for byte in bytes[..last].iter_mut().rev() {
+        *byte = ((magnitude / 10 % 10) as u8) << 4 | (magnitude % 10) as u8;
+        magnitude /= 100;
+    }
+}
+
+/// Edits the given fixed point value (scaled by 10^`scale`) into the given buffer, using the given
+/// zero terminated numeric-edited mask, e.g. `$,$$9.99CR`. The buffer is written with a single
+/// character per character of the mask, excluding any implied decimal point ('V').
+/// High order digits that do not fit within the mask are truncated.
+#[no_mangle]
+pub unsafe extern "C" fn cb_edit_num(val: i64, scale: i64, mask: *const c_char, buf: *mut c_char) {
+    let mask = core::ffi::CStr::from_ptr(mask).to_bytes();
+    let out_len = mask.iter().filter(|c| **c != b'V').count();
+    let out = core::slice::from_raw_parts_mut(buf.cast::<u8>(), out_len);
+
+    // A currency or sign symbol repeated more than once is a floating insertion symbol. The first
+    // occurrence only holds the symbol, with the remainder also acting as digit positions.
+    let floating = [b'$', b'+', b'-']
+        .into_iter()
+        .find(|s| mask.iter().filter(|c| *c == s).count() > 1);
+    let is_digit_pos = |idx: usize| match mask[idx] {
+        b'9' | b'Z' | b'*' => true,
+        c => Some(c) == floating && mask[..idx].contains(&c),
+    };
+
+    // Determine the number of digit positions in total and after the decimal point.
+    let point = mask.iter().position(|c| *c == b'.' || *c == b'V');
+    let digits = (0..mask.len()).filter(|i| is_digit_pos(*i)).count() as u32;
+    let frac_digits = point.map_or(0, |p| (p..mask.len()).filter(|i| is_digit_pos(*i)).count());
+
+    // Align the value to the decimal places of the mask, dropping digits which do not fit.
+    let mut magnitude = val.unsigned_abs() as u128;
+    let scale = scale as u32;
+    if frac_digits as u32 > scale {
+        magnitude *= 10u128.pow(frac_digits as u32 - scale);
+    } else {
+        magnitude /= 10u128.pow(scale - frac_digits as u32);
+    }
+    magnitude %= 10u128.pow(digits);
+    let negative = val < 0 && magnitude != 0;
+
+    // Values of zero with no mandatory digits are entirely suppressed.
+    let fill = if mask.contains(&b'*') { b'*' } else { b' ' };
+    if magnitude == 0 && !mask.contains(&b'9') {
+        for (o, c) in out.iter_mut().zip(mask.iter().filter(|c| **c != b'V')) {
+            *o = match (fill, c) {
+                (b'*', b'.') => b'.',
+                _ => fill,
+            };
+        }
+        return;
+    }
+
+    let sign_symbol = |symbol: u8| match (symbol, negative) {
+        (b'+' | b'-', true) => b'-',
+        (b'-', false) => b' ',
+        (s, _) => s,
+    };
+    let (mut pos, mut idx, mut digit_idx) = (0, 0, 0);
+    let (mut significant, mut float_started) = (false, false);
+    while idx < mask.len() {
+        let c = mask[idx];
+
+        // Any digit position (or the decimal point) ends zero suppression, placing the floating
+        // symbol immediately before the first significant character.
+        let ends_suppression = c == b'.' || c == b'V' || is_digit_pos(idx);
+        let digit = if is_digit_pos(idx) {
+            digit_idx += 1;
+            (magnitude / 10u128.pow(digits - digit_idx) % 10) as u8
+        } else {
+            0
+        };
+        if !significant && ends_suppression && (digit != 0 || !is_digit_pos(idx) || c == b'9') {
+            significant = true;
+            if let Some(symbol) = floating.filter(|_| float_started && pos > 0) {
+                out[pos - 1] = sign_symbol(symbol);
+            }
+        }
+
+        match c {
+            b'V' => {
+                idx += 1;
+                continue;
+            }
+            b'C' | b'D' if mask.get(idx + 1).is_some_and(|n| *n == b'R' || *n == b'B') => {
+                let symbol: &[u8] = if !negative {
+                    b"  "
+                } else if c == b'C' {
+                    b"CR"
+                } else {
+                    b"DB"
+                };
+                out[pos..pos + 2].copy_from_slice(symbol);
+                pos += 2;
+                idx += 2;
+                continue;
+            }
+            c if Some(c) == floating && !float_started => {
+                float_started = true;
+                out[pos] = b' ';
+            }
+            _ if is_digit_pos(idx) => {
+                out[pos] = match (significant, c) {
+                    (true, _) => b'0' + digit,
+                    (false, b'*') => b'*',
+                    (false, _) => b' ',
+                };
+            }
+            b'.' => out[pos] = b'.',
+            b',' | b'B' | b'0' | b'/' if !significant => out[pos] = fill,
+            b'B' => out[pos] = b' ',
+            b'+' | b'-' => out[pos] = sign_symbol(c),
+            c => out[pos] = c,
+        }
+        pos += 1;
+        idx += 1;
+    }
+}
+
+/// Raises a fixed point number to a fixed point power, returning the result scaled by 10^`out_scale`.
+/// Both operands are given as integers scaled by 10^ their respective scales.
+/// Whole powers are calculated in fixed point with additional decimal places before being truncated,
+/// while fractional powers fall back to floating point. Results which do not fit are saturated.
+#[no_mangle]
+pub unsafe extern "C" fn cb_pow_dec(
+    base: i64,
+    base_scale: i64,
+    exp: i64,
+    exp_scale: i64,
+    out_scale: i64,
+) -> i64 {
+    let exp_unit = 10i64.pow(exp_scale as u32);
+    if exp % exp_unit != 0 {
+        let base = base as f64 / libm::pow(10.0, base_scale as f64);
+        let exp = exp as f64 / exp_unit as f64;
+        let result = libm::pow(base, exp) * libm::pow(10.0, out_scale as f64);
+        return (result as i64).max(-i64::MAX);
+    }
+
+    // Multiply out the power, keeping some extra decimal places to limit truncation error.
+    let guard = 19u32.saturating_sub(out_scale as u32).min(4);
+    let one = 10i128.pow(out_scale as u32 + guard);
+    let base_unit = 10i128.pow(base_scale as u32);
+    let mut acc = one;
+    for _ in 0..(exp / exp_unit).unsigned_abs() {
+        let Some(product) = acc.checked_mul(base as i128) else {
+            return if (acc < 0) != (base < 0) {
+                -i64::MAX
+            } else {
+                i64::MAX
+            };
+        };
+        acc = product / base_unit;
+    }
+    if exp < 0 {
+        acc = match one.checked_mul(one) {
+            Some(one_sq) if acc != 0 => one_sq / acc,
+            _ => return i64::MAX,
+        };
+    }
+    (acc / 10i128.pow(guard)).clamp(-i64::MAX as i128, i64::MAX as i128) as i64
+}
+
+/// Compares two given strings of the given maximum lengths, returning the following as an [`i8`]:
+/// - If the two strings match, 1.
+/// - If the two strings do not match, 0.
+///
+/// The shorter of the two strings is treated as if padded with trailing spaces.
+#[no_mangle]
+pub unsafe extern "C" fn cb_strcmp(
+    str_a: *const c_char,
+    a_max_len: i64,
+    str_b: *const c_char,
+    b_max_len: i64,
+) -> i8 {
+    (cb_strord(str_a, a_max_len, str_b, b_max_len) == 0) as i8
+}
+
+/// Compares the ordering of two given strings of the given maximum lengths, returning the following as an [`i8`]:
+/// - If the first string orders before the second, -1.
+/// - If the two strings are equal, 0.
+/// - If the first string orders after the second, 1.
+///
+/// The shorter of the two strings is treated as if padded with trailing spaces.
+#[no_mangle]
+pub unsafe extern "C" fn cb_strord(
+    str_a: *const c_char,
+    a_max_len: i64,
+    str_b: *const c_char,
+    b_max_len: i64,
+) -> i8 {
+    // Convert both strings into slices.
+    let slice_a = cb_bounded_bytes(str_a, a_max_len);
+    let slice_b = cb_bounded_bytes(str_b, b_max_len);
+
+    // Perform a bytewise comparison, padding with spaces.
+    for i in 0..slice_a.len().max(slice_b.len()) {
+        let char_a = slice_a.get(i).copied().unwrap_or(b' ');
+        let char_b = slice_b.get(i).copied().unwrap_or(b' ');
+        if char_a != char_b {
+            return if char_a < char_b { -1 } else { 1 };
+        }
+    }
+    0
+}
+
 /// Copies a portion of the given source string into the destination string.
 /// Panics if the source or destination span are invalid.
 /// If the source span does not fit within the destination span, the source is truncated upon copy.
@@ -111,10 +489,9 @@ pub unsafe extern "C" fn cb_strcpy(
         );
     }
 
-    // The destination string may currently have garbage post-terminator data before our index.
-    // If that's the case, we need to overwrite it with spaces.
-    let dest_cstr: &CStr = unsafe { CStr::from_ptr(dest_str) };
-    let dest_slice: &str = dest_cstr.to_str().unwrap();
+    // The destination string may currently end before our index.
+    // If that's the case, we need to fill the gap with spaces.
+    let dest_slice = cb_bounded_bytes(dest_str, dest_len);
     let orig_dest_len = dest_slice.len();
     if dest_slice.len() < dest_span_idx as usize {
         libc::memset(
@@ -132,8 +509,9 @@ pub unsafe extern "C" fn cb_strcpy(
     // Perform copy (possibly overlapping).
     core::ptr::copy(src_ptr, dest_ptr, size);
 
-    // Add a null terminator, if the original string ended before where our copy ended.
-    if orig_dest_len < (dest_span_idx as usize) + size {
+    // Add a null terminator, if the original string ended before where our copy ended and
+    // there is space remaining within the destination.
+    if orig_dest_len < (dest_span_idx as usize) + size && dest_span_idx + (size as i64) < dest_len {
         *dest_ptr.add(size) = b'\0' as _;
     }
 }
@@ -161,10 +539,10 @@ pub unsafe extern "C" fn cb_charcpy(
 #[no_mangle]
 pub unsafe extern "C" fn cb_readstr(buf: *mut c_char, buf_len: usize) {
     let input = cb_readline();
-    if input.len() >= buf_len {
+    if input.len() > buf_len {
         panic!(
             "Input string was too long for string buffer ({} > {})",
-            input.len() + 1,
+            input.len(),
             buf_len
         );
     }
@@ -173,8 +551,8 @@ pub unsafe extern "C" fn cb_readstr(buf: *mut c_char, buf_len: usize) {
 
     // Safety: This is only valid because our string lives until the end of the function.
     core::ptr::copy_nonoverlapping(input_ptr, buf.cast(), input_bytes.len());
-    // Add our null terminator.
-    *buf.add(input.len()) = b'\0' as _;
+    // Pad out any remaining space in the buffer with spaces.
+    core::ptr::write_bytes(buf.add(input.len()), b' ', buf_len - input.len());
 }
 
 /// Reads a single integer from the console, returning the result.
@@ -187,14 +565,34 @@ pub unsafe extern "C" fn cb_readint() -> i64 {
         .expect("Invalid integer, could not parse.")
 }
 
-/// Reads a single floating point number from the console, returning the result.
+/// Reads a single decimal number from the console, returning the result as an integer
+/// scaled by 10^`scale`. Any decimal places beyond the given scale are truncated.
 /// On failure to parse, panics.
 #[no_mangle]
-pub unsafe extern "C" fn cb_readfloat() -> f64 {
+pub unsafe extern "C" fn cb_readdec(scale: i64) -> i64 {
     let input = cb_readline();
-    input
-        .parse::<f64>()
-        .expect("Invalid float, could not parse.")
+    let input = input.trim();
+    let (negative, input) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (int_part, frac_part) = input.split_once('.').unwrap_or((input, ""));
+    let mut val = int_part
+        .parse::<i64>()
+        .expect("Invalid decimal, could not parse.");
+    let mut frac_digits = frac_part.chars();
+    for _ in 0..scale {
+        let digit = match frac_digits.next() {
+            Some(c) => c.to_digit(10).expect("Invalid decimal, could not parse.") as i64,
+            None => 0,
+        };
+        val = val * 10 + digit;
+    }
+    if negative {
+        -val
+    } else {
+        val
+    }
 }
 
 /// Reads a single owned line from the console, returning it.
@@ -218,12 +616,15 @@ pub unsafe extern "C" fn cb_mod(a: i64, b: i64) -> i64 {
 }
 
 /// COBOL length intrinsic.
-/// Returns the length of the given string.
+/// Returns the length of the given string of the given maximum length, excluding any trailing
+/// space padding.
 #[no_mangle]
-pub unsafe extern "C" fn cb_length(str: *const c_char) -> i64 {
-    let c_str: &CStr = unsafe { CStr::from_ptr(str) };
-    let slice: &str = c_str.to_str().unwrap();
-    slice.len() as i64
+pub unsafe extern "C" fn cb_length(str: *const c_char, max_len: i64) -> i64 {
+    let slice = cb_bounded_bytes(str, max_len);
+    slice
+        .iter()
+        .rposition(|byte| *byte != b' ')
+        .map_or(0, |idx| idx + 1) as i64
 }
 
 /// COBOL random intrinsic.
@@ -255,3 +656,11 @@ pub unsafe extern "C" fn cb_random() -> f64 {
 pub unsafe extern "C" fn cb_integer(f: f64) -> i64 {
     libm::ceil(f) as i64
 }
+
+/// Reports a table subscript which is out of bounds for its table, then exits the program
+/// with a failure status.
+#[no_mangle]
+pub unsafe extern "C" fn cb_subscript_fail(idx: i64, occurs: i64) {
+    eprintln!("Subscript {idx} is out of bounds for a table which occurs {occurs} times.");
+    libc::exit(1);
+}